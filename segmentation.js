@@ -0,0 +1,77 @@
+// Node script to generate the UAX #29 property tables used by \X, \b{g} and \b{w}
+// Takes the directory holding GraphemeBreakProperty.txt, WordBreakProperty.txt and emoji-data.txt from
+// https://www.unicode.org/Public/<version>/ucd/ (the first two are under auxiliary/, the last under emoji/)
+// node segmentation.js path/to/ucd
+
+const fs = require('fs');
+const path = require('path');
+
+const dir = process.argv[2] || '.';
+
+// Variant names in segmentation.rs, anything not listed is the property value without underscores
+const renames = { 'ZWJ': 'Zwj', 'LVT': 'Lvt' };
+
+function read(file) {
+    return fs.readFileSync(path.join(dir, file), 'utf8');
+}
+
+// The version is in the first line of each file, e.g. "# GraphemeBreakProperty-15.1.0.txt"
+function version(text) {
+    const found = /-(\d+\.\d+\.\d+)\.txt/.exec(text.split('\n')[0]);
+    return found ? found[1] : 'unknown';
+}
+
+// Lines look like "0600..0605    ; Prepend # Cf   [6] ARABIC NUMBER SIGN..ARABIC NUMBER MARK ABOVE"
+function parse(text, wanted) {
+    const ranges = [];
+    for (const line of text.split('\n')) {
+        const data = line.split('#')[0].trim();
+        if (data === '') {
+            continue;
+        }
+        const [codes, value] = data.split(';').map((x) => x.trim());
+        if (wanted && value !== wanted) {
+            continue;
+        }
+        const [start, end] = codes.split('..').map((x) => parseInt(x, 16));
+        ranges.push([start, end === undefined ? start : end, value]);
+    }
+    ranges.sort((a, b) => a[0] - b[0]);
+    // Ranges next to each other with the same value become one
+    const merged = [];
+    for (const range of ranges) {
+        const last = merged[merged.length - 1];
+        if (last && last[1] + 1 === range[0] && last[2] === range[2]) {
+            last[1] = range[1];
+        } else {
+            merged.push(range);
+        }
+    }
+    return merged;
+}
+
+function char(code) {
+    return "'\\u{" + code.toString(16).toUpperCase() + "}'";
+}
+
+function table(name, type, ranges, variant) {
+    let output = '\npub(crate) const ' + name + ': &[' + type + '] = &[\n';
+    for (const [start, end, value] of ranges) {
+        output += '    (' + char(start) + ', ' + char(end) + (variant ? ', ' + variant(value) : '') + '),\n';
+    }
+    return output + '];\n';
+}
+
+const grapheme = read('GraphemeBreakProperty.txt');
+const word = read('WordBreakProperty.txt');
+const emoji = read('emoji-data.txt');
+const name = (value) => renames[value] || value.replace(/_/g, '');
+
+let output = '// Generated by segmentation.js from the Unicode ' + version(grapheme) + ' data files, don\'t edit by hand\n';
+output += '// Characters that aren\'t in a table have the property value Other\n\n';
+output += 'use crate::segmentation::{GraphemeBreak, WordBreak};\n';
+output += table('GRAPHEME_BREAK', '(char, char, GraphemeBreak)', parse(grapheme), (value) => 'GraphemeBreak::' + name(value));
+output += table('WORD_BREAK', '(char, char, WordBreak)', parse(word), (value) => 'WordBreak::' + name(value));
+output += table('EXTENDED_PICTOGRAPHIC', '(char, char)', parse(emoji, 'Extended_Pictographic'));
+
+fs.writeFileSync(path.join(__dirname, 'src', 'segmentation_tables.rs'), output);
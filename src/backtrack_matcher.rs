@@ -10,11 +10,12 @@ use std::hint::unreachable_unchecked;
 // pub type CapturesMap = fxhash::FxHashMap<u32, Vec<(usize, usize)>>;
 
 use super::compiled_node::{CNode::*, CompiledNode, *};
+use crate::regex::Input;
 use crate::root_node_optimizer::RootNode;
-use crate::utf_8::*;
+use crate::haystack::{char_before_end, next_index, Haystack};
 use BackTrackToken::*;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum BackTrackToken {
     // String index, child(ren)
     // Special case for a branch with only 2 directions, occurs with +, ?, *
@@ -28,29 +29,73 @@ pub enum BackTrackToken {
     NegativeLookahead(usize, usize),
     // For atomic groups
     Atomic,
+    // Counter index and the state to put back when backtracking past a repetition node
+    RestoreCounter(usize, (usize, usize)),
+    // Same for capture group slots
+    RestoreCapture(usize, Option<usize>),
 }
 
-pub(crate) fn backtrack_match_indices(
+// Drops every choice point made since the marker for atomic groups and lookarounds
+// Restore tokens are kept so that backtracking past this point still undoes captures and counters set inside it
+fn cut(callstack: &mut Vec<BackTrackToken>, is_marker: impl Fn(&BackTrackToken) -> bool) {
+    let marker = match callstack.iter().rposition(is_marker) {
+        Some(marker) => marker,
+        None => {
+            callstack.clear();
+            return;
+        }
+    };
+    let mut kept = marker;
+    for i in marker + 1..callstack.len() {
+        if let RestoreCounter(..) | RestoreCapture(..) = callstack[i] {
+            callstack.swap(kept, i);
+            kept += 1;
+        }
+    }
+    callstack.truncate(kept);
+}
+
+// Finds the leftmost match inside the input's span, stopping at the first one found
+// The first match found is already the preferred one so earliest makes no difference here
+pub(crate) fn backtrack_find<H: Haystack + ?Sized>(
     nodes: &[CompiledNode],
-    string: &[u8],
     start_node: usize,
     // Node index, string index, child
     callstack: &mut Vec<BackTrackToken>,
     root_node: &Option<RootNode>,
-) -> Vec<(usize, usize)> {
-    let mut out = Vec::new();
-
+    input: &Input<H>,
+    // Start and end of each capture group, can be left empty when the groups aren't needed
+    slots: &mut [Option<usize>],
+    // When given every way of matching is tried and where each of them ends goes in here, the search then always returns None
+    mut ends: Option<&mut Vec<usize>>,
+) -> Option<(usize, usize)> {
+    callstack.clear();
+    slots.fill(None);
+    let string = input.haystack();
+    let (start, end) = (input.start(), input.end());
+    let root_node = if input.is_anchored() { &None } else { root_node };
     let mut node_index = start_node;
-    let mut string_index = 0;
-    let mut start_string_index = 0;
+    let mut string_index = start;
+    let mut start_string_index = start;
+    if let Some(root_node) = root_node {
+        let (candidate, resume) = root_node.run(string, start)?;
+        if resume > end {
+            return None;
+        }
+        node_index = root_node.child;
+        start_string_index = candidate;
+        string_index = resume;
+    }
     // Node index, string index, child
     let mut completed_recursion_stack = Vec::new();
     // String Index, Node Index
     let mut alt_stack = Vec::<(usize, usize)>::new();
+    // Iterations done so far by each counted repetition and where the current one started
+    let mut counters = Vec::<(usize, usize)>::new();
 
     'outer: loop {
         let node = unsafe { nodes.get_unchecked(node_index) };
-        let string_data = decode_utf8(&string[string_index..]);
+        let string_data = char_before_end(string, string_index, end);
         match &node.node {
             Match(match_node) => match string_data {
                 Some((c, len)) => {
@@ -75,240 +120,29 @@ pub(crate) fn backtrack_match_indices(
                 }
                 None => (),
             },
-            Anchor(anchor_node) => {
-                if anchor_node.is_match(string_index, string, string_data) {
+            Sequence(sequence_node) => {
+                if let Some(len) = sequence_node.match_len(string, string_index, end) {
                     match &node.children {
                         Children::Multiple(vec) => {
                             if vec.len() == 2 {
-                                callstack.push(BackTrackToken::Single(string_index, unsafe { *vec.get_unchecked(1) }));
+                                callstack.push(BackTrackToken::Single(string_index + len, unsafe { *vec.get_unchecked(1) }));
                             } else {
-                                callstack.push(BackTrackToken::Multiple(string_index, node_index, 1));
+                                callstack.push(BackTrackToken::Multiple(string_index + len, node_index, 1));
                             }
                             node_index = *unsafe { vec.get_unchecked(0) };
                         }
                         Children::Single(num) => {
                             node_index = *num;
                         }
-                        Children::None => panic!("Anchor node has no children"),
+                        Children::None => panic!("Sequence node has no children"),
                     }
+                    string_index += len;
                     continue 'outer;
                 }
             }
-            Special(special_node) => {
-                use SpecialNode::*;
-                match special_node {
-                    DropStack => callstack.clear(),
-                    GlobalRecursion => {
-                        alt_stack.push((string_index, node_index));
-                        callstack.push(PopAltStack);
-                        node_index = start_node;
-                        continue 'outer;
-                    }
-                    StartLookAhead => {
-                        alt_stack.push((string_index, node_index));
-                        callstack.push(PopAltStack);
-                    }
-                    EndLookAhead => {
-                        let res = alt_stack.pop().unwrap();
-                        while let Some(token) = callstack.pop() {
-                            // println!("Removed {:?}", nodes.get(node_idx));
-                            if token == PopAltStack {
-                                break;
-                            }
-                        }
-                        string_index = res.0;
-                    }
-                    StartNegativeLookAhead => {
-                        // alt_stack.push((string_index, node_index + 1));
-                        callstack.push(NegativeLookahead(string_index, node_index + 1));
-                    }
-                    EndNegativeLookAhead => {
-                        // let (old_string_idx, _old_node_idx,) = alt_stack.pop().unwrap();
-                        while let Some(token) = callstack.pop() {
-                            // println!("Removed {:?}", nodes.get(node_idx));
-                            if let NegativeLookahead(_, _) = token {
-                                break;
-                            }
-                        }
-                        // string_index = old_string_idx;
-                    }
-                    StartAtomic => {
-                        callstack.push(Atomic);
-                    }
-                    EndAtomic => {
-                        while let Some(token) = callstack.pop() {
-                            if token == Atomic {
-                                break;
-                            }
-                        }
-                    }
-                    _ => unimplemented!(),
-                }
-                match &node.children {
-                    Children::Multiple(vec) => {
-                        if vec.len() == 2 {
-                            callstack.push(BackTrackToken::Single(string_index, unsafe { *vec.get_unchecked(1) }));
-                        } else {
-                            callstack.push(BackTrackToken::Multiple(string_index, node_index, 1));
-                        }
-                        node_index = *unsafe { vec.get_unchecked(0) };
-                    }
-                    Children::Single(num) => {
-                        node_index = *num;
-                    }
-                    Children::None => panic!("Anchor node has no children"),
-                }
-                continue 'outer;
-            }
-            Behaviour(_) => {
-                match &node.children {
-                    Children::Multiple(vec) => {
-                        if vec.len() == 2 {
-                            callstack.push(BackTrackToken::Single(string_index, unsafe { *vec.get_unchecked(1) }));
-                        } else {
-                            callstack.push(BackTrackToken::Multiple(string_index, node_index, 1));
-                        }
-                        node_index = *unsafe { vec.get_unchecked(0) };
-                    }
-                    Children::Single(num) => {
-                        node_index = *num;
-                    }
-                    Children::None => panic!("Anchor node has no children"),
-                }
-                continue 'outer;
-            }
-            CNode::End => match alt_stack.pop() {
-                Some(n) => {
-                    completed_recursion_stack.push(n.1);
-                    node_index = n.1;
-                    callstack.push(BackTrackToken::End);
-                    continue 'outer;
-                }
-                None => {
-                    callstack.clear();
-                    out.push((start_string_index, string_index));
-                    start_string_index = string_index;
-                }
-            },
-        }
-        loop {
-            match callstack.pop() {
-                Some(token) => match token {
-                    Single(str_idx, node_idx) => {
-                        node_index = node_idx;
-                        string_index = str_idx;
-                        continue 'outer;
-                    }
-                    Multiple(str_idx, node_idx, child) => {
-                        string_index = str_idx;
-                        let node = unsafe { nodes.get_unchecked(node_idx) };
-                        match &node.children {
-                            Children::Multiple(vec) => {
-                                let new_child = unsafe { *vec.get_unchecked(child) };
-                                if child + 2 == vec.len() {
-                                    callstack.push(Single(str_idx, *unsafe { vec.get_unchecked(child + 1) }));
-                                } else {
-                                    callstack.push(Multiple(str_idx, node_idx, child + 1));
-                                }
-                                node_index = new_child;
-                            }
-                            _ => unreachable!(),
-                        }
-                        continue 'outer;
-                    }
-                    PopAltStack => {
-                        alt_stack.pop();
-                    }
-                    NegativeLookahead(string_idx, node_idx) => {
-                        let node = nodes.get(node_idx).unwrap();
-                        string_index = string_idx;
-                        match &node.children {
-                            Children::Multiple(vec) => {
-                                if vec.len() == 2 {
-                                    callstack.push(BackTrackToken::Single(string_index, unsafe { *vec.get_unchecked(1) }));
-                                } else {
-                                    callstack.push(BackTrackToken::Multiple(string_index, node_index, 1));
-                                }
-                                node_index = *unsafe { vec.get_unchecked(0) };
-                            }
-                            Children::Single(num) => {
-                                node_index = *num;
-                            }
-                            Children::None => panic!("Anchor node has no children"),
-                        }
-                        continue 'outer;
-                    }
-                    _ => (),
-                },
-                None => {
-                    start_string_index = next_utf8(string, start_string_index);
-                    if let Some(root_node) = root_node {
-                        match root_node.run(string, start_string_index) {
-                            Some(idx) => {
-                                node_index = root_node.child;
-                                start_string_index = idx;
-                                string_index = idx;
-                                continue 'outer;
-                            }
-                            None => return out,
-                        }
-                    } else if start_string_index < string.len() {
-                        string_index = start_string_index;
-                        node_index = start_node;
-                        continue 'outer;
-                    } else {
-                        return out;
-                    }
-                }
-            }
-        }
-    }
-}
-
-pub(crate) fn backtrack_pure_match(
-    nodes: &[CompiledNode],
-    string: &[u8],
-    start_node: usize,
-    callstack: &mut Vec<BackTrackToken>,
-    root_node: &Option<RootNode>,
-) -> bool {
-    let mut node_index = start_node;
-    let mut string_index = 0;
-    let mut start_string_index = 0;
-    // Node index, string index, child
-    let mut completed_recursion_stack = Vec::new();
-    // String Index, Node Index
-    let mut alt_stack = Vec::<(usize, usize)>::new();
-
-    'outer: loop {
-        let node = unsafe { nodes.get_unchecked(node_index) };
-        let string_data = decode_utf8(&string[string_index..]);
-        match &node.node {
-            Match(match_node) => match string_data {
-                Some((c, len)) => {
-                    if match_node.is_match(&c) {
-                        match &node.children {
-                            Children::Multiple(vec) => {
-                                if vec.len() == 2 {
-                                    callstack.push(BackTrackToken::Single(string_index + len, unsafe { *vec.get_unchecked(1) }));
-                                } else {
-                                    callstack.push(BackTrackToken::Multiple(string_index + len, node_index, 1));
-                                }
-                                node_index = *unsafe { vec.get_unchecked(0) };
-                            }
-                            Children::Single(num) => {
-                                node_index = *num;
-                            }
-                            Children::None => panic!("Match node has no children"),
-                        }
-                        string_index += len;
-                        continue 'outer;
-                    }
-                }
-                None => (),
-            },
             Anchor(anchor_node) => {
-                if anchor_node.is_match(string_index, string, string_data) {
+                // Anchors can see past the end of the span
+                if anchor_node.is_match(string_index, string, string.next_char(string_index)) {
                     match &node.children {
                         Children::Multiple(vec) => {
                             if vec.len() == 2 {
@@ -326,6 +160,12 @@ pub(crate) fn backtrack_pure_match(
                     continue 'outer;
                 }
             }
+            // An iteration that consumed nothing would do the same again, so it ends the loop
+            Special(SpecialNode::RepeatLoop(counter, max))
+                if counters[*counter].0 >= *max || counters[*counter].1 == string_index => (),
+            Special(SpecialNode::RepeatExit(counter, min)) if counters[*counter].0 < *min => (),
+            // Getting to the end of a negative lookahead means what it was looking for is there
+            Special(SpecialNode::EndNegativeLookAhead) => cut(callstack, |token| matches!(token, NegativeLookahead(..))),
             Special(special_node) => {
                 use SpecialNode::*;
                 match special_node {
@@ -342,38 +182,40 @@ pub(crate) fn backtrack_pure_match(
                     }
                     EndLookAhead => {
                         let res = alt_stack.pop().unwrap();
-                        while let Some(token) = callstack.pop() {
-                            // println!("Removed {:?}", nodes.get(node_idx));
-                            if token == PopAltStack {
-                                break;
-                            }
-                        }
+                        cut(callstack, |token| *token == PopAltStack);
                         string_index = res.0;
                     }
                     StartNegativeLookAhead => {
                         // alt_stack.push((string_index, node_index + 1));
                         callstack.push(NegativeLookahead(string_index, node_index + 1));
                     }
-                    EndNegativeLookAhead => {
-                        // let (old_string_idx, _old_node_idx,) = alt_stack.pop().unwrap();
-                        while let Some(token) = callstack.pop() {
-                            // println!("Removed {:?}", nodes.get(node_idx));
-                            if let NegativeLookahead(_, _) = token {
-                                break;
-                            }
-                        }
-                        // string_index = old_string_idx;
-                    }
                     StartAtomic => {
                         callstack.push(Atomic);
                     }
-                    EndAtomic => {
-                        while let Some(token) = callstack.pop() {
-                            if token == Atomic {
-                                break;
-                            }
+                    EndAtomic => cut(callstack, |token| *token == Atomic),
+                    StartRepeat(counter) => {
+                        if counters.len() <= *counter {
+                            counters.resize(counter + 1, (0, 0));
                         }
+                        callstack.push(RestoreCounter(*counter, counters[*counter]));
+                        counters[*counter] = (0, string_index);
+                    }
+                    EndRepeat(counter, min) => {
+                        callstack.push(RestoreCounter(*counter, counters[*counter]));
+                        let (done, iteration_start) = counters[*counter];
+                        // The rest of the iterations could all be empty like this one, so count them as done
+                        let done = if iteration_start == string_index {
+                            done.saturating_add(1).max(*min)
+                        } else {
+                            done + 1
+                        };
+                        counters[*counter].0 = done;
+                    }
+                    RepeatLoop(counter, _) => {
+                        callstack.push(RestoreCounter(*counter, counters[*counter]));
+                        counters[*counter].1 = string_index;
                     }
+                    RepeatExit(..) => (),
                     _ => unimplemented!(),
                 }
                 match &node.children {
@@ -392,7 +234,26 @@ pub(crate) fn backtrack_pure_match(
                 }
                 continue 'outer;
             }
-            Behaviour(_) => {
+            // Going round a loop again after an iteration that consumed nothing would only repeat that iteration
+            Behaviour(BehaviourNode::NextIteration(counter)) if counters[*counter].1 == string_index => (),
+            Behaviour(behaviour_node) => {
+                let slot = match behaviour_node {
+                    BehaviourNode::CapGroup(number) => *number as usize * 2,
+                    BehaviourNode::EndCapGroup(number) => *number as usize * 2 + 1,
+                    BehaviourNode::StartIteration(counter) => {
+                        if counters.len() <= *counter {
+                            counters.resize(counter + 1, (0, 0));
+                        }
+                        callstack.push(RestoreCounter(*counter, counters[*counter]));
+                        counters[*counter].1 = string_index;
+                        usize::MAX
+                    }
+                    BehaviourNode::Transition | BehaviourNode::NextIteration(_) => usize::MAX,
+                };
+                if slot < slots.len() {
+                    callstack.push(RestoreCapture(slot, slots[slot]));
+                    slots[slot] = Some(string_index);
+                }
                 match &node.children {
                     Children::Multiple(vec) => {
                         if vec.len() == 2 {
@@ -416,9 +277,14 @@ pub(crate) fn backtrack_pure_match(
                     callstack.push(BackTrackToken::End);
                     continue 'outer;
                 }
+                None if ends.is_some() => ends.as_mut().unwrap().push(string_index),
                 None => {
                     callstack.clear();
-                    return true;
+                    if slots.len() >= 2 {
+                        slots[0] = Some(start_string_index);
+                        slots[1] = Some(string_index);
+                    }
+                    return Some((start_string_index, string_index));
                 }
             },
         }
@@ -450,6 +316,12 @@ pub(crate) fn backtrack_pure_match(
                     PopAltStack => {
                         alt_stack.pop();
                     }
+                    RestoreCounter(counter, value) => {
+                        counters[counter] = value;
+                    }
+                    RestoreCapture(slot, value) => {
+                        slots[slot] = value;
+                    }
                     NegativeLookahead(string_idx, node_idx) => {
                         let node = nodes.get(node_idx).unwrap();
                         string_index = string_idx;
@@ -458,7 +330,7 @@ pub(crate) fn backtrack_pure_match(
                                 if vec.len() == 2 {
                                     callstack.push(BackTrackToken::Single(string_index, unsafe { *vec.get_unchecked(1) }));
                                 } else {
-                                    callstack.push(BackTrackToken::Multiple(string_index, node_index, 1));
+                                    callstack.push(BackTrackToken::Multiple(string_index, node_idx, 1));
                                 }
                                 node_index = *unsafe { vec.get_unchecked(0) };
                             }
@@ -472,24 +344,26 @@ pub(crate) fn backtrack_pure_match(
                     _ => (),
                 },
                 None => {
-                    start_string_index = next_utf8(string, start_string_index);
+                    // Nothing left to try from this start so move on to the next one
+                    if input.is_anchored() {
+                        return None;
+                    }
+                    start_string_index = next_index(string, start_string_index);
                     if let Some(root_node) = root_node {
-                        match root_node.run(string, start_string_index) {
-                            Some(idx) => {
-                                node_index = root_node.child;
-                                start_string_index = idx;
-                                string_index = idx;
-                                continue 'outer;
-                            }
-                            None => return false,
+                        let (candidate, resume) = root_node.run(string, start_string_index)?;
+                        if resume > end {
+                            return None;
                         }
-                    } else if start_string_index < string.len() {
+                        node_index = root_node.child;
+                        start_string_index = candidate;
+                        string_index = resume;
+                    } else if start_string_index <= end {
                         string_index = start_string_index;
                         node_index = start_node;
-                        continue 'outer;
                     } else {
-                        return false;
+                        return None;
                     }
+                    continue 'outer;
                 }
             }
         }
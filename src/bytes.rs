@@ -0,0 +1,127 @@
+use crate::config::RegexConfig;
+use crate::haystack::{next_index, RawBytes, Units};
+use crate::error::Error;
+use crate::regex;
+
+// What to search and how over any bytes, a span can start and end anywhere
+pub type Input<'h> = regex::Input<'h, [u8]>;
+
+// Same patterns as regex::Regex but the haystack doesn't have to be valid UTF-8
+// Bytes that aren't part of a valid UTF-8 sequence are each one character that only . and negated classes like [^a] take
+// (?-u) at the start of the pattern makes the search go byte by byte, then ., classes and \xHH match single bytes and
+// characters that aren't ASCII match their UTF-8 encoding
+#[derive(Clone, Debug)]
+pub struct Regex {
+    pub(crate) regex: regex::Regex,
+}
+
+impl Regex {
+    // Panics if the pattern goes over any of the default limits, use try_new for patterns that aren't trusted
+    pub fn new(regex: &str) -> Self {
+        return Self::try_new(regex).unwrap();
+    }
+
+    pub fn try_new(regex: &str) -> Result<Self, Error> {
+        return Self::with_config(regex, RegexConfig::default());
+    }
+
+    pub fn with_config(regex: &str, config: RegexConfig) -> Result<Self, Error> {
+        return Ok(Self {
+            regex: regex::Regex::build(regex, config, true)?,
+        });
+    }
+
+    pub fn as_str(&self) -> &str {
+        return &self.regex.expr;
+    }
+
+    pub fn is_match(&self, haystack: &[u8]) -> bool {
+        return self.search_span(&Input::new(haystack).earliest(true)).is_some();
+    }
+
+    pub fn find<'h>(&self, haystack: &'h [u8]) -> Option<Match<'h>> {
+        return self.search(&Input::new(haystack));
+    }
+
+    // Searching starts at the offset but anchors still see what comes before it
+    pub fn find_at<'h>(&self, haystack: &'h [u8], start: usize) -> Option<Match<'h>> {
+        if start > haystack.len() {
+            return None;
+        }
+        return self.search(&Input::new(haystack).span(start..haystack.len()));
+    }
+
+    pub fn find_iter<'r, 'h>(&'r self, haystack: &'h [u8]) -> Matches<'r, 'h> {
+        return Matches::new(self, Input::new(haystack));
+    }
+
+    pub fn captures<'h>(&self, haystack: &'h [u8]) -> Option<Captures<'h>> {
+        return self.search_captures(&Input::new(haystack));
+    }
+
+    pub fn captures_iter<'r, 'h>(&'r self, haystack: &'h [u8]) -> CaptureMatches<'r, 'h> {
+        return CaptureMatches::new(Matches::new(self, Input::new(haystack)));
+    }
+
+    pub fn search<'h>(&self, input: &Input<'h>) -> Option<Match<'h>> {
+        let (start, end) = self.search_span(input)?;
+        return Some(Match::new(input.haystack(), start, end));
+    }
+
+    pub fn search_captures<'h>(&self, input: &Input<'h>) -> Option<Captures<'h>> {
+        let slots = match self.regex.units {
+            Units::Bytes => self.regex.search_slots(&input.with_haystack(&RawBytes(input.haystack())))?,
+            Units::Utf8 => self.regex.search_slots(input)?,
+        };
+        return Some(Captures::new(input.haystack(), slots, self.regex.group_names.clone()));
+    }
+
+    // With (?-u) the engines see every byte on its own
+    pub(crate) fn search_span(&self, input: &Input) -> Option<(usize, usize)> {
+        return match self.regex.units {
+            Units::Bytes => self.regex.search_span(&input.with_haystack(&RawBytes(input.haystack()))),
+            Units::Utf8 => self.regex.search_span(input),
+        };
+    }
+
+    // Where the search carries on from after an empty match
+    pub(crate) fn next_index(&self, haystack: &[u8], i: usize) -> usize {
+        return match self.regex.units {
+            Units::Bytes => i + 1,
+            Units::Utf8 => next_index(haystack, i),
+        };
+    }
+}
+
+impl regex::FindMatches<[u8]> for Regex {
+    fn search_span(&self, input: &Input) -> Option<(usize, usize)> {
+        return Regex::search_span(self, input);
+    }
+
+    fn search_captures<'h>(&self, input: &Input<'h>) -> Option<Captures<'h>> {
+        return Regex::search_captures(self, input);
+    }
+
+    fn next_index(&self, haystack: &[u8], at: usize) -> usize {
+        return Regex::next_index(self, haystack, at);
+    }
+}
+
+// A match in the haystack, positions are byte offsets
+pub type Match<'h> = regex::Match<'h, [u8]>;
+
+impl<'h> Match<'h> {
+    pub fn as_bytes(&self) -> &'h [u8] {
+        return self.slice();
+    }
+}
+
+// Where each capture group matched, group 0 being the whole match
+pub type Captures<'h> = regex::Captures<'h, [u8]>;
+
+// Successive non-overlapping matches, the same rules as regex::Matches
+// After an empty match the search moves on by one character, or one byte if it isn't valid UTF-8
+pub type Matches<'r, 'h> = regex::Matches<'r, 'h, [u8]>;
+
+// Capture groups of each match find_iter would return
+pub type CaptureMatches<'r, 'h> = regex::CaptureMatches<'r, 'h, [u8]>;
@@ -1,6 +1,7 @@
 use super::fnv::FnvHashMap;
 use super::nfa::Node::*;
 use super::sorted_vec::SortedVec;
+use crate::haystack::Haystack;
 
 trait Offset {
     fn offset(&mut self, num: usize) -> ();
@@ -28,6 +29,7 @@ pub(crate) enum CNode {
     Anchor(AnchorNode),
     Behaviour(BehaviourNode),
     Special(SpecialNode),
+    Sequence(SequenceNode),
     End,
 }
 
@@ -43,6 +45,9 @@ pub(crate) enum BehaviourNode {
     Transition,
     CapGroup(u32),
     EndCapGroup(u32),
+    // Counter index, the same ones counted repetitions use
+    StartIteration(usize),
+    NextIteration(usize),
 }
 
 #[derive(Clone, Debug)]
@@ -60,6 +65,30 @@ pub(crate) enum SpecialNode {
     EndLookBack,
     StartVariableLookback(usize, usize),
     BackRef(u32),
+    // Counter index and the bound to check against
+    StartRepeat(usize),
+    EndRepeat(usize, usize),
+    RepeatLoop(usize, usize),
+    RepeatExit(usize, usize),
+}
+
+// Match more than one character at once, only supported by the backtracker
+#[derive(Clone, Debug)]
+pub(crate) enum SequenceNode {
+    GraphemeCluster,
+    LineBreak,
+}
+
+impl SequenceNode {
+    // Number of code units matched starting at index without going past end
+    #[inline]
+    pub fn match_len<H: Haystack + ?Sized>(&self, string: &H, index: usize, end: usize) -> Option<usize> {
+        use crate::haystack::{grapheme_len, line_break_len};
+        return match self {
+            Self::GraphemeCluster => grapheme_len(string, index, end),
+            Self::LineBreak => line_break_len(string, index, end),
+        };
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -90,6 +119,12 @@ pub(crate) enum AnchorNode {
     NotWordBoundary,
     StartOfString,
     EndOfString,
+    // \b{w} and \b{g}, UAX #29 word and grapheme cluster boundaries
+    UnicodeWordBoundary,
+    GraphemeBoundary,
+    // \< and \>, also \b{start} and \b{end}
+    StartOfWord,
+    EndOfWord,
 }
 
 impl AnchorNode {
@@ -139,50 +174,36 @@ impl AnchorNode {
     //     };
     // }
 
-    pub fn is_match_bytes(&self, index: usize, length: usize, current_char: Option<char>, previous_char: Option<char>) -> bool {
-        return match self {
-            Self::StartOfString => index == 0,
-            Self::EndOfString => index == length,
-            Self::BeginningOfLine => index == 0 || previous_char.map(|c| c == '\n').is_true(),
-            Self::EndOfLine => index == length || current_char.map(|c| c == '\n').is_true(),
-            Self::WordBoundary => {
-                (index == 0 && current_char.map(|c| c._is_alphanumeric()).is_true())
-                    || (index == length && previous_char.map(|c| c._is_alphanumeric()).is_true())
-                    || (previous_char.map(|c| c._is_alphanumeric()).is_true() && current_char.map(|c| c._is_alphanumeric()).is_false())
-                    || (current_char.map(|c| c._is_alphanumeric()).is_true() && previous_char.map(|c| c._is_alphanumeric()).is_false())
-            }
-            Self::NotWordBoundary => {
-                !((index == 0 && current_char.map(|c| c._is_alphanumeric()).is_true())
-                    || (index == length && previous_char.map(|c| c._is_alphanumeric()).is_true())
-                    || (previous_char.map(|c| c._is_alphanumeric()).is_true() && current_char.map(|c| c._is_alphanumeric()).is_false())
-                    || (current_char.map(|c| c._is_alphanumeric()).is_true() && previous_char.map(|c| c._is_alphanumeric()).is_false()))
-            }
-        };
-    }
-
-    pub fn is_match(&self, index: usize, string: &[u8], current: Option<(char, usize)>) -> bool {
-        use crate::utf_8::{decode_last_utf8, decode_utf8};
+    pub fn is_match<H: Haystack + ?Sized>(&self, index: usize, string: &H, current: Option<(char, usize)>) -> bool {
         return match self {
             Self::StartOfString => index == 0,
             Self::EndOfString => index == string.len(),
-            Self::BeginningOfLine => index == 0 || decode_last_utf8(&string[..index]).map(|c| c.0 == '\n').is_true(),
+            Self::BeginningOfLine => index == 0 || string.prev_char(index).map(|c| c.0 == '\n').is_true(),
             Self::EndOfLine => index == string.len() || current.map(|c| c.0 == '\n').is_true(),
             Self::WordBoundary => {
                 (index == 0 && current.map(|c| c.0._is_alphanumeric()).is_true())
-                    || (index == string.len() && decode_last_utf8(&string[..index]).map(|c| c.0._is_alphanumeric()).is_true())
-                    || (decode_last_utf8(&string[..index]).map(|c| c.0._is_alphanumeric()).is_true()
+                    || (index == string.len() && string.prev_char(index).map(|c| c.0._is_alphanumeric()).is_true())
+                    || (string.prev_char(index).map(|c| c.0._is_alphanumeric()).is_true()
                         && current.map(|c| c.0._is_alphanumeric()).is_false())
                     || (current.map(|c| c.0._is_alphanumeric()).is_true()
-                        && decode_last_utf8(&string[..index]).map(|c| c.0._is_alphanumeric()).is_false())
+                        && string.prev_char(index).map(|c| c.0._is_alphanumeric()).is_false())
             }
             Self::NotWordBoundary => {
                 !((index == 0 && current.map(|c| c.0._is_alphanumeric()).is_true())
-                    || (index == string.len() && decode_last_utf8(&string[..index]).map(|c| c.0._is_alphanumeric()).is_true())
-                    || (decode_last_utf8(&string[..index]).map(|c| c.0._is_alphanumeric()).is_true()
+                    || (index == string.len() && string.prev_char(index).map(|c| c.0._is_alphanumeric()).is_true())
+                    || (string.prev_char(index).map(|c| c.0._is_alphanumeric()).is_true()
                         && current.map(|c| c.0._is_alphanumeric()).is_false())
                     || (current.map(|c| c.0._is_alphanumeric()).is_true()
-                        && decode_last_utf8(&string[..index]).map(|c| c.0._is_alphanumeric()).is_false()))
+                        && string.prev_char(index).map(|c| c.0._is_alphanumeric()).is_false()))
+            }
+            Self::StartOfWord => {
+                current.map(|c| c.0._is_alphanumeric()).is_true() && string.prev_char(index).map(|c| c.0._is_alphanumeric()).is_false()
+            }
+            Self::EndOfWord => {
+                string.prev_char(index).map(|c| c.0._is_alphanumeric()).is_true() && current.map(|c| c.0._is_alphanumeric()).is_false()
             }
+            Self::UnicodeWordBoundary => crate::segmentation::is_word_boundary(string, index),
+            Self::GraphemeBoundary => crate::segmentation::is_grapheme_boundary(string, index),
         };
     }
 }
@@ -208,6 +229,7 @@ impl CompiledNode {
         }
 
         let start: usize;
+        let first_child = nodes[0].get_children().unwrap()[0];
         if nodes.get(0).unwrap().get_children().unwrap().len() == 1 {
             start = 1;
         } else {
@@ -238,7 +260,9 @@ impl CompiledNode {
                 }
             }
         }
-        (cnodes, start, flag)
+        // The first node gets skipped if it only has one child, so the program starts at wherever that child ended up
+        let root = if start == 1 { *old_to_new.get(&first_child).unwrap() } else { 0 };
+        (cnodes, root, flag)
     }
 }
 
@@ -309,7 +333,7 @@ impl MatchNode {
                     Inclusive(chars) => chars.contains(character),
                     Exclusive(chars) => !chars.contains(character),
                     InclusiveRange(characters) => characters.find(character),
-                    ExclusiveRange(characters) => characters.find(character),
+                    ExclusiveRange(characters) => !characters.find(character),
                 }
             }
         }
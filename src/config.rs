@@ -1,17 +1,34 @@
 #[derive(Copy, Clone, Debug)]
 pub struct RegexConfig {
-    dotall: bool,
-    enforce_linear_time_match: bool,
-    multithreading: bool,
+    pub dotall: bool,
+    // Same as starting the pattern with (?i) and (?x)
+    pub case_insensitive: bool,
+    pub ignore_whitespace: bool,
+    // Off is the same as starting the pattern with (?-u), every byte is then its own character
+    pub unicode: bool,
+    pub enforce_linear_time_match: bool,
+    pub multithreading: bool,
+    // Most nodes the program may have, checked while parsing and again before compiling
+    pub size_limit: usize,
+    // Deepest allowed nesting of groups
+    pub nest_limit: usize,
+    // Biggest number allowed in {n,m}
+    pub repetition_limit: usize,
 }
 
 impl Default for RegexConfig {
     fn default() -> Self {
         return RegexConfig {
             dotall: false,
+            case_insensitive: false,
+            ignore_whitespace: false,
+            unicode: true,
             enforce_linear_time_match: false,
             // Offload utf8 decoding and potentially matching to multiple threads
             multithreading: true,
+            size_limit: 1 << 20,
+            nest_limit: 250,
+            repetition_limit: 100_000,
         };
     }
 }
@@ -11,6 +11,12 @@ pub const WHITESPACE: &[char] = &[
     '\u{2003}', '\u{2004}', '\u{2005}', '\u{2003}', '\u{2006}', '\u{2007}', '\u{2008}', '\u{2009}', '\u{200A}', '\u{2028}', '\u{2029}', '\u{202F}',
     '\u{205F}', '\u{3000}',
 ];
+// \h and \v
+pub const HORIZONTAL_SPACE: &[char] = &[
+    '\u{0009}', '\u{0020}', '\u{00A0}', '\u{1680}', '\u{180E}', '\u{2000}', '\u{2001}', '\u{2002}', '\u{2003}', '\u{2004}', '\u{2005}', '\u{2006}',
+    '\u{2007}', '\u{2008}', '\u{2009}', '\u{200A}', '\u{202F}', '\u{205F}', '\u{3000}',
+];
+pub const VERTICAL_SPACE: &[char] = &['\u{000A}', '\u{000B}', '\u{000C}', '\u{000D}', '\u{0085}', '\u{2028}', '\u{2029}'];
 const EMOJI_RANGE: (u32, u32) = (128512, 128591);
 
 pub const W: [char; 63] = [
@@ -31,4 +37,4 @@ pub fn d() -> Vec<(char, char)> {
 
 pub fn w() -> Vec<(char, char)> {
     vec![('0','9'), ('A', 'Z'), ('_', '_'), ('a', 'z')]
-}
\ No newline at end of file
+}
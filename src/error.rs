@@ -0,0 +1,39 @@
+use std::fmt;
+
+// Returned instead of building a program that would take an unreasonable amount of memory, or for replacements that can't be used
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    // The program needs more nodes than the configured size limit
+    SizeLimitExceeded { limit: usize },
+    // Groups are nested deeper than the configured nesting limit
+    NestLimitExceeded { limit: usize },
+    // A bound of a counted repetition is bigger than the configured repetition limit
+    RepetitionLimitExceeded { limit: usize },
+    // The pattern isn't valid regex syntax
+    InvalidPattern { reason: String },
+    // A replacement template refers to a group the regex doesn't have
+    UnknownGroup { group: String },
+    // A substitution command isn't of the form s/pattern/replacement/flags
+    InvalidSubstitution { reason: String },
+    // The pattern turns Unicode off, which only works when searching bytes
+    UnicodeRequired,
+    // The pattern can't be run over a stream without holding on to all of it
+    Unstreamable { reason: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Self::SizeLimitExceeded { limit } => write!(f, "compiled regex exceeds the size limit of {} nodes", limit),
+            Self::NestLimitExceeded { limit } => write!(f, "regex exceeds the nesting limit of {} groups", limit),
+            Self::RepetitionLimitExceeded { limit } => write!(f, "repetition count exceeds the limit of {}", limit),
+            Self::InvalidPattern { reason } => write!(f, "invalid regex: {}", reason),
+            Self::UnknownGroup { group } => write!(f, "replacement refers to group {} which doesn't exist", group),
+            Self::InvalidSubstitution { reason } => write!(f, "invalid substitution command: {}", reason),
+            Self::UnicodeRequired => write!(f, "pattern can match invalid UTF-8, use bytes::Regex instead"),
+            Self::Unstreamable { reason } => write!(f, "pattern can't be searched as a stream: {}", reason),
+        };
+    }
+}
+
+impl std::error::Error for Error {}
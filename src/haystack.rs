@@ -0,0 +1,282 @@
+use crate::utf16::{decode_last_utf16, decode_utf16};
+use crate::utf_8::{decode_last_utf8, decode_utf8};
+
+// Stands in for units that aren't part of a valid character, patterns can't name it so only . and negated classes match it
+pub(crate) const INVALID: char = '\u{10FFFF}';
+
+// Text the engines can search, positions are in whatever units the haystack counts in, bytes for str and chars for [char]
+// The engines only ever ask for the characters on either side of a position so the text doesn't have to be in one piece
+pub trait Haystack {
+    // Position of the end of the text
+    fn len(&self) -> usize;
+
+    // Character starting at `at` and how many units it takes up, None at the end
+    // Units that aren't part of a valid character should come back as INVALID on their own so searching can carry on past them
+    fn next_char(&self, at: usize) -> Option<(char, usize)>;
+
+    // Same as next_char for the character that ends at `at`
+    fn prev_char(&self, at: usize) -> Option<(char, usize)>;
+
+    fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+
+    // Whether a span can start or end at `at`
+    fn is_char_boundary(&self, at: usize) -> bool {
+        return at <= self.len();
+    }
+
+    // First place at or after `at` the literal starts, worth overriding when there's something faster than going a character at a time
+    fn find_literal(&self, mut at: usize, literal: &str) -> Option<usize> {
+        while at <= self.len() {
+            let mut position = at;
+            let found = literal.chars().all(|c| match self.next_char(position) {
+                Some((next, len)) if next == c => {
+                    position += len;
+                    true
+                }
+                _ => false,
+            });
+            if found {
+                return Some(at);
+            }
+            at = next_index(self, at);
+        }
+        return None;
+    }
+}
+
+impl Haystack for str {
+    fn len(&self) -> usize {
+        return self.len();
+    }
+
+    #[inline(always)]
+    fn next_char(&self, at: usize) -> Option<(char, usize)> {
+        return self.as_bytes().next_char(at);
+    }
+
+    #[inline(always)]
+    fn prev_char(&self, at: usize) -> Option<(char, usize)> {
+        return self.as_bytes().prev_char(at);
+    }
+
+    fn is_char_boundary(&self, at: usize) -> bool {
+        return self.is_char_boundary(at);
+    }
+
+    fn find_literal(&self, at: usize, literal: &str) -> Option<usize> {
+        return self.as_bytes().find_literal(at, literal);
+    }
+}
+
+// UTF-8 that may not be valid
+impl Haystack for [u8] {
+    fn len(&self) -> usize {
+        return self.len();
+    }
+
+    #[inline(always)]
+    fn next_char(&self, at: usize) -> Option<(char, usize)> {
+        let rest = self.get(at..).filter(|rest| !rest.is_empty())?;
+        return Some(decode_utf8(rest).unwrap_or((INVALID, 1)));
+    }
+
+    #[inline(always)]
+    fn prev_char(&self, at: usize) -> Option<(char, usize)> {
+        let before = self.get(..at).filter(|before| !before.is_empty())?;
+        return Some(decode_last_utf8(before).unwrap_or((INVALID, 1)));
+    }
+
+    fn find_literal(&self, at: usize, literal: &str) -> Option<usize> {
+        return crate::utils::find_bytes(self.get(at..)?, literal.as_bytes()).map(|i| at + i);
+    }
+}
+
+// UTF-16 that may have unpaired surrogates
+impl Haystack for [u16] {
+    fn len(&self) -> usize {
+        return self.len();
+    }
+
+    #[inline(always)]
+    fn next_char(&self, at: usize) -> Option<(char, usize)> {
+        let rest = self.get(at..).filter(|rest| !rest.is_empty())?;
+        return Some(decode_utf16(rest).unwrap_or((INVALID, 1)));
+    }
+
+    #[inline(always)]
+    fn prev_char(&self, at: usize) -> Option<(char, usize)> {
+        let before = self.get(..at).filter(|before| !before.is_empty())?;
+        return Some(decode_last_utf16(before).unwrap_or((INVALID, 1)));
+    }
+
+    fn find_literal(&self, at: usize, literal: &str) -> Option<usize> {
+        let len = literal.encode_utf16().count();
+        let last_start = self.len().checked_sub(len)?;
+        return (at..=last_start).find(|i| self[*i..*i + len].iter().copied().eq(literal.encode_utf16()));
+    }
+}
+
+impl Haystack for [char] {
+    fn len(&self) -> usize {
+        return self.len();
+    }
+
+    #[inline(always)]
+    fn next_char(&self, at: usize) -> Option<(char, usize)> {
+        return self.get(at).map(|c| (*c, 1));
+    }
+
+    #[inline(always)]
+    fn prev_char(&self, at: usize) -> Option<(char, usize)> {
+        return self.get(at.checked_sub(1)?).map(|c| (*c, 1));
+    }
+}
+
+// Pieces of text searched as if they were one string, positions are byte offsets into all of them put together
+// Built once so that finding the piece a position falls in doesn't have to go through every piece before it
+#[derive(Clone, Debug)]
+pub struct Chunks<'a> {
+    chunks: &'a [&'a str],
+    // Offset each chunk starts at
+    starts: Vec<usize>,
+    len: usize,
+}
+
+impl<'a> Chunks<'a> {
+    pub fn new(chunks: &'a [&'a str]) -> Self {
+        let mut starts = Vec::with_capacity(chunks.len());
+        let mut len = 0;
+        for chunk in chunks {
+            starts.push(len);
+            len += chunk.len();
+        }
+        return Self { chunks, starts, len };
+    }
+
+    // Chunk that holds the byte at `at` and where the byte is in it, empty chunks are never picked
+    #[inline(always)]
+    fn locate(&self, at: usize) -> Option<(&'a str, usize)> {
+        if at >= self.len {
+            return None;
+        }
+        // Several chunks can start at the same place if some of them are empty, the last one has the byte
+        let index = self.starts.partition_point(|start| *start <= at) - 1;
+        return Some((self.chunks[index], at - self.starts[index]));
+    }
+}
+
+impl<'a> Haystack for Chunks<'a> {
+    fn len(&self) -> usize {
+        return self.len;
+    }
+
+    #[inline(always)]
+    fn next_char(&self, at: usize) -> Option<(char, usize)> {
+        let (chunk, offset) = self.locate(at)?;
+        return chunk.next_char(offset);
+    }
+
+    #[inline(always)]
+    fn prev_char(&self, at: usize) -> Option<(char, usize)> {
+        let (chunk, offset) = self.locate(at.checked_sub(1)?)?;
+        return chunk.prev_char(offset + 1);
+    }
+
+    fn is_char_boundary(&self, at: usize) -> bool {
+        return match self.locate(at) {
+            Some((chunk, offset)) => chunk.is_char_boundary(offset),
+            None => at == self.len,
+        };
+    }
+}
+
+// What a regex takes as one character of a byte haystack
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Units {
+    // UTF-8 sequences, a byte that isn't part of a valid sequence is INVALID on its own
+    Utf8,
+    // Every byte is the character with the same value, as with (?-u)
+    Bytes,
+}
+
+// Every byte on its own as the character with the same value, what bytes::Regex searches with (?-u)
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct RawBytes<'h>(pub &'h [u8]);
+
+impl<'h> Haystack for RawBytes<'h> {
+    fn len(&self) -> usize {
+        return self.0.len();
+    }
+
+    #[inline(always)]
+    fn next_char(&self, at: usize) -> Option<(char, usize)> {
+        return self.0.get(at).map(|b| (*b as char, 1));
+    }
+
+    #[inline(always)]
+    fn prev_char(&self, at: usize) -> Option<(char, usize)> {
+        return self.0.get(at.checked_sub(1)?).map(|b| (*b as char, 1));
+    }
+
+    fn find_literal(&self, at: usize, literal: &str) -> Option<usize> {
+        return self.0.find_literal(at, literal);
+    }
+}
+
+// Position of the character after the one at `at`, or one past the end
+#[inline(always)]
+pub(crate) fn next_index<H: Haystack + ?Sized>(haystack: &H, at: usize) -> usize {
+    return match haystack.next_char(at) {
+        Some((_, len)) => at + len,
+        None => at + 1,
+    };
+}
+
+// Character at `at` as long as it ends by `end`, anchors can look past the end of a span but nothing can be consumed there
+#[inline(always)]
+pub(crate) fn char_before_end<H: Haystack + ?Sized>(haystack: &H, at: usize, end: usize) -> Option<(char, usize)> {
+    if at >= end {
+        return None;
+    }
+    return haystack.next_char(at).filter(|(_, len)| at + len <= end);
+}
+
+// Character ending at `at` as long as it starts at or after `start`
+#[inline(always)]
+pub(crate) fn char_after_start<H: Haystack + ?Sized>(haystack: &H, start: usize, at: usize) -> Option<(char, usize)> {
+    if at <= start {
+        return None;
+    }
+    return haystack.prev_char(at).filter(|(_, len)| at - len >= start);
+}
+
+// Length of the extended grapheme cluster (UAX #29) starting at `at`, None at the end
+#[inline]
+pub(crate) fn grapheme_len<H: Haystack + ?Sized>(haystack: &H, at: usize, end: usize) -> Option<usize> {
+    use crate::segmentation::GraphemeState;
+    let (mut previous, mut len) = char_before_end(haystack, at, end)?;
+    let mut state = GraphemeState::default();
+    state.advance(previous);
+    while let Some((c, n)) = char_before_end(haystack, at + len, end) {
+        if state.is_break(previous, c) {
+            break;
+        }
+        state.advance(c);
+        previous = c;
+        len += n;
+    }
+    return Some(len);
+}
+
+// Length of the line break at `at`, either \r\n or any single vertical whitespace character
+#[inline]
+pub(crate) fn line_break_len<H: Haystack + ?Sized>(haystack: &H, at: usize, end: usize) -> Option<usize> {
+    use crate::constants::VERTICAL_SPACE;
+    return match char_before_end(haystack, at, end) {
+        Some(('\r', len)) if char_before_end(haystack, at + len, end).map(|c| c.0) == Some('\n') => Some(len + 1),
+        Some((c, len)) if VERTICAL_SPACE.contains(&c) => Some(len),
+        _ => None,
+    };
+}
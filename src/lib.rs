@@ -11,7 +11,7 @@ extern crate educe;
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::regex::Regex;
+    use crate::regex::{Captures, Regex};
     use config::*;
     use test::Bencher;
 
@@ -47,6 +47,14 @@ mod tests {
         assert_eq!(r.match_str("hell"), false);
     }
 
+    #[test]
+    fn empty_pattern() {
+        // Nothing but the end node is left after compiling these
+        assert_eq!(Regex::new("").match_str("b"), true);
+        assert_eq!(Regex::new("(?:)").match_str(""), true);
+        assert_eq!(Regex::new("()").match_str("b"), true);
+    }
+
     #[test]
     fn add_operator() {
         let r = Regex::new("a+b");
@@ -323,6 +331,157 @@ mod tests {
         assert_eq!(r.match_str("abdc"), true);
     }
 
+    #[test]
+    fn curly_brackets_zero() {
+        assert_eq!(Regex::new("^a{0,2}$").match_str(""), true);
+        assert_eq!(Regex::new("^a{0,2}$").match_str("aaa"), false);
+        assert_eq!(Regex::new("^x{,3}y$").match_str("xxxy"), true);
+        assert_eq!(Regex::new("^x{,3}y$").match_str("xxxxy"), false);
+        assert_eq!(Regex::new("^a{0}b$").match_str("b"), true);
+        assert_eq!(Regex::new("^(ab){0,}c$").match_str("ababc"), true);
+        assert_eq!(Regex::new("^(ab){2}$").match_str("abab"), true);
+        assert_eq!(Regex::new("^(ab){2}$").match_str("ababab"), false);
+    }
+
+    #[test]
+    fn large_curly_brackets() {
+        let r = Regex::new("^a{300}$");
+        assert_eq!(r.match_str(&"a".repeat(300)), true);
+        assert_eq!(r.match_str(&"a".repeat(299)), false);
+        assert_eq!(r.match_str(&"a".repeat(301)), false);
+        let r = Regex::new("^(?:a|bb){2,1000}c$");
+        assert_eq!(r.match_str("abbac"), true);
+        assert_eq!(r.match_str("ac"), false);
+        assert_eq!(r.match_str("abac"), false);
+        assert_eq!(Regex::new("^a{1,500}+a$").match_str("aaa"), false);
+        assert_eq!(Regex::new("^a{1,500}a$").match_str("aaa"), true);
+        let r = Regex::new(r"^(\w{1,100}){1,100}!$");
+        assert_eq!(r.match_str("hello!"), true);
+        assert_eq!(r.match_str("!"), false);
+        // Empty iterations stop the loop instead of going round until memory runs out
+        let r = Regex::new("^(?:a*){300,}$");
+        assert_eq!(r.match_str("aa"), true);
+        assert_eq!(r.match_str(""), true);
+        assert_eq!(r.match_str("ab"), false);
+        assert_eq!(Regex::new("^(?:a|b?){300,400}c$").match_str("abc"), true);
+        // Copied out rather than counted while it fits, so the parallel engine runs it instead of the backtracker trying
+        // every way of splitting the a's up
+        let now = std::time::Instant::now();
+        let r = Regex::new("^(?:a|aa){1,300}$");
+        assert_eq!(r.match_str(&("a".repeat(60) + "b")), false);
+        assert_eq!(r.match_str(&"a".repeat(60)), true);
+        assert!(now.elapsed() < std::time::Duration::from_secs(5));
+    }
+
+    #[test]
+    fn compile_limits() {
+        use crate::config::RegexConfig;
+        use crate::error::Error;
+        let config = RegexConfig {
+            size_limit: 50,
+            nest_limit: 2,
+            repetition_limit: 1000,
+            ..Default::default()
+        };
+        assert_eq!(Regex::with_config("a{10}", config).is_ok(), true);
+        // Too big to copy out within the limit, so they're counted instead
+        assert_eq!(Regex::with_config("a{20}b{20}", config).is_ok(), true);
+        assert_eq!(Regex::with_config(&"a".repeat(100), config).unwrap_err(), Error::SizeLimitExceeded { limit: 50 });
+        assert_eq!(Regex::with_config("((a))", config).is_ok(), true);
+        assert_eq!(Regex::with_config("(((a)))", config).unwrap_err(), Error::NestLimitExceeded { limit: 2 });
+        assert_eq!(Regex::with_config("a{1001}", config).unwrap_err(), Error::RepetitionLimitExceeded { limit: 1000 });
+        assert_eq!(Regex::try_new("a{1000000}").unwrap_err(), Error::RepetitionLimitExceeded { limit: 100_000 });
+        let nested = "(".repeat(300) + &")".repeat(300);
+        assert_eq!(Regex::try_new(&nested).unwrap_err(), Error::NestLimitExceeded { limit: 250 });
+        // Counted repetition keeps nested bounds from multiplying out past the size limit
+        let r = Regex::try_new("^((a{1000}){1000}){1000}$").unwrap();
+        assert!(r.node_vec.len() < 10_000);
+        assert_eq!(Regex::with_config("a{10}{10}", config).is_ok(), true);
+    }
+
+    #[test]
+    fn invalid_patterns() {
+        use crate::error::Error;
+        let invalid = |pattern: &str| matches!(Regex::try_new(pattern), Err(Error::InvalidPattern { .. }));
+        assert_eq!(Regex::try_new("a{99999999999999999999999}").unwrap_err(), Error::RepetitionLimitExceeded { limit: 100_000 });
+        for pattern in ["a{3,2}", "a{x}", "a{}", "a{2", "(", "(a))", ")", "(?", "(?y)", "(?i", "\\xZZ", "\\x1", "\\x{", "[a", "[z-a]", "\\", "\\c"] {
+            assert_eq!(invalid(pattern), true, "{}", pattern);
+        }
+        // Quantifiers need something to repeat
+        for pattern in ["*a", "+", "a|?", "(*a)", "a**", "^*", "{2}"] {
+            assert_eq!(invalid(pattern), true, "{}", pattern);
+        }
+        assert_eq!(Regex::try_new("a*?").is_ok(), true);
+        assert_eq!(Regex::try_new("(a)*+").is_ok(), true);
+        // An empty alternative is still tried in its place
+        assert_eq!(Regex::new("a??|").match_indices("ab"), vec![(0, 0), (1, 1), (2, 2)]);
+        assert_eq!(Regex::new("(?:a*)*b").match_indices("aab"), vec![(0, 3)]);
+    }
+
+    #[test]
+    fn find() {
+        let r = Regex::new("ab");
+        let m = r.find("xaab").unwrap();
+        assert_eq!((m.start(), m.end()), (2, 4));
+        assert_eq!(m.range(), 2..4);
+        assert_eq!(m.as_str(), "ab");
+        assert_eq!(r.find("ba"), None);
+        assert_eq!(Regex::new("[0-9]+").find("abc123def").unwrap().as_str(), "123");
+        assert_eq!(Regex::new("caf\u{e9}").find("un caf\u{e9}").unwrap().range(), 3..8);
+        // Greedy and lazy decide where the match ends
+        assert_eq!(Regex::new("a+").find("baaa").unwrap().as_str(), "aaa");
+        assert_eq!(Regex::new("a+?").find("baaa").unwrap().as_str(), "a");
+        assert_eq!(Regex::new("(ab)+").find("xababab").unwrap().as_str(), "ababab");
+        assert_eq!(Regex::new("(ab)+?").find("xababab").unwrap().as_str(), "ab");
+        assert_eq!(Regex::new("b*?c").find("abbc").unwrap().as_str(), "bbc");
+        // The first alternative that matches wins, not the longest
+        assert_eq!(Regex::new("x|xy").find("xy").unwrap().as_str(), "x");
+        assert_eq!(Regex::new("xy|x").find("xy").unwrap().as_str(), "xy");
+        assert_eq!(Regex::new("a*").find("baaa").unwrap().range(), 0..0);
+        assert_eq!(Regex::new("$").find("abc").unwrap().range(), 3..3);
+    }
+
+    #[test]
+    fn find_backtracking() {
+        assert_eq!(Regex::new(r"(?=b)b").find("ab").unwrap().range(), 1..2);
+        assert_eq!(Regex::new("a{2,300}").find("baaab").unwrap().range(), 1..4);
+        assert_eq!(Regex::new(r"\X").find("e\u{301}x").unwrap().as_str(), "e\u{301}");
+        assert_eq!(Regex::new(r"(?>a+)b").find("aaac aab").unwrap().range(), 5..8);
+        assert_eq!(Regex::new(r"(?=b)b").find("aaa"), None);
+    }
+
+    #[test]
+    fn find_after_failed_anchor() {
+        // The root node skips ahead to the next place the first character matches, past where the anchor failed
+        assert_eq!(Regex::new(r"\d$").is_match("123"), true);
+        assert_eq!(Regex::new(r"\d$").find("123").unwrap().range(), 2..3);
+        assert_eq!(Regex::new("a$").find("aaa").unwrap().range(), 2..3);
+        assert_eq!(Regex::new(r"a\b").find("aaa").unwrap().range(), 2..3);
+        assert_eq!(Regex::new("x$").find("xxx").unwrap().range(), 2..3);
+    }
+
+    #[test]
+    fn find_iter() {
+        let ranges = |r: &Regex, s: &str| r.find_iter(s).map(|m| m.range()).collect::<Vec<_>>();
+        let r = Regex::new("[0-9]+");
+        assert_eq!(ranges(&r, "a1b22c333"), vec![1..2, 3..5, 6..9]);
+        assert_eq!(r.find_iter("1 2 3").map(|m| m.as_str()).collect::<Vec<_>>(), vec!["1", "2", "3"]);
+        // No empty match right after a match and empty matches step over whole characters
+        assert_eq!(ranges(&Regex::new("a*"), "baaa"), vec![0..0, 1..4]);
+        assert_eq!(ranges(&Regex::new("a*"), "baaab"), vec![0..0, 1..4, 5..5]);
+        assert_eq!(ranges(&Regex::new(""), "\u{e9}a"), vec![0..0, 2..2, 3..3]);
+        assert_eq!(ranges(&Regex::new("a|"), "ab"), vec![0..1, 2..2]);
+        // Same again on the backtracker
+        assert_eq!(ranges(&Regex::new("a{0,300}"), "baaa"), vec![0..0, 1..4]);
+        assert_eq!(ranges(&Regex::new("a{0,300}"), "baaab"), vec![0..0, 1..4, 5..5]);
+        assert_eq!(ranges(&Regex::new("(?=a)|b"), "ab"), vec![0..0, 1..2]);
+        assert_eq!(ranges(&Regex::new("x(a)|b"), "bxa"), vec![0..1, 1..3]);
+        // Lazy, so only as much of the haystack is searched as needed
+        let haystack = "x1".repeat(100_000);
+        assert_eq!(r.find_iter(&haystack).take(2).map(|m| m.start()).collect::<Vec<_>>(), vec![1, 3]);
+        assert_eq!(r.match_indices("a1b22"), vec![(1, 2), (3, 5)]);
+    }
+
     #[test]
     fn positive_lookahead() {
         let r = Regex::new("^abc(?=def)d");
@@ -357,6 +516,15 @@ mod tests {
         assert!(r.is_match(r#""abc"x"#));
     }
 
+    #[test]
+    fn possessive_exact_repetition() {
+        // The + only makes {2} possessive, it doesn't repeat it again
+        let r = Regex::new("^a{2}+$");
+        assert_eq!(r.match_str("aa"), true);
+        assert_eq!(r.match_str("aaaa"), false);
+        assert_eq!(Regex::new("a{2,}+a").match_str("aaa"), false);
+    }
+
     #[test]
     fn lazy() {
         let r = Regex::new("[ab]+?(?>b)c");
@@ -368,6 +536,69 @@ mod tests {
         assert_eq!(vec![(0,14)], r.match_indices("wwawwwwwwwwwwa"));
     }
 
+    #[test]
+    fn lazy_loops() {
+        // A lazy loop tries leaving before going round again, in either engine
+        assert_eq!(Regex::new("(ab)+?").match_indices("ababab")[0], (0, 2));
+        assert_eq!(Regex::new("(?=a)(ab)+?").match_indices("ababab")[0], (0, 2));
+        assert_eq!(Regex::new("(?=a)a*?").match_indices("aa")[0], (0, 0));
+        assert_eq!(Regex::new("(?=a)(?:ab)*?c").match_indices("ababc")[0], (0, 5));
+    }
+
+    #[test]
+    fn empty_loop_iterations() {
+        // A group that can match nothing doesn't go round again once an iteration consumes nothing
+        assert_eq!(Regex::new("(?=a)(a*)*").match_indices("aab"), vec![(0, 2)]);
+        assert_eq!(Regex::new("(?=a)(?:a|)+b").match_indices("aab"), vec![(0, 3)]);
+        assert_eq!(Regex::new("(?=a)(?:a*?)*?b").match_indices("aab"), vec![(0, 3)]);
+        assert_eq!(Regex::new("^(?=a)(?:a*){2,}$").match_str("aa"), true);
+        assert_eq!(Regex::new("(a*)*").match_indices("aab"), vec![(0, 2), (3, 3)]);
+        // Stacked quantifiers each put their own loop around a group or a counted repetition
+        assert_eq!(Regex::new("a{2}*?").match_indices("aa"), vec![(0, 0), (1, 1), (2, 2)]);
+        assert_eq!(Regex::new("(?=a)a{2}*?b").match_indices("aaaab"), vec![(0, 5)]);
+        assert_eq!(Regex::new("(?=a)(a)**").captures("aab").map(|caps| caps.get(1).unwrap().range()), Some(1..2));
+        assert_eq!(Regex::new("(?=a)\\w{1,3}*+\\w{2}").match_indices("aab"), vec![]);
+    }
+
+    #[test]
+    fn parallel_nfa_matches() {
+        assert_eq!(Regex::new("a+?").match_indices("baaa"), vec![(1, 2), (2, 3), (3, 4)]);
+        assert_eq!(Regex::new("a*").match_indices("baaa"), vec![(0, 0), (1, 4)]);
+        assert_eq!(Regex::new("$").match_indices("abc"), vec![(3, 3)]);
+        assert_eq!(Regex::new("c$").match_indices("abc\nc"), vec![(2, 3), (4, 5)]);
+        assert_eq!(Regex::new("^b").match_indices("a\nb"), vec![(2, 3)]);
+        assert_eq!(Regex::new(r"[a ]\<b").match_indices("a b"), vec![(1, 3)]);
+        assert_eq!(Regex::new(r"\<b").match_str("ab"), false);
+        assert_eq!(Regex::new(r"\b{w}b").match_str("ab"), false);
+    }
+
+    #[test]
+    fn backtrack_match_indices() {
+        // Each match is searched for on its own, so the last character and the end of the string can still start one
+        assert_eq!(Regex::new(r"\X").match_indices("e\u{301}x"), vec![(0, 3), (3, 4)]);
+        assert_eq!(Regex::new("(?=x)?$").match_indices("ab"), vec![(2, 2)]);
+        assert_eq!(Regex::new("(?=b)b").match_indices("abab"), vec![(1, 2), (3, 4)]);
+    }
+
+    #[test]
+    fn alternation_after_group() {
+        // An alternative after a group branches off from the start of the whole expression, not from inside the group
+        assert_eq!(Regex::new("(?=.)a|(?=.)").match_indices("ba"), vec![(0, 0), (1, 2)]);
+        assert_eq!(Regex::new("(?=[ab])ab|(?=[ab])b").match_indices("abb"), vec![(0, 2), (2, 3)]);
+        assert_eq!(Regex::new("(a)b|c").find("xc").unwrap().range(), 1..2);
+    }
+
+    #[test]
+    fn scoped_inline_flags() {
+        // A flag set inside a group is back to what it was once the group closes
+        let r = Regex::new("(?:(?i)a)a");
+        assert_eq!(r.match_str("Aa"), true);
+        assert_eq!(r.match_str("AA"), false);
+        let r = Regex::new("((?x) a )b c");
+        assert_eq!(r.match_str("ab c"), true);
+        assert_eq!(r.match_str("abc"), false);
+    }
+
     #[test]
     fn recurse() {
         let r = Regex::new(r"(?:a|b)(?R)?");
@@ -393,6 +624,727 @@ mod tests {
         let r = Regex::new(r"a{2,}+b");
     }
 
+    #[test]
+    fn escape_test() {
+        let special = r"a.b*c+d?e(f)g|h[i]j{2}k^l$m#n o\p-q";
+        assert_eq!(escape("1+1=2"), r"1\+1=2");
+        let r = Regex::new(&escape(special));
+        assert_eq!(r.match_str(special), true);
+        assert_eq!(r.match_str("axbbbc"), false);
+        let r = Regex::new(&format!("^{}$", escape("(?x) # not a comment")));
+        assert_eq!(r.match_str("(?x) # not a comment"), true);
+    }
+
+    #[test]
+    fn literal() {
+        let r = Regex::literal("a.c");
+        assert_eq!(r.match_str("xxa.cxx"), true);
+        assert_eq!(r.match_str("abc"), false);
+        let r = Regex::literal("ab");
+        assert_eq!(r.match_str("aaab"), true);
+        assert_eq!(r.match_str("aaa"), false);
+        let r = Regex::literal("");
+        assert_eq!(r.match_str("anything"), true);
+        assert_eq!(Regex::literal("a(b").expr, r"a\(b");
+        // A clone keeps the literal's needle and compiled program
+        let r = Regex::literal("a.c").clone();
+        assert_eq!(r.optimized_root_node.is_some(), true);
+        assert_eq!(r.match_str("xxa.cxx"), true);
+        assert_eq!(r.match_str("abc"), false);
+        let r = Regex::new(r"(?<x>a)(b)?").clone();
+        assert_eq!(r.captures("a").unwrap().name("x").unwrap().as_str(), "a");
+    }
+
+    #[test]
+    fn grapheme_cluster() {
+        let r = Regex::new(r"^\X$");
+        assert_eq!(r.match_str("e\u{301}"), true);
+        assert_eq!(r.match_str("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}"), true);
+        assert_eq!(r.match_str("\u{1F1EC}\u{1F1E7}"), true);
+        assert_eq!(r.match_str("\u{1F1EC}\u{1F1E7}\u{1F1EB}\u{1F1F7}"), false);
+        assert_eq!(r.match_str("\r\n"), true);
+        assert_eq!(r.match_str("ab"), false);
+        assert_eq!(Regex::new(r"^\X\X$").match_str("e\u{301}"), false);
+        assert_eq!(Regex::new(r"^\X{3}$").match_str("\u{D55C}\u{AE00}a"), true);
+        // Straight from the Unicode data, an extending mark and a prepended sign the old trimmed tables didn't have
+        assert_eq!(r.match_str("a\u{7FD}"), true);
+        assert_eq!(r.match_str("\u{890}a"), true);
+    }
+
+    #[test]
+    fn line_break_and_spaces() {
+        let r = Regex::new(r"^a\Rb$");
+        assert_eq!(r.match_str("a\r\nb"), true);
+        assert_eq!(r.match_str("a\nb"), true);
+        assert_eq!(r.match_str("a\u{2028}b"), true);
+        assert_eq!(r.match_str("a b"), false);
+        // \R is atomic, it won't give back the \n of a \r\n
+        assert_eq!(Regex::new(r"^a\R\nb$").match_str("a\r\nb"), false);
+        assert_eq!(Regex::new(r"^\h+$").match_str(" \t\u{3000}"), true);
+        assert_eq!(Regex::new(r"\h").match_str("\n"), false);
+        assert_eq!(Regex::new(r"^\v\H[\v]$").match_str("\nx\r"), true);
+        assert_eq!(Regex::new(r"\V").match_str("\n\r"), false);
+    }
+
+    #[test]
+    fn unicode_word_boundary() {
+        assert_eq!(Regex::new(r"^can\b").match_str("can't"), true);
+        assert_eq!(Regex::new(r"^can\b{w}").match_str("can't"), false);
+        assert_eq!(Regex::new(r"^can't\b{w}").match_str("can't stop"), true);
+        assert_eq!(Regex::new(r"^3\b").match_str("3.14"), true);
+        assert_eq!(Regex::new(r"^3\b{w}").match_str("3.14"), false);
+        assert_eq!(Regex::new(r"^3\.\b{w}").match_str("3. 14"), true);
+        // Every ideograph is a word of its own
+        assert_eq!(Regex::new("^\u{65E5}\\b{w}").match_str("\u{65E5}\u{672C}"), true);
+        assert_eq!(Regex::new("^\u{65E5}\\b").match_str("\u{65E5}\u{672C}"), false);
+        assert_eq!(Regex::new(r"^a\b{w}").match_str("ab"), false);
+        assert_eq!(Regex::new(r"a\b{w}b").match_str("ab"), false);
+        assert_eq!(Regex::new(r"\b{w}b").match_str("ab"), false);
+        assert_eq!(Regex::new("^\u{30A2}\\b{w}").match_str("\u{30A2}\u{1AFF0}"), false);
+    }
+
+    #[test]
+    fn invalid_boundaries() {
+        use crate::error::Error;
+        let invalid = |pattern: &str| matches!(Regex::try_new(pattern), Err(Error::InvalidPattern { .. }));
+        assert_eq!(invalid(r"\b{foo}"), true);
+        assert_eq!(invalid(r"\b{w"), true);
+        assert_eq!(invalid(r"\b{}"), true);
+        assert_eq!(Regex::try_new(r"\b{start}a").is_ok(), true);
+    }
+
+    #[test]
+    fn grapheme_boundary() {
+        assert_eq!(Regex::new(r"^e\b{g}").match_str("e\u{301}"), false);
+        assert_eq!(Regex::new(r"^a\b{g}").match_str("ab"), true);
+        assert_eq!(Regex::new("^\u{1F1EC}\\b{g}").match_str("\u{1F1EC}\u{1F1E7}"), false);
+        assert_eq!(Regex::new("^\u{1F1EC}\u{1F1E7}\\b{g}").match_str("\u{1F1EC}\u{1F1E7}\u{1F1EB}\u{1F1F7}"), true);
+    }
+
+    #[test]
+    fn start_and_end_of_word() {
+        let r = Regex::new(r"[a ]\<b");
+        assert_eq!(r.match_str(" b"), true);
+        assert_eq!(r.match_str("a b"), true);
+        assert_eq!(r.match_str("ab"), false);
+        assert_eq!(Regex::new(r"\<b").match_str("ab"), false);
+        let r = Regex::new(r"a\>");
+        assert_eq!(r.match_str("ba "), true);
+        assert_eq!(r.match_str("ab"), false);
+        assert_eq!(r.match_str("a"), true);
+        // Unlike \b these only match on one side of a word
+        assert_eq!(Regex::new(r"a\b{start}").match_str("a b"), false);
+        assert_eq!(Regex::new(r" \b{start}b").match_str("a b"), true);
+        assert_eq!(Regex::new(r"a\b{end} ").match_str("a b"), true);
+    }
+
+    #[test]
+    fn find_at() {
+        // Anchors still see the character before the offset
+        assert_eq!(Regex::new(r"\bb").find_at("ab", 1), None);
+        assert_eq!(Regex::new(r"^b").find_at("ab", 1), None);
+        assert_eq!(Regex::new(r"^b").is_match_at("ab", 1), false);
+        assert_eq!(Regex::new(r"b").is_match_at("ab", 1), true);
+        assert_eq!(Regex::new(r"a").is_match_at("ab", 1), false);
+        assert_eq!(Regex::new(r"(?=b)\w").find_at("abab", 2).map(|m| m.range()), Some(3..4));
+        assert_eq!(Regex::new(r"\w+").find_at("ab cd", 1).map(|m| m.range()), Some(1..2));
+        assert_eq!(Regex::new(r"a").find_at("a", 2), None);
+    }
+
+    #[test]
+    fn captures() {
+        let r = Regex::new(r"(a)(b)?c");
+        let caps = r.captures("xac").unwrap();
+        assert_eq!(caps.len(), 3);
+        assert_eq!(&caps[0], "ac");
+        assert_eq!(&caps[1], "a");
+        assert_eq!(caps.get(2), None);
+        assert_eq!(caps.get(1).map(|m| m.range()), Some(1..2));
+        assert_eq!(r.captures("abd"), None);
+        // Groups set on a path that was backtracked out of are cleared
+        let caps = Regex::new(r"(?:(a)x|ab)").captures("ab").unwrap();
+        assert_eq!(caps.get(1), None);
+        let caps = Regex::new(r"(?>(a+))(?=(b))").captures("aab").unwrap();
+        assert_eq!(&caps[1], "aa");
+        assert_eq!(&caps[2], "b");
+        assert_eq!(&caps[0], "aa");
+        let caps = Regex::new(r"(\w)(\w)").captures_at("abcd", 1).unwrap();
+        assert_eq!(&caps[1], "b");
+        assert_eq!(&caps[2], "c");
+        assert_eq!(Regex::new(r"^a(?!b)").is_match("ab"), false);
+        assert_eq!(Regex::new(r"^a(?!b)").is_match("ac"), true);
+    }
+
+    #[test]
+    fn search_input() {
+        use crate::regex::Input;
+        for r in [Regex::new(r"\d+"), Regex::new(r"(?>\d+)")].iter() {
+            let input = Input::new("a12 345b");
+            assert_eq!(r.search(&input).map(|m| m.range()), Some(1..3));
+            assert_eq!(r.search(&input.span(2..6)).map(|m| m.range()), Some(2..3));
+            assert_eq!(r.search(&input.span(5..8)).map(|m| m.range()), Some(5..7));
+            assert_eq!(r.search(&input.span(4..6)).map(|m| m.as_str()), Some("34"));
+            assert_eq!(r.search(&input.anchored(true)), None);
+            assert_eq!(r.search(&input.span(4..8).anchored(true)).map(|m| m.range()), Some(4..7));
+            assert_eq!(r.search(&input.span(0..1)), None);
+        }
+        // Anchors look past the span but matching stops at its end
+        let r = Regex::new(r"b\b");
+        assert_eq!(r.search(&Input::new("abc").span(0..2)), None);
+        assert_eq!(Regex::new(r"b").search(&Input::new("abc").span(0..2)).map(|m| m.range()), Some(1..2));
+        assert_eq!(Regex::new(r"c").search(&Input::new("abc").span(0..2)), None);
+        assert_eq!(Regex::literal("bc").search(&Input::new("abc").span(0..2)), None);
+        // Earliest stops as soon as a match is known
+        let r = Regex::new(r"a+");
+        assert_eq!(r.search(&Input::new("baaa").earliest(true)).map(|m| m.range()), Some(1..2));
+        assert_eq!(r.search(&Input::new("baaa")).map(|m| m.range()), Some(1..4));
+        let caps = Regex::new(r"(\w)(\w)").search_captures(&Input::new("abcd").span(1..4).anchored(true)).unwrap();
+        assert_eq!(&caps[0], "bc");
+        assert_eq!(&caps[2], "c");
+        assert_eq!(Regex::new(r"(\w)(\w)").search_captures(&Input::new("a bc").anchored(true)), None);
+    }
+
+    #[test]
+    fn full_match() {
+        let r = Regex::new(r"a|ab");
+        assert_eq!(r.is_full_match("ab"), true);
+        assert_eq!(r.is_full_match("a"), true);
+        assert_eq!(r.is_full_match("abc"), false);
+        assert_eq!(r.is_full_match("cab"), false);
+        assert_eq!(Regex::new(r"\d+").is_full_match("12a"), false);
+        assert_eq!(Regex::new(r"(?>\d+)").is_full_match("123"), true);
+        assert_eq!(Regex::new(r"(?>\d+)").is_full_match("x123"), false);
+        assert_eq!(Regex::new(r"").is_full_match(""), true);
+        assert_eq!(Regex::new(r"").is_full_match("a"), false);
+        assert_eq!(Regex::literal("ab").is_full_match("ab"), true);
+        assert_eq!(Regex::literal("ab").is_full_match("abab"), false);
+        // $ still only means the end of a line
+        assert_eq!(Regex::new(r"a$\n").is_full_match("a\n"), true);
+        let caps = Regex::new(r"(\w+?)(\d*)").full_captures("abc12").unwrap();
+        assert_eq!(&caps[1], "abc");
+        assert_eq!(&caps[2], "12");
+        assert_eq!(Regex::new(r"(\w)").full_captures("ab"), None);
+    }
+
+    #[test]
+    fn shortest_match() {
+        assert_eq!(Regex::new(r"a+").shortest_match("baaa"), Some(2));
+        assert_eq!(Regex::new(r"\w+c|b").shortest_match("abc"), Some(2));
+        assert_eq!(Regex::new(r"x").shortest_match("abc"), None);
+        assert_eq!(Regex::new(r"").shortest_match("abc"), Some(0));
+        assert_eq!(Regex::new(r"a+").shortest_match_at("aba", 1), Some(3));
+        // The backtracker only knows where the leftmost match ends
+        assert_eq!(Regex::new(r"(?>a+)").shortest_match("baaa"), Some(4));
+    }
+
+    #[test]
+    fn find_overlapping_iter() {
+        let ranges = |r: &Regex, s: &str, all_ends: bool| r.find_overlapping_iter(s).all_ends(all_ends).map(|m| m.range()).collect::<Vec<_>>();
+        for r in [Regex::new(r"aa"), Regex::new(r"a(?>a)")].iter() {
+            assert_eq!(ranges(r, "aaaa", false), vec![0..2, 1..3, 2..4]);
+            assert_eq!(ranges(r, "aaaa", true), vec![0..2, 1..3, 2..4]);
+        }
+        for r in [Regex::new(r"a+"), Regex::new(r"(?:a(?=a|$))+")].iter() {
+            assert_eq!(ranges(r, "baa", false), vec![1..3, 2..3]);
+            assert_eq!(ranges(r, "baa", true), vec![1..2, 1..3, 2..3]);
+        }
+        assert_eq!(ranges(&Regex::new(r"a*"), "ab", false), vec![0..1, 1..1, 2..2]);
+        assert_eq!(ranges(&Regex::new(r"ab|b"), "xab", true), vec![1..3, 2..3]);
+        assert_eq!(ranges(&Regex::new(r"é|"), "éa", true), vec![0..0, 0..2, 2..2, 3..3]);
+        // Each start only competes with threads from the same start, the same as searching from each start on its own
+        for r in [Regex::new(r"a|ab|b+"), Regex::new(r"a(?=.)|ab|b+")].iter() {
+            assert_eq!(ranges(r, "abb", false), vec![0..1, 1..3, 2..3]);
+            assert_eq!(ranges(r, "abb", true), vec![0..1, 0..2, 1..2, 1..3, 2..3]);
+        }
+        let long = "a".repeat(2000);
+        assert_eq!(Regex::new(r"a").find_overlapping_iter(&long).count(), 2000);
+    }
+
+    #[test]
+    fn rfind() {
+        let cases = [
+            (r"\d+", "12 345 6789x"),
+            (r"\d{2}:\d{2}", "at 10:15 and 11:45, then 12:00!"),
+            (r"[a-z]+|\d", "abc 1 de2"),
+            (r"\bé\w*", "éa bé éb"),
+            (r"x", "abc"),
+            (r"a*", "baab"),
+            (r"", "ab"),
+            (r"(?=\d)\d\w", "1a 2b"),
+            (r"(?>a+)b", "aab ab"),
+        ];
+        for (pattern, haystack) in cases.iter() {
+            let r = Regex::new(pattern);
+            let mut forward = r.find_iter(haystack).collect::<Vec<_>>();
+            assert_eq!(r.rfind(haystack), forward.last().cloned(), "{}", pattern);
+            forward.reverse();
+            assert_eq!(r.rfind_iter(haystack).collect::<Vec<_>>(), forward, "{}", pattern);
+        }
+        // Overlapping matches are counted from the start, like find_iter
+        assert_eq!(Regex::new(r"aa").rfind("aaa").map(|m| m.range()), Some(0..2));
+        assert_eq!(Regex::new(r"\w+").rfind("ab ").map(|m| m.range()), Some(0..2));
+        // Long enough to be searched in pieces
+        let long = "a".repeat(301) + " b1 " + &"x".repeat(500);
+        for pattern in [r"aa", r"a[^z]*z", r"\w+", r"a*"].iter() {
+            let r = Regex::new(pattern);
+            let mut forward = r.find_iter(&long).collect::<Vec<_>>();
+            forward.reverse();
+            assert_eq!(r.rfind_iter(&long).collect::<Vec<_>>(), forward, "{}", pattern);
+        }
+    }
+
+    #[test]
+    fn split() {
+        let r = Regex::new(r"\s*,\s*");
+        assert_eq!(r.split("a, b ,c").collect::<Vec<_>>(), vec!["a", "b", "c"]);
+        assert_eq!(r.split(",a,").collect::<Vec<_>>(), vec!["", "a", ""]);
+        assert_eq!(r.split("").collect::<Vec<_>>(), vec![""]);
+        assert_eq!(Regex::new(r"").split("ab").collect::<Vec<_>>(), vec!["", "a", "b", ""]);
+        assert_eq!(Regex::new(r"x*").split("axxb").collect::<Vec<_>>(), vec!["", "a", "b", ""]);
+        assert_eq!(r.splitn("a,b,c", 2).collect::<Vec<_>>(), vec!["a", "b,c"]);
+        assert_eq!(r.splitn("a,b,c", 5).collect::<Vec<_>>(), vec!["a", "b", "c"]);
+        assert_eq!(r.splitn("a,b,c", 0).count(), 0);
+        assert_eq!(r.rsplitn("a,b,c", 2).collect::<Vec<_>>(), vec!["c", "a,b"]);
+        assert_eq!(r.rsplitn("a,b,c", 5).collect::<Vec<_>>(), vec!["c", "b", "a"]);
+        let r = Regex::new(r"\n");
+        assert_eq!(r.split_inclusive("a\nb\n").collect::<Vec<_>>(), vec!["a\n", "b\n"]);
+        assert_eq!(r.split_inclusive("a\n\nb").collect::<Vec<_>>(), vec!["a\n", "\n", "b"]);
+        assert_eq!(r.split_inclusive("").count(), 0);
+    }
+
+    #[test]
+    fn segments() {
+        use crate::split::Segment::*;
+        let segments = |r: &str, s: &str| {
+            Regex::new(r)
+                .segments(s)
+                .map(|segment| match segment {
+                    Matched(m) => format!("[{}]", m.as_str()),
+                    Unmatched(text) => text.to_string(),
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(segments(r"\d+", "a1bc23"), vec!["a", "[1]", "bc", "[23]"]);
+        assert_eq!(segments(r"\d+", "12 3!"), vec!["[12]", " ", "[3]", "!"]);
+        assert_eq!(segments(r"\d+", ""), Vec::<String>::new());
+        assert_eq!(segments(r"x*", "ab"), vec!["[]", "a", "[]", "b", "[]"]);
+    }
+
+    #[test]
+    fn replace() {
+        use std::borrow::Cow;
+        let r = Regex::new(r"\d+");
+        assert_eq!(r.replace("a1b22c", "#"), "a#b22c");
+        assert_eq!(r.replace_all("a1b22c", "#"), "a#b#c");
+        assert_eq!(r.replacen("1 2 3 4", 2, "#"), "# # 3 4");
+        assert_eq!(r.replacen("1 2 3 4", 0, "#"), "# # # #");
+        assert_eq!(matches!(r.replace_all("abc", "#"), Cow::Borrowed(_)), true);
+        assert_eq!(Regex::new(r"x*").replace_all("ab", "-"), "-a-b-");
+        let replacement = String::from("<>");
+        assert_eq!(r.replace_all("a1", &replacement), "a<>");
+        let r = Regex::new(r"(\w)(\d)");
+        assert_eq!(r.replace_all("a1 b2", |caps: &Captures| format!("{}{}", &caps[2], &caps[1])), "1a 2b");
+        let mut count = 0;
+        let counted = Regex::new(r"(?>o)").replace_all("foo", |_: &Captures| {
+            count += 1;
+            count.to_string()
+        });
+        assert_eq!(counted, "f12");
+        assert_eq!(Regex::new(r"(a)|b").captures_iter("ab").map(|caps| caps.get(1).is_some()).collect::<Vec<_>>(), vec![true, false]);
+    }
+
+    #[test]
+    fn replacement_templates() {
+        use crate::error::Error;
+        use crate::replace::{NoExpand, Template};
+        let r = Regex::new(r"(?<year>\d{4})-(?P<month>\d{2})");
+        assert_eq!(r.replace_all("2024-05 2023-11", "$month/$year"), "05/2024 11/2023");
+        assert_eq!(r.replace_all("2024-05", "${1}x $2$$ $0"), "2024x 05$ 2024-05");
+        // Without braces the whole name is taken
+        assert_eq!(r.replace_all("2024-05", "$1x"), "");
+        assert_eq!(r.replace_all("2024-05", "$ ${ $"), "$ ${ $");
+        assert_eq!(r.replace_all("2024-05", NoExpand("$1")), "$1");
+        let caps = r.captures("on 2024-05").unwrap();
+        assert_eq!(&caps["year"], "2024");
+        assert_eq!(caps.name("day"), None);
+        let mut expanded = String::new();
+        caps.expand("[$year|$9|$day]", &mut expanded);
+        assert_eq!(expanded, "[2024||]");
+
+        let template = Template::parse("$2.$1");
+        assert_eq!(r.replace_all("2024-05", &template), "05.2024");
+        assert_eq!(r.replace_all("1999-12!", &template), "12.1999!");
+        // Strict templates are checked against the regex when they're made
+        assert_eq!(Template::parse("<$1$day>").strict(&r), Err(Error::UnknownGroup { group: String::from("day") }));
+        assert_eq!(Template::parse("$3").validate(&r), Err(Error::UnknownGroup { group: String::from("3") }));
+        assert_eq!(Template::parse("$month").validate(&r), Ok(()));
+        let strict = Template::parse("<$1$day>").strict(&Regex::new(r"(\d+)-(?<day>\d+)")).unwrap();
+        let mut expanded = String::new();
+        assert_eq!(strict.expand(&caps, &mut expanded), Err(Error::UnknownGroup { group: String::from("day") }));
+        // Used with a regex it wasn't checked against it still doesn't panic
+        assert_eq!(r.replace_all("2024-05", &strict), "<2024>");
+        // Groups that exist but didn't match are fine in strict templates
+        let optional = Regex::new(r"(a)|(b)");
+        let strict = Template::parse("[$1$2]").strict(&optional).unwrap();
+        assert_eq!(optional.replace_all("ab", &strict), "[a][b]");
+    }
+
+    #[test]
+    fn invalid_group_names() {
+        use crate::error::Error;
+        let invalid = |pattern: &str| matches!(Regex::try_new(pattern), Err(Error::InvalidPattern { .. }));
+        for pattern in ["(?<name", "(?<name)", "(?P<", "(?Pname>a)", "(?<>a)", "(?<a b>c)"] {
+            assert_eq!(invalid(pattern), true, "{}", pattern);
+        }
+        assert_eq!(Regex::new("(?<first_1>a)").captures("a").unwrap().name("first_1").is_some(), true);
+    }
+
+    #[test]
+    fn case_conversion_templates() {
+        use crate::replace::Template;
+        let camel = Template::parse_extended(r"\u$1");
+        assert_eq!(Regex::new(r"_(\w)").replace_all("snake_case_name", &camel), "snakeCaseName");
+        let r = Regex::new(r"(\w+) (\w+)");
+        assert_eq!(r.replace_all("hello World", &Template::parse_extended(r"\U$1\E $2")), "HELLO World");
+        assert_eq!(r.replace_all("hello World", &Template::parse_extended(r"\L$2 \u\L$1")), "world Hello");
+        assert_eq!(r.replace_all("ab cd", &Template::parse_extended(r"\Ux-$1\E-\l$2")), "X-AB-cd");
+        // Unicode case mapping can change the length
+        assert_eq!(Regex::new(r".+").replace_all("straße", &Template::parse_extended(r"\U$0")), "STRASSE");
+        assert_eq!(Regex::new(r"[^ ]+").replace_all("éa", &Template::parse_extended(r"\u$0")), "Éa");
+        // \u carries over a group that matched nothing
+        assert_eq!(Regex::new(r"(x?)(\w)").replace_all("a", &Template::parse_extended(r"\u$1$2")), "A");
+        assert_eq!(Regex::new(r"a").replace_all("a", &Template::parse_extended(r"\\ \n \")), r"\ \n \");
+        // Only extended templates treat backslashes specially
+        assert_eq!(Regex::new(r"a").replace_all("a", r"\U$0"), r"\Ua");
+    }
+
+    #[test]
+    fn flags() {
+        assert_eq!(Regex::new(r"a(?i)bC").find("xaBc").map(|m| m.range()), Some(1..4));
+        assert_eq!(Regex::new(r"(?i)b").is_match("aB"), true);
+        assert_eq!(Regex::new(r"A(?i)b").is_match("ab"), false);
+        assert_eq!(Regex::new(r"(?i)é").is_match("É"), true);
+        assert_eq!(Regex::new("(?x) a b # comment\n c").find("abc").map(|m| m.range()), Some(0..3));
+        assert_eq!(Regex::new(r"(?x)a\ b").is_match("a b"), true);
+        let config = RegexConfig {
+            case_insensitive: true,
+            dotall: true,
+            ..RegexConfig::default()
+        };
+        let r = Regex::with_config(r"a.B", config).unwrap();
+        assert_eq!(r.is_match("A\nb"), true);
+        assert_eq!(Regex::new(r"a.b").is_match("a\nb"), false);
+        // A flag sets its mode rather than flipping it, so repeating one or the config does nothing and - turns it off
+        assert_eq!(Regex::new(r"(?i)(?i)a").is_match("A"), true);
+        assert_eq!(Regex::new(r"(?i)a(?-i)b").is_match("AB"), false);
+        assert_eq!(Regex::new(r"(?i)a(?-i)b").is_match("Ab"), true);
+        assert_eq!(Regex::with_config(r"(?i)a", config).unwrap().is_match("A"), true);
+        assert_eq!(Regex::with_config(r"a(?-i)b", config).unwrap().is_match("AB"), false);
+        let config = RegexConfig {
+            ignore_whitespace: true,
+            ..RegexConfig::default()
+        };
+        assert_eq!(Regex::with_config(r"(?x)a b", config).unwrap().find("a b ab").map(|m| m.range()), Some(4..6));
+        assert_eq!(Regex::with_config(r"a(?-x) b", config).unwrap().is_match("a b"), true);
+        assert_eq!(Regex::new(r"(?x)(?x)a b").is_match("ab"), true);
+        // Ignoring case reaches into character classes, negated ones included
+        assert_eq!(Regex::new(r"(?i)[a-z]").is_match("A"), true);
+        assert_eq!(Regex::new(r"(?i)[éx]").is_match("É"), true);
+        assert_eq!(Regex::new(r"(?i)[^a-z]").is_match("A"), false);
+        assert_eq!(Regex::new(r"(?i)[^k]").is_match("K"), false);
+        assert_eq!(Regex::new(r"[a-z](?i)[a-z]").find("AaA").map(|m| m.range()), Some(1..3));
+    }
+
+    #[test]
+    fn negated_classes() {
+        assert_eq!(Regex::new(r"[^a-z]+").find("abC_1d").map(|m| m.range()), Some(2..5));
+        assert_eq!(Regex::new(r"[^a-zA-Z]").find("aB_").map(|m| m.range()), Some(2..3));
+        assert_eq!(Regex::new(r"a\D").find("a1 ab").map(|m| m.range()), Some(3..5));
+        assert_eq!(Regex::new(r"[\W]+").find("ab, c").map(|m| m.range()), Some(2..4));
+        assert_eq!(Regex::new(r"[^\d]+").find("12ab3").map(|m| m.range()), Some(2..4));
+        assert_eq!(Regex::new(r"[^\W\d]+").find("1a_b2").map(|m| m.range()), Some(1..4));
+        assert_eq!(Regex::new(r"[\w.]+").find(" a1.b ").map(|m| m.range()), Some(1..5));
+    }
+
+    #[test]
+    fn scoped_flags() {
+        assert_eq!(Regex::new(r"(a(?i)b)b").is_match("aBb"), true);
+        assert_eq!(Regex::new(r"(a(?i)b)b").is_match("aBB"), false);
+        assert_eq!(Regex::new(r"(?:(?x) a b )c d").is_match("abc d"), true);
+    }
+
+    #[test]
+    fn substitution() {
+        use crate::error::Error;
+        use crate::replace::Substitution;
+        let apply = |command: &str, s: &str| Substitution::parse(command).unwrap().apply(s).into_owned();
+        assert_eq!(apply(r"s/_(\w)/\u$1/g", "snake_case_name"), "snakeCaseName");
+        assert_eq!(apply(r"s/o/0/", "foo boo"), "f0o boo");
+        assert_eq!(apply(r"s/O/0/gi", "foo"), "f00");
+        assert_eq!(apply(r"s/[a-z]+/X/i", "ABC"), "X");
+        assert_eq!(apply(r"s/o/0/2", "foo boo"), "fo0 boo");
+        assert_eq!(apply(r"s/o/0/2g", "foo boo"), "fo0 b00");
+        assert_eq!(apply(r"s/x/y/", "foo"), "foo");
+        // Other delimiters and escaped delimiters
+        assert_eq!(apply(r"s#/usr/#/opt/#", "/usr/bin"), "/opt/bin");
+        assert_eq!(apply(r"s/a\/b/a\/c/", "a/b"), "a/c");
+        assert_eq!(apply(r"s|a\|b|x\|y|g", "a|b ab"), "x|y ab");
+        assert_eq!(apply(r"s,a.b,[$0],s", "a\nb"), "[a\nb]");
+        assert_eq!(apply(r"s/a.b/x/", "a\nb"), "a\nb");
+        assert_eq!(apply(r"s/ a b /x/x", "ab"), "x");
+        assert_eq!(apply(r"s/(?i)a/b/i", "A"), "b");
+
+        let invalid = |command: &str| match Substitution::parse(command) {
+            Err(Error::InvalidSubstitution { .. }) => true,
+            _ => false,
+        };
+        assert_eq!(invalid(r"y/a/b/"), true);
+        assert_eq!(invalid(r"s/a/b"), true);
+        assert_eq!(invalid(r"s/a"), true);
+        assert_eq!(invalid(r"s/a/b/q"), true);
+        assert_eq!(invalid(r"s/a/b/0"), true);
+        assert_eq!(invalid(r"s"), true);
+        assert_eq!(Substitution::parse(r"s/(a)/$2/").err(), Some(Error::UnknownGroup { group: String::from("2") }));
+        assert_eq!(Substitution::parse(r"s/a+/b/g").unwrap().regex().expr, "a+");
+    }
+
+    #[test]
+    fn replace_all_to_writer() {
+        use crate::replace::Template;
+        // Reads a few bytes at a time so matches and characters get cut up between chunks
+        struct Trickle<'a>(&'a [u8], usize);
+        impl<'a> std::io::Read for Trickle<'a> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                let len = self.1.min(self.0.len()).min(buf.len());
+                buf[..len].copy_from_slice(&self.0[..len]);
+                self.0 = &self.0[len..];
+                return Ok(len);
+            }
+        }
+        let stream = |r: &Regex, s: &str, replacement: &Template, chunk: usize| {
+            let mut out = Vec::new();
+            r.replace_all_to_writer(std::io::BufReader::with_capacity(chunk, Trickle(s.as_bytes(), chunk)), &mut out, replacement).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        let haystack = "at 10:15 é and 11:45é, then 12:00! aaaa ab a\nb ".repeat(20);
+        let cases = [(r"\d+", "<$0>"), (r"\b\w", "$0$0"), (r"a+b|é", "[$0]"), (r"x*", "-"), (r"(?>a{1,3})", "A"), (r"a(?=b)", "$0$0"), (r"\w+", ""), (r"b$", "B")];
+        for (pattern, replacement) in cases.iter() {
+            let r = Regex::new(pattern);
+            let template = Template::parse(replacement);
+            let expected = r.replace_all(&haystack, &template);
+            for chunk in [1, 2, 3, 7, 64].iter() {
+                assert_eq!(stream(&r, &haystack, &template, *chunk), expected, "{} with chunks of {}", pattern, chunk);
+            }
+        }
+        let mut out = Vec::new();
+        let invalid = Regex::new(r"a").replace_all_to_writer(&b"a\xffa"[..], &mut out, "b");
+        assert_eq!(invalid.map_err(|e| e.kind()), Err(std::io::ErrorKind::InvalidData));
+        // Patterns that would need the whole input kept around are turned down before anything is read
+        for pattern in [r"\b{w}", r"(?>a+)"] {
+            let mut out = Vec::new();
+            let error = Regex::new(pattern).replace_all_to_writer(&b"aa"[..], &mut out, "b").unwrap_err();
+            assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput, "{}", pattern);
+            assert_eq!(out.is_empty(), true);
+        }
+        // The backtracker relies on the longest a match can be to know what it can let go of
+        assert_eq!(Regex::new(r"\d+").max_match_len(), None);
+        assert_eq!(Regex::new(r"a(?=b)").max_match_len(), Some(8));
+    }
+
+    #[test]
+    fn multi_replacer() {
+        use crate::error::Error;
+        use crate::replace::MultiReplacer;
+        let rules = MultiReplacer::new(&[("cat", "dog"), ("dog", "cat"), ("(\\d)(\\d)", "$2$1"), ("(?<word>ca)\\w+", "<$word>")]).unwrap();
+        // Replacements aren't matched again so the rules don't feed into each other
+        assert_eq!(rules.replace_all("cat dog 12 camel"), "dog cat 21 <ca>");
+        // The earlier rule wins when both match at the same place, even when the later one would match more
+        assert_eq!(rules.replace_all("cats"), "dogs");
+        assert_eq!(rules.replace_all("nothing"), "nothing");
+        let rules = MultiReplacer::new(&[("b+", "B"), ("ab", "X")]).unwrap();
+        assert_eq!(rules.replace_all("abbb"), "XB");
+        // Inline flags stay inside their own rule
+        let rules = MultiReplacer::new(&[("(?i)a", "1"), ("b", "2")]).unwrap();
+        assert_eq!(rules.replace_all("ABab"), "1B12");
+        let rules = MultiReplacer::new(&[("(?x) a b # ends the rule", "1"), ("c d", "2"), ("(?x)e", "3")]).unwrap();
+        assert_eq!(rules.replace_all("ab c d cd e"), "1 2 cd 3");
+        assert_eq!(MultiReplacer::new::<&str, &str>(&[]).unwrap().replace_all("abc"), "abc");
+        assert_eq!(MultiReplacer::new(&[("a", "$1")]).err(), Some(Error::UnknownGroup { group: String::from("1") }));
+    }
+
+    #[test]
+    fn bytes_regex() {
+        use crate::bytes;
+        use crate::error::Error;
+        let r = bytes::Regex::new(r"(?-u)\xFF+");
+        assert_eq!(r.find(b"a\xFF\xFFb").map(|m| m.range()), Some(1..3));
+        assert_eq!(bytes::Regex::new(r"(?-u)^.$").is_match("é".as_bytes()), false);
+        assert_eq!(bytes::Regex::new(r"(?-u)^..$").is_match("é".as_bytes()), true);
+        assert_eq!(bytes::Regex::new(r"(?-u)[\x80-\xFF]+").find(b"ab\xC3\xA9").map(|m| m.range()), Some(2..4));
+        assert_eq!(bytes::Regex::new(r"(?-u)é").is_match("café".as_bytes()), true);
+        // Bytes or characters is decided for the whole pattern, so (?-u) can't be switched on part way through
+        for pattern in [r"(?:(?-u)\xFF)\xE9", r"(?:(?-u)z)|.", r"a(?-u)|[é]"] {
+            assert_eq!(bytes::Regex::try_new(pattern).is_err(), true);
+        }
+        // Without (?-u) valid UTF-8 is still a whole character and invalid bytes don't stop the search
+        assert_eq!(bytes::Regex::new(r"^.$").is_match("é".as_bytes()), true);
+        assert_eq!(bytes::Regex::new(r"b+").find(b"\xFFa\xFEbb").map(|m| m.range()), Some(3..5));
+        assert_eq!(bytes::Regex::new(r"a.c").is_match(b"a\xFFc"), true);
+        assert_eq!(bytes::Regex::new(r"[a-z]").find_iter(b"a\xFFb\xC3c").map(|m| m.start()).collect::<Vec<usize>>(), vec![0, 2, 4]);
+        assert_eq!(bytes::Regex::new(r"").find_iter(b"\xFF\xC3").count(), 3);
+        // An invalid byte isn't U+FFFD or anything else a pattern can name, only . and negated classes take it
+        assert_eq!(bytes::Regex::new(r"\x{FFFD}").is_match(b"\xFF"), false);
+        assert_eq!(bytes::Regex::new(r"[\x{FFFD}-\x{10FFFE}]").is_match(b"\xFF"), false);
+        assert_eq!(bytes::Regex::new(r"[^a]").is_match(b"\xFF"), true);
+        assert_eq!(bytes::Regex::new(r"\W").is_match(b"\xFF"), true);
+        assert_eq!(bytes::Regex::new(r"\x{FFFD}").is_match("\u{FFFD}".as_bytes()), true);
+        let caps = bytes::Regex::new(r"(?-u)(?<key>\w+)=([^\x00]*)").captures(b"\x00key=\xFEvalue\x00").unwrap();
+        assert_eq!(&caps["key"], b"key");
+        assert_eq!(&caps[2], b"\xFEvalue");
+        // The same pattern only works on str with Unicode left on
+        assert_eq!(Regex::try_new(r"(?-u)\xFF").unwrap_err(), Error::UnicodeRequired);
+        assert_eq!(Regex::new(r"\x{e9}\xE9").is_match("éé"), true);
+        // Escapes that aren't a character, or a byte without Unicode, are errors rather than panics
+        let invalid = |pattern: &str| matches!(bytes::Regex::try_new(pattern), Err(Error::InvalidPattern { .. }));
+        for pattern in [r"\x{110000}", r"\x{D800}", r"[\x{110000}]", r"(?-u)\x{100}", r"\x{10FFFF}", "\u{10FFFF}"] {
+            assert_eq!(invalid(pattern), true, "{}", pattern);
+        }
+        assert_eq!(matches!(Regex::try_new(r"\x{110000}"), Err(Error::InvalidPattern { .. })), true);
+    }
+
+    #[test]
+    fn utf16_regex() {
+        use crate::utf16;
+        let text = |s: &str| s.encode_utf16().collect::<Vec<u16>>();
+        // The emoji is a surrogate pair so it takes up two units
+        let haystack = text("a😀 bc é");
+        let r = utf16::Regex::new(r"\w+");
+        assert_eq!(r.find_iter(&haystack).map(|m| m.range()).collect::<Vec<_>>(), vec![0..1, 4..6]);
+        assert_eq!(utf16::Regex::new(r"a.\s").find(&haystack).map(|m| m.range()), Some(0..4));
+        assert_eq!(utf16::Regex::new(r"é$").find(&haystack).map(|m| m.to_string_lossy()), Some(String::from("é")));
+        assert_eq!(utf16::Regex::new(r"").find_iter(&text("😀")).map(|m| m.start()).collect::<Vec<_>>(), vec![0, 2]);
+        // An unpaired surrogate is a character of its own and doesn't stop the search
+        let broken = [0x61, 0xD800, 0x62, 0xDC00];
+        assert_eq!(utf16::Regex::new(r"a.b.").find(&broken).map(|m| m.range()), Some(0..4));
+        assert_eq!(utf16::Regex::new(r"b").find(&broken).map(|m| m.start()), Some(2));
+        assert_eq!(utf16::Regex::new(r"\x{FFFD}").is_match(&broken), false);
+        assert_eq!(utf16::Regex::new(r"[^a-z]").find(&broken).map(|m| m.range()), Some(1..2));
+        let pair = text("😀 key=value");
+        let caps = utf16::Regex::new(r"(?<key>\w+)=(\w*)").captures(&pair).unwrap();
+        assert_eq!(&caps["key"], text("key").as_slice());
+        assert_eq!(caps.get(2).map(|m| m.range()), Some(7..12));
+        assert_eq!(utf16::Regex::new(r"\bbc\b").find(&haystack).map(|m| m.range()), Some(4..6));
+        assert_eq!(utf16::Regex::new(r"\b{g}").find_iter(&text("😀e\u{301}")).map(|m| m.start()).collect::<Vec<_>>(), vec![0, 2, 4]);
+        assert_eq!(utf16::Regex::try_new(r"(?-u)a").is_err(), true);
+    }
+
+    #[test]
+    fn haystacks() {
+        use crate::haystack::Chunks;
+        use crate::regex::Input;
+        let r = Regex::new(r"\w+");
+        // Words and a multi byte character split between chunks, with an empty chunk in the way
+        let pieces = ["ab", "c dé", "", "f ", "gh"];
+        let chunks = Chunks::new(&pieces);
+        let joined = pieces.concat();
+        assert_eq!(r.find_ranges(&chunks).collect::<Vec<_>>(), r.find_iter(&joined).map(|m| m.range()).collect::<Vec<_>>());
+        assert_eq!(Regex::new(r"\bdé").search_range(&Input::new(&chunks)), Some(4..7));
+        assert_eq!(Regex::new(r"\bgh$").search_range(&Input::new(&chunks)), Some(9..11));
+        assert_eq!(Regex::literal("c d").search_range(&Input::new(&chunks).span(1..11)), Some(2..5));
+        let groups = Regex::new(r"(\w)(\w)?$").search_group_ranges(&Input::new(&chunks)).unwrap();
+        assert_eq!(groups, vec![Some(9..11), Some(9..10), Some(10..11)]);
+        // Positions in a char slice count chars
+        let chars = "añb c".chars().collect::<Vec<_>>();
+        assert_eq!(Regex::new(r"[^ ]+").find_ranges(chars.as_slice()).collect::<Vec<_>>(), vec![0..3, 4..5]);
+        assert_eq!(Regex::new(r"\b{g}").find_ranges(chars.as_slice()).count(), 6);
+        assert_eq!(Regex::new(r"").find_ranges(&chunks).count(), 11);
+        assert_eq!(Regex::new(r"a\X").search_range(&Input::new(chars.as_slice())), Some(0..2));
+    }
+
+    #[test]
+    fn stream_matcher() {
+        use crate::bytes;
+        use crate::error::Error;
+        use crate::stream::StreamMatcher;
+        // Invalid UTF-8 and a character cut short in the middle
+        let mut data = "GET /a é\nhost: x.org\n\nPOST /bé cd\n".as_bytes().to_vec();
+        data[30] = 0xFF;
+        data.insert(18, 0xE2);
+        // Every way of cutting the stream up has to give the same matches as searching all of it
+        for pattern in [r"[A-Z]+ /\w*", r"\b\w+\b", r"^$", r"\w+$", r"é", r"x*", r"(?-u)\xFF", r"a(?=\s)", r".+?\n"] {
+            let expected = bytes::Regex::new(pattern).find_iter(&data).map(|m| m.range()).collect::<Vec<_>>();
+            for size in 1..8 {
+                let mut stream = StreamMatcher::new(pattern).unwrap();
+                let mut found = Vec::new();
+                for chunk in data.chunks(size) {
+                    found.extend(stream.feed(chunk));
+                }
+                found.extend(stream.finish());
+                assert_eq!(found, expected, "{} in chunks of {}", pattern, size);
+            }
+            assert_eq!(StreamMatcher::new(pattern).unwrap().search_reader(data.as_slice()).unwrap(), expected);
+        }
+        // A match is only given once nothing after it could make it longer
+        let mut stream = StreamMatcher::new(r"ab+").unwrap();
+        assert_eq!(stream.feed(b"xxabb"), vec![]);
+        assert_eq!(stream.feed(b"bc ab"), vec![2..6]);
+        assert_eq!(stream.finish(), vec![8..10]);
+        // Threads that go on for a long time are carried from one chunk to the next
+        let mut stream = StreamMatcher::new(r"a[^z]*z").unwrap();
+        assert_eq!(stream.feed(b"xa"), vec![]);
+        for _ in 0..1000 {
+            assert_eq!(stream.feed(b"bbbb"), vec![]);
+        }
+        assert_eq!(stream.feed(b"z a"), vec![1..4003]);
+        assert_eq!(stream.finish(), vec![]);
+        assert_eq!(matches!(StreamMatcher::new(r"\b{g}x"), Err(Error::Unstreamable { .. })), true);
+        assert_eq!(matches!(StreamMatcher::new(r"\b{w}"), Err(Error::Unstreamable { .. })), true);
+        assert_eq!(matches!(StreamMatcher::new(r"(?=a)a*"), Err(Error::Unstreamable { .. })), true);
+        // Bounded, but too big to copy out within the size limit so it's counted
+        let config = RegexConfig {
+            size_limit: 50,
+            ..RegexConfig::default()
+        };
+        let counted = StreamMatcher::with_config(r"a{100}", config);
+        assert_eq!(matches!(counted, Err(Error::Unstreamable { reason }) if reason.contains("counted")), true);
+        assert_eq!(StreamMatcher::with_config(r"a{10}", config).is_ok(), true);
+    }
+
+    #[test]
+    fn search_lines() {
+        use std::io::BufReader;
+        let mut text = String::new();
+        for i in 0..4000 {
+            text.push_str(["error: disk é full\n", "ok\r\n", "\n", "warn: retry 3\n", "id=42 error\n"][i % 5]);
+        }
+        text.push_str("last error");
+        // Searching each line on its own is what the block search has to agree with
+        let expected = |r: &Regex, invert: bool| {
+            let mut offset = 0;
+            let mut lines = Vec::new();
+            for (i, line) in text.split_inclusive('\n').enumerate() {
+                let content = line.trim_end_matches('\n').trim_end_matches('\r');
+                let matches = r.find_iter(content).map(|m| m.range()).collect::<Vec<_>>();
+                if matches.is_empty() == invert {
+                    lines.push((i + 1, offset, content.to_string(), matches));
+                }
+                offset += line.len();
+            }
+            return lines;
+        };
+        for pattern in [r"error", r"^\w+:", r"\d+$", r"ok$", r"^$", r"k\s+w", r"\berror\b|\bid", r"\Aok\z", r"e(?=\s)", r"x*"] {
+            let r = Regex::new(pattern);
+            for invert in [false, true] {
+                let found = r.search_lines(BufReader::with_capacity(100, text.as_bytes())).invert(invert).collect::<Result<Vec<_>, _>>().unwrap();
+                assert_eq!(found, expected(&r, invert), "{} inverted {}", pattern, invert);
+            }
+        }
+        let first = Regex::new(r"\d+").search_lines("a\nb 12 3\n".as_bytes()).next().unwrap().unwrap();
+        assert_eq!(first, (2, 2, String::from("b 12 3"), vec![2..4, 5..6]));
+        // Text that isn't UTF-8 is an error like it is for replace_all_to_writer
+        assert_eq!(Regex::new(r"a").search_lines(&b"a\n\xFF\n"[..]).next().map(|line| line.is_err()), Some(true));
+    }
+
     #[test]
     fn benchmark_but_run_only_once() {
         let r = Regex::new(r"[\w\.+-]+@[\w\.-]+\.[\w\.-]+");
@@ -494,8 +1446,12 @@ mod tests {
 }
 
 mod backtrack_matcher;
+pub mod bytes;
 mod compiled_node;
 pub mod config;
+pub mod error;
+pub mod haystack;
+pub mod lines;
 #[macro_use]
 mod constants;
 mod dfa_matcher;
@@ -505,9 +1461,16 @@ mod optimize;
 mod parallel_nfa;
 mod parse;
 pub mod regex;
-mod replace;
+pub mod replace;
 mod root_node_optimizer;
+mod segmentation;
+mod segmentation_tables;
 mod sorted_vec;
+pub mod split;
+pub mod stream;
 mod unicode_ranges;
+pub mod utf16;
 mod utf_8;
 mod utils;
+
+pub use utils::escape;
@@ -0,0 +1,154 @@
+use super::compiled_node::{AnchorNode, CNode, SpecialNode};
+use super::regex::*;
+use std::io::{self, BufRead};
+use std::ops::Range;
+
+// How much is read before the lines in it get searched, more if a single line is longer than this
+const BLOCK_SIZE: usize = 64 * 1024;
+
+impl Regex {
+    // Every line with a match as (line number, offset of the line, line, where each match is in the line) like grep
+    // Lines are numbered from 1 and searched without their \n or \r\n, so ^, $, \A and \z all match at the start and
+    // end of each line and no match can run on to the next one
+    pub fn search_lines<R: BufRead>(&self, reader: R) -> LineMatches<'_, R> {
+        let mut line_ends = false;
+        let mut fast = true;
+        for node in self.node_vec.iter() {
+            match &node.node {
+                CNode::Anchor(AnchorNode::EndOfLine) => line_ends = true,
+                // Whether these match depends on more than the line so searching the whole block could skip lines they'd match in
+                CNode::Anchor(AnchorNode::StartOfString) | CNode::Anchor(AnchorNode::EndOfString) => fast = false,
+                CNode::Special(
+                    SpecialNode::StartLookAhead | SpecialNode::StartNegativeLookAhead | SpecialNode::StartLookBack(_) | SpecialNode::StartVariableLookback(..),
+                ) => fast = false,
+                _ => (),
+            }
+        }
+        return LineMatches {
+            regex: self,
+            reader,
+            invert: false,
+            fast,
+            line_ends,
+            block: String::new(),
+            block_offset: 0,
+            rest: Vec::new(),
+            position: 0,
+            hit: None,
+            block_fast: false,
+            line_number: 0,
+            eof: false,
+        };
+    }
+}
+
+// Lines with matches found a block at a time
+// Rather than running the regex over every line, the whole block is searched and only lines a match starts in are
+// searched on their own. A match in the block that runs on past its line can't hide a later one as the search picks up
+// again at the start of the next line rather than where the match ended.
+#[derive(Debug)]
+pub struct LineMatches<'r, R> {
+    regex: &'r Regex,
+    reader: R,
+    // Give the lines without a match instead
+    invert: bool,
+    // Whether searching the whole block finds every line a match can be in
+    fast: bool,
+    // $ can't see a line end in the block when it's \r\n, so blocks with \r in them go line by line
+    line_ends: bool,
+    // Whether the block being gone through is searched all at once
+    block_fast: bool,
+    // Whole lines read but not gone through yet
+    block: String,
+    // Where the block starts in the stream
+    block_offset: usize,
+    // Start of a line that hasn't finished yet
+    rest: Vec<u8>,
+    // Start of the next line in the block
+    position: usize,
+    // Where the next match in the block starts, the end of the block if there isn't one
+    hit: Option<usize>,
+    line_number: usize,
+    eof: bool,
+}
+
+impl<'r, R: BufRead> LineMatches<'r, R> {
+    // Lines without any match, each with an empty list of matches
+    pub fn invert(mut self, invert: bool) -> Self {
+        self.invert = invert;
+        return self;
+    }
+
+    // Reads at least a block's worth of whole lines, false once there's nothing left
+    fn fill(&mut self) -> io::Result<bool> {
+        self.block_offset += self.block.len();
+        self.block.clear();
+        self.position = 0;
+        self.hit = None;
+        let mut bytes = std::mem::take(&mut self.rest);
+        while !self.eof {
+            let chunk = self.reader.fill_buf()?;
+            if chunk.is_empty() {
+                self.eof = true;
+                break;
+            }
+            let has_line_end = chunk.contains(&b'\n');
+            bytes.extend_from_slice(chunk);
+            let consumed = chunk.len();
+            self.reader.consume(consumed);
+            if has_line_end && bytes.len() >= BLOCK_SIZE {
+                break;
+            }
+        }
+        if !self.eof {
+            let end = bytes.iter().rposition(|b| *b == b'\n').map_or(0, |i| i + 1);
+            self.rest = bytes.split_off(end);
+        }
+        self.block = String::from_utf8(bytes).map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "stream did not contain valid UTF-8"))?;
+        self.block_fast = self.fast && !(self.line_ends && self.block.contains('\r'));
+        return Ok(!self.block.is_empty());
+    }
+}
+
+impl<'r, R: BufRead> Iterator for LineMatches<'r, R> {
+    type Item = io::Result<(usize, usize, String, Vec<Range<usize>>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.position == self.block.len() {
+                match self.fill() {
+                    Ok(true) => (),
+                    Ok(false) => return None,
+                    Err(error) => return Some(Err(error)),
+                }
+            }
+            let start = self.position;
+            let next = self.block[start..].find('\n').map_or(self.block.len(), |i| start + i + 1);
+            let line = self.block[start..next].trim_end_matches('\n');
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            let end = start + line.len();
+            self.position = next;
+            self.line_number += 1;
+
+            if self.block_fast {
+                if !matches!(self.hit, Some(hit) if hit >= start) {
+                    let input = Input::new(self.block.as_str()).span(start..self.block.len());
+                    self.hit = Some(self.regex.search_span(&input).map_or(self.block.len() + 1, |found| found.0));
+                }
+                let hit = self.hit.unwrap();
+                // A match starting on the line end might not be one in the line but one starting after it can't be
+                if hit >= next && !(hit == end && end == next) {
+                    if !self.invert {
+                        continue;
+                    }
+                    return Some(Ok((self.line_number, self.block_offset + start, line.to_string(), Vec::new())));
+                }
+            }
+
+            let matches = self.regex.find_iter(line).map(|m| m.range()).collect::<Vec<_>>();
+            if matches.is_empty() == self.invert {
+                return Some(Ok((self.line_number, self.block_offset + start, line.to_string(), matches)));
+            }
+        }
+    }
+}
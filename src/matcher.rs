@@ -1,52 +1,321 @@
 use super::backtrack_matcher::*;
+use super::compiled_node::*;
 use super::config::*;
 use super::nfa::*;
 use super::parallel_nfa;
 use super::regex::*;
+use super::haystack::Haystack;
+use super::utf_8::*;
 use super::utils::*;
 use std::ops::DerefMut;
 
 impl Regex {
     pub fn is_match(&self, string: &str) -> bool {
+        return self.search_span(&Input::new(string).earliest(true)).is_some();
+    }
+
+    // Searching starts at the offset but anchors still see what comes before it, unlike slicing the string
+    pub fn is_match_at(&self, string: &str, start: usize) -> bool {
+        if start > string.len() {
+            return false;
+        }
+        return self.search_span(&Input::new(string).span(start..string.len()).earliest(true)).is_some();
+    }
+
+    pub fn match_str(&self, string: &str) -> bool {
+        return self.is_match(string);
+    }
+
+    // End of the first match the search comes across, which can be before the end of the leftmost match
+    // Patterns that need the backtracker give the end of the leftmost match instead
+    pub fn shortest_match(&self, string: &str) -> Option<usize> {
+        return self.search_span(&Input::new(string).earliest(true)).map(|(_, end)| end);
+    }
+
+    pub fn shortest_match_at(&self, string: &str, start: usize) -> Option<usize> {
+        if start > string.len() {
+            return None;
+        }
+        return self.search_span(&Input::new(string).span(start..string.len()).earliest(true)).map(|(_, end)| end);
+    }
+
+    // Leftmost match, stopping as soon as it's found rather than looking for the ones after it
+    pub fn find<'h>(&self, string: &'h str) -> Option<Match<'h>> {
+        return self.search(&Input::new(string));
+    }
+
+    pub fn find_at<'h>(&self, string: &'h str, start: usize) -> Option<Match<'h>> {
+        if start > string.len() {
+            return None;
+        }
+        return self.search(&Input::new(string).span(start..string.len()));
+    }
+
+    // Only holds on to the engine while looking for the next match
+    pub fn find_iter<'r, 'h>(&'r self, string: &'h str) -> Matches<'r, 'h> {
+        return Matches::new(self, Input::new(string));
+    }
+
+    pub fn captures<'h>(&self, string: &'h str) -> Option<Captures<'h>> {
+        return self.search_captures(&Input::new(string));
+    }
+
+    pub fn captures_iter<'r, 'h>(&'r self, string: &'h str) -> CaptureMatches<'r, 'h> {
+        return CaptureMatches::new(Matches::new(self, Input::new(string)));
+    }
+
+    pub fn captures_at<'h>(&self, string: &'h str, start: usize) -> Option<Captures<'h>> {
+        if start > string.len() {
+            return None;
+        }
+        return self.search_captures(&Input::new(string).span(start..string.len()));
+    }
+
+    // Whether the whole string matches, the same as wrapping the pattern in ^(?:...)$ without changing what ^ and $ mean
+    pub fn is_full_match(&self, string: &str) -> bool {
+        let (nodes, start_node) = self.full_match_program();
+        let input = Input::new(string).anchored(true).earliest(true);
+        return match self.engine.lock().unwrap().deref_mut() {
+            MatchingEngine::Backtrack { callstack, backref_data: _ } => backtrack_find(nodes, *start_node, callstack, &None, &input, &mut [], None).is_some(),
+            MatchingEngine::ParallelNFA {} => parallel_nfa::find(nodes, *start_node, &None, &input).is_some(),
+            _ => unimplemented!(),
+        };
+    }
+
+    pub fn full_captures<'h>(&self, string: &'h str) -> Option<Captures<'h>> {
+        let (nodes, start_node) = self.full_match_program();
+        let input = Input::new(string).anchored(true);
+        let mut slots = vec![None; self.captures_len * 2];
         match self.engine.lock().unwrap().deref_mut() {
             MatchingEngine::Backtrack { callstack, backref_data: _ } => {
-                // let chars = string.chars().collect::<Vec<_>>();
-                // return c_pure_match(&self.node_vec, &chars, callstack, self.root_node_idx);
-                return backtrack_pure_match(&self.node_vec, string.as_bytes(), self.root_node_idx, callstack, &self.optimized_root_node);
+                backtrack_find(nodes, *start_node, callstack, &None, &input, &mut slots, None)?;
             }
             MatchingEngine::ParallelNFA {} => {
-                return parallel_nfa::pure_match(&self.node_vec, string.as_bytes(), self.root_node_idx, &self.optimized_root_node);
+                parallel_nfa::find(nodes, *start_node, &None, &input)?;
+                backtrack_find(nodes, *start_node, &mut Vec::new(), &None, &input, &mut slots, None)?;
             }
             _ => unimplemented!(),
         };
+        return Some(Captures::new(string, slots, self.group_names.clone()));
     }
 
-    pub fn match_str(&self, string: &str) -> bool {
-        return self.is_match(string);
+    // Everything that led to the end node goes through an end of string anchor first
+    fn full_match_program(&self) -> &(Vec<CompiledNode>, usize) {
+        return self.full_match_program.get_or_init(|| {
+            let mut nodes = self.node_vec.clone();
+            let anchor = nodes.len();
+            let end = nodes.iter().position(|node| matches!(node.node, CNode::End)).unwrap();
+            for node in nodes.iter_mut() {
+                match &mut node.children {
+                    Children::Single(child) if *child == end => *child = anchor,
+                    Children::Multiple(vec) => vec.iter_mut().filter(|child| **child == end).for_each(|child| *child = anchor),
+                    _ => (),
+                }
+            }
+            nodes.push(CompiledNode {
+                node: CNode::Anchor(AnchorNode::EndOfString),
+                children: Children::Single(end),
+            });
+            let start_node = if self.root_node_idx == end { anchor } else { self.root_node_idx };
+            return (nodes, start_node);
+        });
     }
 
-    // pub fn first_match(&self, string: &str) -> Option<(usize, usize)> {
-    //     match self.engine.lock().unwrap().deref_mut() {
-    //         MatchingEngine::Backtrack { callstack, backref_data: _ } => {
-    //             return backtrack_first_match(&self.node_vec, string.as_bytes(), self.root_node_idx, callstack, &self.optimized_root_node);
-    //         }
-    //         MatchingEngine::ParallelNFA {} => {
-    //             return parallel_nfa::index_match(&self.node_vec, string.as_bytes(), self.root_node_idx);
-    //         }
-    //         _ => unimplemented!(),
-    //     };
-    // }
+    // Last match in the string, the same as the last one from find_iter
+    // The matches are found forwards from a point near the end that no match crosses, which the reversed program looks for
+    pub fn rfind<'h>(&self, string: &'h str) -> Option<Match<'h>> {
+        return self.rfind_iter(string).next();
+    }
+
+    pub fn rfind_iter<'r, 'h>(&'r self, string: &'h str) -> RMatches<'r, 'h> {
+        return RMatches::new(self, string);
+    }
+
+    // Position at least distance before end where find_iter would start a fresh search, because every match that starts before it also ends before it
+    // The distance doubles each time a match is found crossing the point tried
+    pub(crate) fn sync_point(&self, haystack: &str, end: usize, mut distance: usize) -> usize {
+        let (nodes, start_node) = match self.reverse_program() {
+            Some(program) => program,
+            // Lookarounds and the like can't be run backwards so all the matches have to be found going forwards
+            None => return 0,
+        };
+        let input = Input::new(haystack).span(0..end);
+        while distance < end {
+            let mut at = end - distance;
+            while !haystack.is_char_boundary(at) {
+                at -= 1;
+            }
+            if !parallel_nfa::crosses(nodes, *start_node, &input, at) {
+                return at;
+            }
+            distance *= 2;
+        }
+        return 0;
+    }
+
+    fn reverse_program(&self) -> &Option<(Vec<CompiledNode>, usize)> {
+        return self.reverse_program.get_or_init(|| {
+            let end = self.node_vec.iter().position(|node| matches!(node.node, CNode::End)).unwrap();
+            let mut reachable = vec![false; self.node_vec.len()];
+            let mut stack = vec![self.root_node_idx];
+            let mut parents = vec![Vec::new(); self.node_vec.len()];
+            while let Some(index) = stack.pop() {
+                if reachable[index] {
+                    continue;
+                }
+                reachable[index] = true;
+                let node = &self.node_vec[index];
+                let children = match &node.node {
+                    CNode::Special(_) | CNode::Sequence(_) => return None,
+                    _ => match &node.children {
+                        Children::Single(child) => std::slice::from_ref(child),
+                        Children::Multiple(vec) => vec.as_slice(),
+                        Children::None => &[],
+                    },
+                };
+                for child in children {
+                    parents[*child].push(index);
+                    stack.push(*child);
+                }
+            }
+            if !reachable[end] {
+                return None;
+            }
+            // The old start leads to a new end node and the old end becomes the start
+            let new_end = self.node_vec.len();
+            parents[self.root_node_idx].push(new_end);
+            let mut nodes = self.node_vec.clone();
+            for (node, parents) in nodes.iter_mut().zip(parents) {
+                node.children = match parents.len() {
+                    0 => Children::None,
+                    1 => Children::Single(parents[0]),
+                    _ => Children::Multiple(parents),
+                };
+            }
+            nodes[end].node = CNode::Behaviour(BehaviourNode::Transition);
+            nodes.push(CompiledNode {
+                node: CNode::End,
+                children: Children::None,
+            });
+            return Some((nodes, end));
+        });
+    }
+
+    // Every match no matter if it overlaps with another, the preferred one for each position a match can start at
+    pub fn find_overlapping_iter<'r, 'h>(&'r self, string: &'h str) -> OverlappingMatches<'r, 'h> {
+        return OverlappingMatches::new(self, string);
+    }
+
+    // Sorted positions a match starting at the start of the input's span can end at
+    pub(crate) fn match_ends(&self, input: &Input) -> Vec<usize> {
+        let input = input.anchored(true);
+        return match self.engine.lock().unwrap().deref_mut() {
+            MatchingEngine::Backtrack { callstack, backref_data: _ } => {
+                let mut ends = Vec::new();
+                backtrack_find(&self.node_vec, self.root_node_idx, callstack, &None, &input, &mut [], Some(&mut ends));
+                ends.sort_unstable();
+                ends.dedup();
+                ends
+            }
+            MatchingEngine::ParallelNFA {} => parallel_nfa::match_ends(&self.node_vec, self.root_node_idx, &input),
+            _ => unimplemented!(),
+        };
+    }
+
+    // Most bytes a match can take up including anything lookarounds look at, None if there's no limit
+    pub(crate) fn max_match_len(&self) -> Option<usize> {
+        const VISITING: usize = usize::MAX;
+        let nodes = &self.node_vec;
+        // Longest path from each node to the end, VISITING while a node is on the current path so loops are caught
+        let mut longest: Vec<Option<usize>> = vec![None; nodes.len()];
+        let mut stack = vec![(self.root_node_idx, false)];
+        while let Some((index, children_done)) = stack.pop() {
+            let node = &nodes[index];
+            let children = match &node.children {
+                Children::Single(child) => std::slice::from_ref(child),
+                Children::Multiple(vec) => vec.as_slice(),
+                Children::None => &[],
+            };
+            if children_done {
+                let own = match &node.node {
+                    CNode::Match(_) => 4,
+                    CNode::Sequence(_) | CNode::Special(SpecialNode::BackRef(_)) | CNode::Special(SpecialNode::GlobalRecursion) => return None,
+                    _ => 0,
+                };
+                longest[index] = Some(own + children.iter().map(|child| longest[*child].unwrap()).max().unwrap_or(0));
+                continue;
+            }
+            match longest[index] {
+                Some(VISITING) => return None,
+                Some(_) => continue,
+                None => longest[index] = Some(VISITING),
+            }
+            stack.push((index, true));
+            for child in children {
+                match longest[*child] {
+                    Some(VISITING) => return None,
+                    Some(_) => (),
+                    None => stack.push((*child, false)),
+                }
+            }
+        }
+        return longest[self.root_node_idx];
+    }
 
     pub fn match_indices(&self, string: &str) -> Vec<(usize, usize)> {
+        return self.find_iter(string).map(|m| (m.start(), m.end())).collect();
+    }
+
+    pub fn search<'h>(&self, input: &Input<'h>) -> Option<Match<'h>> {
+        let (start, end) = self.search_span(input)?;
+        return Some(Match::new(input.haystack(), start, end));
+    }
+
+    pub fn search_captures<'h>(&self, input: &Input<'h>) -> Option<Captures<'h>> {
+        let slots = self.search_slots(input)?;
+        return Some(Captures::new(input.haystack(), slots, self.group_names.clone()));
+    }
+
+    // Leftmost match in any haystack, like a rope or a list of chunks that would otherwise have to be put together into one str first
+    pub fn search_range<H: ?Sized + Haystack>(&self, input: &Input<H>) -> Option<std::ops::Range<usize>> {
+        return self.search_span(input).map(|(start, end)| start..end);
+    }
+
+    // Every group of the leftmost match in any haystack, group 0 being the whole match
+    pub fn search_group_ranges<H: ?Sized + Haystack>(&self, input: &Input<H>) -> Option<Vec<Option<std::ops::Range<usize>>>> {
+        let slots = self.search_slots(input)?;
+        return Some(slots.chunks(2).map(|pair| Some(pair[0]?..pair[1]?)).collect());
+    }
+
+    pub fn find_ranges<'r, 'h, H: ?Sized + Haystack>(&'r self, haystack: &'h H) -> RangeMatches<'r, 'h, H> {
+        return RangeMatches::new(self, Input::new(haystack));
+    }
+
+    // Start and end of every group for the leftmost match
+    pub(crate) fn search_slots<H: ?Sized + Haystack>(&self, input: &Input<H>) -> Option<Vec<Option<usize>>> {
+        let mut slots = vec![None; self.captures_len * 2];
         match self.engine.lock().unwrap().deref_mut() {
             MatchingEngine::Backtrack { callstack, backref_data: _ } => {
-                // let chars = string.char_indices().collect::<Vec<_>>();
-                // return c_indices_match(&self.node_vec, &chars, callstack, self.root_node_idx)
-                return backtrack_match_indices(&self.node_vec, string.as_bytes(), self.root_node_idx, callstack, &self.optimized_root_node);
+                backtrack_find(&self.node_vec, self.root_node_idx, callstack, &self.optimized_root_node, input, &mut slots, None)?;
             }
             MatchingEngine::ParallelNFA {} => {
-                return parallel_nfa::indices_match(&self.node_vec, string.as_bytes(), self.root_node_idx, &self.optimized_root_node);
+                // The parallel engine doesn't keep track of groups so the backtracker fills them in from wherever the match starts
+                let (match_start, _) = parallel_nfa::find(&self.node_vec, self.root_node_idx, &self.optimized_root_node, &input.earliest(false))?;
+                let input = input.span(match_start..input.end()).anchored(true);
+                backtrack_find(&self.node_vec, self.root_node_idx, &mut Vec::new(), &self.optimized_root_node, &input, &mut slots, None)?;
+            }
+            _ => unimplemented!(),
+        };
+        return Some(slots);
+    }
+
+    // The engines run on anything that can hand over the characters on either side of a position
+    pub(crate) fn search_span<H: ?Sized + Haystack>(&self, input: &Input<H>) -> Option<(usize, usize)> {
+        return match self.engine.lock().unwrap().deref_mut() {
+            MatchingEngine::Backtrack { callstack, backref_data: _ } => {
+                backtrack_find(&self.node_vec, self.root_node_idx, callstack, &self.optimized_root_node, input, &mut [], None)
             }
+            MatchingEngine::ParallelNFA {} => parallel_nfa::find(&self.node_vec, self.root_node_idx, &self.optimized_root_node, input),
             _ => unimplemented!(),
         };
     }
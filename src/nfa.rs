@@ -60,6 +60,27 @@ pub(crate) enum Node {
     NotWordBoundary {
         children: Vec<usize>,
     },
+    // \b{w} and \b{g}
+    UnicodeWordBoundary {
+        children: Vec<usize>,
+    },
+    GraphemeBoundary {
+        children: Vec<usize>,
+    },
+    // \< and \>
+    StartOfWord {
+        children: Vec<usize>,
+    },
+    EndOfWord {
+        children: Vec<usize>,
+    },
+    // \X and \R, consume a variable number of characters and never give any of them back
+    GraphemeCluster {
+        children: Vec<usize>,
+    },
+    LineBreak {
+        children: Vec<usize>,
+    },
     // Ending node
     End,
     // Epsilon Transition State, Ideally removed by the time it reaches the matching engine.
@@ -80,6 +101,15 @@ pub(crate) enum Node {
         children: Vec<usize>,
         number: u32,
     },
+    // Around a group that loops, Start records where an iteration began and Next only goes round again if it moved on from there
+    StartIteration {
+        children: Vec<usize>,
+        counter: usize,
+    },
+    NextIteration {
+        children: Vec<usize>,
+        counter: usize,
+    },
     // For lookarounds
     StartLookAhead {
         children: Vec<usize>,
@@ -122,6 +152,27 @@ pub(crate) enum Node {
     },
     // Recursion
     GlobalRecursion,
+    // Counted repetition for when unrolling would blow up the size of the graph, only supported by the backtracker
+    // Start resets the counter and End increments it, Loop and Exit only let the match through while the counter is in range
+    StartRepeat {
+        children: Vec<usize>,
+        counter: usize,
+    },
+    EndRepeat {
+        children: Vec<usize>,
+        counter: usize,
+        min: usize,
+    },
+    RepeatLoop {
+        children: Vec<usize>,
+        counter: usize,
+        max: usize,
+    },
+    RepeatExit {
+        children: Vec<usize>,
+        counter: usize,
+        min: usize,
+    },
 }
 
 impl Node {
@@ -182,6 +233,8 @@ impl Node {
             | CapGroup { ref mut children, .. }
             | DropStack { ref mut children }
             | EndCapGroup { ref mut children, .. }
+            | StartIteration { ref mut children, .. }
+            | NextIteration { ref mut children, .. }
             | EndLookAhead { ref mut children, .. }
             | StartLookAhead { ref mut children, .. }
             | StartLookBack { ref mut children, .. }
@@ -198,7 +251,17 @@ impl Node {
             | StartNegativeLookAhead { ref mut children, .. }
             | EndNegativeLookAhead { ref mut children, .. }
             | StartAtomic {ref mut children}
-            | EndAtomic {ref mut children} => {
+            | EndAtomic {ref mut children}
+            | GraphemeCluster { ref mut children }
+            | LineBreak { ref mut children }
+            | UnicodeWordBoundary { ref mut children }
+            | GraphemeBoundary { ref mut children }
+            | StartOfWord { ref mut children }
+            | EndOfWord { ref mut children }
+            | StartRepeat { ref mut children, .. }
+            | EndRepeat { ref mut children, .. }
+            | RepeatLoop { ref mut children, .. }
+            | RepeatExit { ref mut children, .. } => {
                 return Some(children);
             }
             _ => return None,
@@ -219,6 +282,8 @@ impl Node {
             | CapGroup { children, .. }
             | DropStack { children }
             | EndCapGroup { children, .. }
+            | StartIteration { children, .. }
+            | NextIteration { children, .. }
             | EndLookAhead { children, .. }
             | StartLookAhead { children, .. }
             | StartLookBack { children, .. }
@@ -235,7 +300,17 @@ impl Node {
             | StartNegativeLookAhead { children, .. }
             | EndNegativeLookAhead { children, .. } 
             | StartAtomic {children}
-            | EndAtomic {children} => {
+            | EndAtomic {children}
+            | GraphemeCluster { children }
+            | LineBreak { children }
+            | UnicodeWordBoundary { children }
+            | GraphemeBoundary { children }
+            | StartOfWord { children }
+            | EndOfWord { children }
+            | StartRepeat { children, .. }
+            | EndRepeat { children, .. }
+            | RepeatLoop { children, .. }
+            | RepeatExit { children, .. } => {
                 return Some(children);
             }
             _ => return None,
@@ -273,6 +348,8 @@ impl Node {
             Transition { ref mut children, .. }
             | CapGroup { ref mut children, .. }
             | EndCapGroup { ref mut children, .. }
+            | StartIteration { ref mut children, .. }
+            | NextIteration { ref mut children, .. }
             | StartLookAhead { ref mut children, .. }
             | EndLookAhead { ref mut children, .. }
             | StartLookBack { ref mut children, .. }
@@ -358,6 +435,12 @@ impl Node {
             EndOfString { .. } => CNode::Anchor(AnchorNode::EndOfString),
             WordBoundary { .. } => CNode::Anchor(AnchorNode::WordBoundary),
             NotWordBoundary { .. } => CNode::Anchor(AnchorNode::NotWordBoundary),
+            UnicodeWordBoundary { .. } => CNode::Anchor(AnchorNode::UnicodeWordBoundary),
+            GraphemeBoundary { .. } => CNode::Anchor(AnchorNode::GraphemeBoundary),
+            StartOfWord { .. } => CNode::Anchor(AnchorNode::StartOfWord),
+            EndOfWord { .. } => CNode::Anchor(AnchorNode::EndOfWord),
+            GraphemeCluster { .. } => CNode::Sequence(SequenceNode::GraphemeCluster),
+            LineBreak { .. } => CNode::Sequence(SequenceNode::LineBreak),
             End => CNode::End,
             Transition { .. } => CNode::Behaviour(BehaviourNode::Transition),
             ExclusiveNodes { .. } => unimplemented!(),
@@ -371,15 +454,18 @@ impl Node {
             BackRef { number, .. } => CNode::Special(SpecialNode::BackRef(number)),
             DropStack { .. } => CNode::Special(SpecialNode::DropStack),
             EndCapGroup { number, .. } => CNode::Behaviour(BehaviourNode::EndCapGroup(number)),
+            StartIteration { counter, .. } => CNode::Behaviour(BehaviourNode::StartIteration(counter)),
+            NextIteration { counter, .. } => CNode::Behaviour(BehaviourNode::NextIteration(counter)),
             GlobalRecursion { .. } => CNode::Special(SpecialNode::GlobalRecursion),
             StartAtomic {..} => CNode::Special(SpecialNode::StartAtomic),
             EndAtomic {..}=> CNode::Special(SpecialNode::EndAtomic),
+            StartRepeat { counter, .. } => CNode::Special(SpecialNode::StartRepeat(counter)),
+            EndRepeat { counter, min, .. } => CNode::Special(SpecialNode::EndRepeat(counter, min)),
+            RepeatLoop { counter, max, .. } => CNode::Special(SpecialNode::RepeatLoop(counter, max)),
+            RepeatExit { counter, min, .. } => CNode::Special(SpecialNode::RepeatExit(counter, min)),
         };
 
-        let special = match &node {
-            CNode::Special(_) => true,
-            _ => false,
-        };
+        let special = matches!(&node, CNode::Special(_) | CNode::Sequence(_));
 
         return (CompiledNode { children, node }, special);
     }
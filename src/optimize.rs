@@ -6,17 +6,16 @@ const OPTIMIZATION_PASSES: u8 = 3;
 pub(crate) fn optimize(nodes: &mut Vec<Node>) {
     // Removing most transition nodes => removing vast majority of epsilon transitons. Makes it much faster.
     // Normally a 2-3x speed up
-    let mut transition_state_indices = Vec::new();
     let mut transition_children = FxHashMap::default();
+    // Child list each node was last put in, so a child that shows up twice is found without searching the list
+    let mut seen = vec![0; nodes.len()];
+    let mut list = 0;
     for _ in 0..OPTIMIZATION_PASSES {
         for i in 0..nodes.len() {
             let node = nodes.get_mut(i).unwrap();
             match node {
                 Node::Transition { ref children } => {
-                    transition_state_indices.push(i);
-                    let mut new_children = children.clone();
-                    new_children.reverse();
-                    transition_children.insert(i, new_children);
+                    transition_children.insert(i, children.clone());
                 }
                 _ => (),
             }
@@ -24,18 +23,20 @@ pub(crate) fn optimize(nodes: &mut Vec<Node>) {
         for node in &mut *nodes {
             match node.get_children_mut() {
                 Some(children) => {
-                    for i in 0..children.len() {
-                        let j = children[i];
-                        if transition_state_indices.contains(&j) {
-                            let new_children = transition_children.get(&j).unwrap();
-                            children.remove(i);
-                            for child in new_children {
-                                if !children.contains(child) {
-                                    children.insert(i, *child);
-                                }
+                    // Splicing in a transition's children can leave fewer children than there were
+                    // A child that shows up twice keeps its first place since that's the one tried first
+                    list += 1;
+                    let mut spliced = Vec::with_capacity(children.len());
+                    for child in children.iter() {
+                        let new_children = transition_children.get(child).map_or(std::slice::from_ref(child), |c| c.as_slice());
+                        for new_child in new_children {
+                            if seen[*new_child] != list {
+                                seen[*new_child] = list;
+                                spliced.push(*new_child);
                             }
                         }
                     }
+                    *children = spliced;
                 }
                 None => (),
             }
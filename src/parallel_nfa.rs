@@ -1,492 +1,466 @@
 use crate::compiled_node::*;
+use crate::regex::Input;
 use crate::root_node_optimizer::RootNode;
-use crate::utf_8::*;
+use crate::haystack::{char_after_start, char_before_end, next_index, Haystack};
+use std::collections::VecDeque;
 
-// A custom queue is neccessary to provide predictable matching behaviour eg. not finding the shortest match but the first one that would appear in a bracktracker
-// This makes lazy and greedy operators do what they were supposed to do
+// Every thread steps over the string in lockstep, so the time taken is linear in the length of the string.
+// Threads are kept in the order a backtracker would have tried them in, which gives predictable matching behaviour eg. not finding the shortest match but the first one that would appear in a backtracker.
+// This makes lazy and greedy operators do what they were supposed to do.
 
-#[derive(Default, Debug, Clone)]
-struct Queue(Vec<StackItem>);
+#[derive(Debug)]
+struct Threads {
+    // Match and End nodes waiting on the next character, highest priority first, with where their match started
+    threads: Vec<(usize, usize)>,
+    // Generation each node was last added in so clearing the list doesn't have to touch every node
+    added: Vec<usize>,
+    generation: usize,
+}
 
-impl Queue {
-    fn new() -> Self {
-        return Default::default();
+impl Threads {
+    fn new(len: usize) -> Self {
+        return Self {
+            threads: Vec::new(),
+            added: vec![0; len],
+            generation: 1,
+        };
     }
-}
 
-impl core::ops::Deref for Queue {
-    type Target = Vec<StackItem>;
-    fn deref(&self) -> &Self::Target {
-        return &self.0;
+    fn clear(&mut self) {
+        self.threads.clear();
+        self.generation += 1;
     }
-}
 
-impl core::ops::DerefMut for Queue {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        return &mut self.0;
+    // Lets nodes be added again for threads that started somewhere else, when starts don't compete with each other
+    fn next_group(&mut self) {
+        self.generation += 1;
     }
-}
 
-impl Queue {
-    fn insert(&mut self, other: StackItem) {
-        match self.0.binary_search(&other) {
-            Ok(i) => {
-                if other < self.0[i] {
-                    self.0[i] = other;
-                }
-            }
-            Err(i) => {
-                self.0.insert(i, other);
-            }
-        }
+    // Marks the node as added and returns whether it already was
+    #[inline]
+    fn visit(&mut self, node_index: usize) -> bool {
+        let visited = self.added[node_index] == self.generation;
+        self.added[node_index] = self.generation;
+        return visited;
     }
 }
 
-#[derive(Default, Debug, Clone, PartialOrd, Ord, Eq)]
-struct StackItem {
-    node_index: usize,
-    stacktrace: StackTrace,
+// What following threads through the program needs besides the thread lists themselves
+struct Search<'a, 'h, H: Haystack + ?Sized> {
+    nodes: &'a [CompiledNode],
+    input: &'a Input<'h, H>,
+    // Nodes still to visit while adding a thread, kept to reuse the allocation
+    stack: Vec<usize>,
 }
 
-impl PartialEq for StackItem {
-    fn eq(&self, other: &Self) -> bool {
-        self.node_index == other.node_index
+impl<'a, 'h, H: Haystack + ?Sized> Search<'a, 'h, H> {
+    fn new(nodes: &'a [CompiledNode], input: &'a Input<'h, H>) -> Self {
+        return Self { nodes, input, stack: Vec::new() };
     }
-}
 
-#[derive(Default, Debug, Clone, PartialEq, PartialOrd, Ord, Eq)]
-struct StackTrace(
-    // Node Index of parent, numbered child (lower has higher priority)
-    Vec<usize>,
-);
+    // Follows epsilon transitions depth first so that threads end up in priority order
+    // A node reached a second time is dropped as the earlier path to it always takes priority
+    fn add_thread(&mut self, list: &mut Threads, node_index: usize, start: usize, index: usize) {
+        let string = self.input.haystack();
+        self.stack.push(node_index);
+        while let Some(node_index) = self.stack.pop() {
+            if list.visit(node_index) {
+                continue;
+            }
+            let node = unsafe { self.nodes.get_unchecked(node_index) };
+            match &node.node {
+                CNode::Match(_) | CNode::End => {
+                    list.threads.push((node_index, start));
+                    continue;
+                }
+                CNode::Anchor(anchor_node) => {
+                    if !anchor_node.is_match(index, string, string.next_char(index)) {
+                        continue;
+                    }
+                }
+                CNode::Behaviour(_) => (),
+                CNode::Special(_) => panic!("Special Nodes not supported on the BFS engine"),
+                CNode::Sequence(_) => panic!("Sequence Nodes not supported on the BFS engine"),
+            }
+            match &node.children {
+                Children::Multiple(vec) => self.stack.extend(vec.iter().rev()),
+                Children::Single(child) => self.stack.push(*child),
+                Children::None => panic!("Node has no children"),
+            }
+        }
+    }
 
-impl core::ops::Deref for StackTrace {
-    type Target = Vec<usize>;
-    fn deref(&self) -> &Self::Target {
-        return &self.0;
+    // Moves a thread past a character if its node matches it
+    #[inline]
+    fn step(&mut self, next: &mut Threads, node: &CompiledNode, match_node: &MatchNode, c: char, start: usize, index: usize) {
+        if match_node.is_match(&c) {
+            match &node.children {
+                Children::Multiple(vec) => {
+                    for child in vec.iter() {
+                        self.add_thread(next, *child, start, index);
+                    }
+                }
+                Children::Single(child) => self.add_thread(next, *child, start, index),
+                Children::None => panic!("Match node has no children"),
+            }
+        }
     }
 }
 
-impl core::ops::DerefMut for StackTrace {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        return &mut self.0;
+// Every position a match starting at the start of the input's span can end at, in order
+// Threads carry on past the end node instead of lower priority ones being dropped
+pub(crate) fn match_ends<H: Haystack + ?Sized>(nodes: &[CompiledNode], start_node_index: usize, input: &Input<H>) -> Vec<usize> {
+    let string = input.haystack();
+    let mut current = Threads::new(nodes.len());
+    let mut next = Threads::new(nodes.len());
+    let mut search = Search::new(nodes, input);
+    let mut ends = Vec::new();
+    let mut index = input.start();
+    search.add_thread(&mut current, start_node_index, index, index);
+    while !current.threads.is_empty() {
+        let character = char_before_end(string, index, input.end());
+        for &(node_index, thread_start) in current.threads.iter() {
+            let node = unsafe { nodes.get_unchecked(node_index) };
+            match &node.node {
+                CNode::End => {
+                    if ends.last() != Some(&index) {
+                        ends.push(index);
+                    }
+                }
+                CNode::Match(match_node) => {
+                    if let Some((c, len)) = character {
+                        search.step(&mut next, node, match_node, c, thread_start, index + len);
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
+        match character {
+            Some((_, len)) => index += len,
+            None => break,
+        }
+        std::mem::swap(&mut current, &mut next);
+        next.clear();
     }
+    return ends;
 }
 
-#[derive(PartialEq, PartialOrd, Eq, Ord, Debug, Clone)]
-struct AcceptorState(StackTrace, usize);
-
-pub(crate) fn pure_match(nodes: &[CompiledNode], string_bytes: &[u8], start_node_index: usize, root_node: &Option<RootNode>) -> bool {
-    let mut stack1 = sorted_vec::SortedVec::default();
-    let mut stack2 = sorted_vec::SortedVec::default();
-    let mut stack_alt = true;
-    let mut string_index = 0usize;
-    let mut split_at = 0usize;
+// Finds the leftmost match inside the input's span
+// With earliest set it gives up on finding where the preferred match ends and returns as soon as any match is found
+pub(crate) fn find<H: Haystack + ?Sized>(nodes: &[CompiledNode], start_node_index: usize, root_node: &Option<RootNode>, input: &Input<H>) -> Option<(usize, usize)> {
+    let string = input.haystack();
+    let (start, end) = (input.start(), input.end());
+    let mut current = Threads::new(nodes.len());
+    let mut next = Threads::new(nodes.len());
+    let mut search = Search::new(nodes, input);
+    let mut matched = None;
+    let mut index = start;
 
-    if let Some(root_node) = root_node {
-        match root_node.run(string_bytes, split_at) {
-            Some(idx) => {
-                stack1.insert(root_node.child);
-                split_at = idx;
-                string_index = idx;
+    loop {
+        // A new thread can start here, behind every thread that started earlier
+        if matched.is_none() && (!input.is_anchored() || index == start) {
+            // Nodes an anchor turned away at the last position are still marked, which would hide them from the root node here
+            if current.threads.is_empty() {
+                current.clear();
+            }
+            match root_node {
+                Some(root_node) if current.threads.is_empty() && !input.is_anchored() => {
+                    let (candidate, resume) = root_node.run(string, index)?;
+                    if resume > end {
+                        return None;
+                    }
+                    index = resume;
+                    search.add_thread(&mut current, root_node.child, candidate, index);
+                    // The root node already took the first character, a match could still start right after it
+                    if resume != candidate {
+                        search.add_thread(&mut current, start_node_index, index, index);
+                    }
+                }
+                _ => search.add_thread(&mut current, start_node_index, index, index),
             }
-            None => return false,
         }
-    } else {
-        stack1.insert(start_node_index);
-    }
+        if current.threads.is_empty() {
+            if matched.is_some() || index >= end || input.is_anchored() {
+                return matched;
+            }
+            index = next_index(string, index);
+            current.clear();
+            continue;
+        }
 
-    // let mut offset = 0;
-    '_outer: loop {
-        let cached: Option<(char, usize)> = decode_utf8(&string_bytes[split_at..]);
-        let (current_stack, to_add_stack) = if stack_alt {
-            (&mut stack1, &mut stack2)
-        } else {
-            (&mut stack2, &mut stack1)
-        };
-        'inner: while let Some(node_idx) = current_stack.pop()
-        {
-            let node = unsafe { nodes.get_unchecked(node_idx) };
-            // let node = nodes.get(node_idx).unwrap();
-            // println!("{:?}", node);
-            let success: bool;
+        let character = char_before_end(string, index, end);
+        for &(node_index, thread_start) in current.threads.iter() {
+            let node = unsafe { nodes.get_unchecked(node_index) };
             match &node.node {
-                CNode::Match(match_node) => match cached {
-                    Some(t) => {
-                        let character = &t.0;
-                        success = match_node.is_match(character);
-                        if success {
-                            match &node.children {
-                                Children::Multiple(vec) => {
-                                    for child in vec.iter().copied() {
-                                        to_add_stack.insert(child);
-                                    }
-                                }
-                                Children::Single(s) => {
-                                    to_add_stack.insert(*s);
-                                }
-                                Children::None => panic!("Match node has no children"),
-                            }
-                        }
-                    }
-                    None => continue 'inner,
-                },
-                CNode::Anchor(anchor_node) => {
-                    success = anchor_node.is_match(split_at, string_bytes, cached);
-                    if success {
-                        match &node.children {
-                            Children::Multiple(vec) => {
-                                for child in vec.iter().copied() {
-                                    current_stack.insert(child);
-                                }
-                            }
-                            Children::Single(s) => {
-                                current_stack.insert(*s);
-                            }
-                            Children::None => panic!("Anchor node has no children"),
-                        }
+                CNode::End => {
+                    matched = Some((thread_start, index));
+                    if input.is_earliest() {
+                        return matched;
                     }
+                    // Everything after this thread has a lower priority
+                    break;
                 }
-                CNode::Behaviour(_) => match &node.children {
-                    Children::Multiple(vec) => {
-                        for child in vec.iter().copied() {
-                            current_stack.insert(child);
-                        }
-                    }
-                    Children::Single(s) => {
-                        current_stack.insert(*s);
-                    }
-                    Children::None => panic!("Behaviour node has no children"),
-                },
-                CNode::Special(_) => panic!("Special Nodes not supported on the BFS engine"),
-                CNode::End => return true,
-            };
-        }
-        if to_add_stack.is_empty() {
-            string_index = next_utf8(string_bytes, string_index);
-            if string_index < string_bytes.len() {
-                if let Some(root_node) = root_node {
-                    match root_node.run(string_bytes, split_at) {
-                        Some(idx) => {
-                            to_add_stack.insert(root_node.child);
-                            split_at = idx;
-                            string_index = idx;
-                        }
-                        None => return false,
+                CNode::Match(match_node) => {
+                    if let Some((c, len)) = character {
+                        search.step(&mut next, node, match_node, c, thread_start, index + len);
                     }
-                } else {
-                    to_add_stack.insert(start_node_index);
-                    split_at = string_index;
                 }
-            } else {
-                return false;
+                _ => unreachable!(),
             }
-        }  
-        stack_alt = !stack_alt;
+        }
 
-        if let Some((_, len)) = cached {
-            split_at += len;
+        match character {
+            Some((_, len)) => index += len,
+            None => return matched,
         }
+        std::mem::swap(&mut current, &mut next);
+        next.clear();
     }
-    // None
 }
 
-pub(crate) fn index_match(
-    nodes: &[CompiledNode],
-    string_bytes: &[u8],
-    start_node_index: usize,
-    root_node: &Option<RootNode>,
-) -> Option<(usize, usize)> {
-    let mut stack1 = Queue::default();
-    let mut stack2 = Queue::default();
-    let mut acceptors: Vec<AcceptorState> = Vec::new();
-    let mut stack_alt = true;
-    let mut string_index = 0usize;
-    let mut split_at = 0usize;
+// Whether a match that starts before at ends at or after it, by running a reversed program leftwards from the end of the input's span
+// Threads are only started from at onwards, so any that reach the end of the reversed program before at cross it
+pub(crate) fn crosses<H: Haystack + ?Sized>(nodes: &[CompiledNode], start_node_index: usize, input: &Input<H>, at: usize) -> bool {
+    let string = input.haystack();
+    let mut current = Threads::new(nodes.len());
+    let mut next = Threads::new(nodes.len());
+    let mut search = Search::new(nodes, input);
+    let mut index = input.end();
 
-    if let Some(root_node) = root_node {
-        match root_node.run(string_bytes, split_at) {
-            Some(idx) => {
-                stack1.insert(StackItem {
-                    node_index: root_node.child,
-                    ..Default::default()
-                });
-                split_at = idx;
-                string_index = idx;
+    loop {
+        if index >= at {
+            search.add_thread(&mut current, start_node_index, index, index);
+        }
+        let character = char_after_start(string, input.start(), index);
+        for &(node_index, thread_start) in current.threads.iter() {
+            let node = unsafe { nodes.get_unchecked(node_index) };
+            match &node.node {
+                CNode::End => {
+                    if index < at {
+                        return true;
+                    }
+                }
+                CNode::Match(match_node) => {
+                    if let Some((c, len)) = character {
+                        search.step(&mut next, node, match_node, c, thread_start, index - len);
+                    }
+                }
+                _ => unreachable!(),
             }
-            None => return None,
         }
-    } else {
-        stack1.insert(StackItem {
-            node_index: start_node_index,
-            ..Default::default()
-        });
+
+        match character {
+            Some((_, len)) if index > at || !next.threads.is_empty() => index -= len,
+            _ => return false,
+        }
+        std::mem::swap(&mut current, &mut next);
+        next.clear();
     }
+}
+
+// A leftmost first search over text that comes a piece at a time, which stops at the end of each piece and carries on with the next
+// Only the threads and where they started are kept in between, so the haystack only has to hold the text from the search's index on
+// Positions kept here are in the whole text and offset is where the haystack starts in it
+#[derive(Debug)]
+pub(crate) struct Resumable {
+    current: Threads,
+    next: Threads,
+    stack: Vec<usize>,
+    // Position of the next character to step over
+    index: usize,
+    // Match found so far, higher priority threads that are still going could replace it
+    matched: Option<(usize, usize)>,
+}
 
-    // let mut offset = 0;
-    '_outer: loop {
-        let cached: Option<(char, usize)> = decode_utf8(&string_bytes[split_at..]);
-        let (current_stack, to_add_stack) = if stack_alt {
-            (&mut stack1, &mut stack2)
-        } else {
-            (&mut stack2, &mut stack1)
+impl Resumable {
+    pub fn new(nodes: &[CompiledNode]) -> Self {
+        return Self {
+            current: Threads::new(nodes.len()),
+            next: Threads::new(nodes.len()),
+            stack: Vec::new(),
+            index: 0,
+            matched: None,
         };
-        'inner: while let Some(StackItem {
-            node_index: node_idx,
-            stacktrace,
-        }) = current_stack.pop()
-        {
-            let node = unsafe { nodes.get_unchecked(node_idx) };
-            // let node = nodes.get(node_idx).unwrap();
-            // println!("{:?}", node);
-            let success: bool;
-            match &node.node {
-                CNode::Match(match_node) => match cached {
-                    Some(t) => {
-                        let character = &t.0;
-                        success = match_node.is_match(character);
-                        if success {
-                            match &node.children {
-                                Children::Multiple(vec) => {
-                                    for (index, child) in vec.iter().enumerate() {
-                                        let mut stacktrace = stacktrace.clone();
-                                        stacktrace.push(index);
-                                        to_add_stack.insert(StackItem {
-                                            node_index: *child,
-                                            stacktrace,
-                                        });
-                                    }
-                                }
-                                Children::Single(s) => {
-                                    to_add_stack.insert(StackItem { node_index: *s, stacktrace });
-                                }
-                                Children::None => panic!("Match node has no children"),
-                            }
-                        }
-                    }
-                    None => continue 'inner,
-                },
-                CNode::Anchor(anchor_node) => {
-                    success = anchor_node.is_match(split_at, string_bytes, cached);
-                    if success {
-                        match &node.children {
-                            Children::Multiple(vec) => {
-                                for (index, child) in vec.iter().enumerate() {
-                                    let mut stacktrace = stacktrace.clone();
-                                    stacktrace.push(index);
-                                    current_stack.insert(StackItem {
-                                        node_index: *child,
-                                        stacktrace,
-                                    });
-                                }
-                            }
-                            Children::Single(s) => {
-                                current_stack.insert(StackItem { node_index: *s, stacktrace });
-                            }
-                            Children::None => panic!("Anchor node has no children"),
-                        }
+    }
+
+    // Drops the search so far and starts a new one at the position
+    pub fn restart(&mut self, index: usize) {
+        self.current.clear();
+        self.next.clear();
+        self.index = index;
+        self.matched = None;
+    }
+
+    // Where the search carries on from, the match found so far is searched on from its end once it's given
+    pub fn resume_point(&self) -> usize {
+        return self.matched.map_or(self.index, |(_, end)| end);
+    }
+
+    // Earliest position a match that hasn't been given yet can start at
+    pub fn earliest_start(&self) -> usize {
+        let start = self.current.threads.iter().map(|(_, start)| *start).min().unwrap_or(self.index);
+        return self.matched.map_or(start, |(matched, _)| matched.min(start));
+    }
+
+    // Carries the search on to the end of the input's span and gives the match once no thread can change it
+    // Until the end of the text nothing is decided at the end of the span, the haystack has to have the character after it for anchors
+    pub fn run<H: Haystack + ?Sized>(&mut self, nodes: &[CompiledNode], start_node_index: usize, root_node: &Option<RootNode>, input: &Input<H>, offset: usize, last: bool) -> Option<(usize, usize)> {
+        let string = input.haystack();
+        let end = input.end();
+        let mut search = Search { nodes, input, stack: std::mem::take(&mut self.stack) };
+        let found = loop {
+            let mut index = self.index - offset;
+            if self.matched.is_none() {
+                if self.current.threads.is_empty() {
+                    self.current.clear();
+                    // Skip straight to where a match could start, or to the end of the span if nothing before it can
+                    if let Some(root_node) = root_node {
+                        index = match root_node.run(string, index) {
+                            Some((candidate, _)) if candidate < end || last => candidate,
+                            _ if last => break None,
+                            _ => end,
+                        };
+                        self.index = offset + index;
                     }
                 }
-                CNode::Behaviour(_) => match &node.children {
-                    Children::Multiple(vec) => {
-                        for (index, child) in vec.iter().enumerate() {
-                            let mut stacktrace = stacktrace.clone();
-                            stacktrace.push(index);
-                            current_stack.insert(StackItem {
-                                node_index: *child,
-                                stacktrace,
-                            });
-                        }
-                    }
-                    Children::Single(s) => {
-                        current_stack.insert(StackItem { node_index: *s, stacktrace });
-                    }
-                    Children::None => panic!("Behaviour node has no children"),
-                },
-                CNode::Special(_) => panic!("Special Nodes not supported on the BFS engine"),
-                CNode::End => {
-                    acceptors.push(AcceptorState(stacktrace, split_at));
-                },
-            };
-        }
-        if to_add_stack.is_empty() {
-            if !acceptors.is_empty() {
-                let accepted = acceptors.iter().min().unwrap();
-                return Some((string_index, accepted.1));
+                if index >= end && !last {
+                    break None;
+                }
+                search.add_thread(&mut self.current, start_node_index, offset + index, index);
             }
-            string_index = next_utf8(string_bytes, string_index);
-            if string_index < string_bytes.len() {
-                if let Some(root_node) = root_node {
-                    match root_node.run(string_bytes, split_at) {
-                        Some(idx) => {
-                            to_add_stack.insert(StackItem{node_index: root_node.child, ..Default::default()});
-                            split_at = idx;
-                            string_index = idx;
+            if self.current.threads.is_empty() {
+                if self.matched.is_some() || index >= end {
+                    break self.matched.take();
+                }
+                self.index = offset + next_index(string, index);
+                self.current.clear();
+                continue;
+            }
+            if index >= end && !last {
+                break None;
+            }
+
+            let character = char_before_end(string, index, end);
+            for &(node_index, thread_start) in self.current.threads.iter() {
+                let node = unsafe { nodes.get_unchecked(node_index) };
+                match &node.node {
+                    CNode::End => {
+                        self.matched = Some((thread_start, offset + index));
+                        // Everything after this thread has a lower priority
+                        break;
+                    }
+                    CNode::Match(match_node) => {
+                        if let Some((c, len)) = character {
+                            search.step(&mut self.next, node, match_node, c, thread_start, index + len);
                         }
-                        None => return None,
                     }
-                } else {
-                    to_add_stack.insert(StackItem{node_index: start_node_index, ..Default::default()});
-                    split_at = string_index;
+                    _ => unreachable!(),
                 }
-            } else {
-                return None;
             }
-        }  
-        stack_alt = !stack_alt;
 
-        if let Some((_, len)) = cached {
-            split_at += len;
-        }
+            match character {
+                Some((_, len)) => self.index += len,
+                None => break self.matched.take(),
+            }
+            std::mem::swap(&mut self.current, &mut self.next);
+            self.next.clear();
+        };
+        self.stack = search.stack;
+        return found;
     }
 }
 
-pub(crate) fn indices_match(
-    nodes: &[CompiledNode],
-    string_bytes: &[u8],
-    start_node_index: usize,
-    root_node: &Option<RootNode>,
-) -> Vec<(usize, usize)> {
-    let mut stack1 = Queue::default();
-    let mut stack2 = Queue::default();
-    let mut acceptors: Vec<AcceptorState> = Vec::new();
-    let mut stack_alt = true;
-    let mut string_index = 0usize;
-    let mut split_at = 0usize;
+// Search for the matches starting at every position in one pass over the string
+// Threads are grouped by where they started and only take priority over threads in the same group
+#[derive(Debug)]
+pub(crate) struct Overlapping {
+    current: Threads,
+    next: Threads,
+    stack: Vec<usize>,
+    index: usize,
+    finished: bool,
+    // Starts that still have threads or haven't been handed out yet, in order, with the ends found for them so far
+    starts: VecDeque<(usize, Vec<usize>)>,
+}
 
-    let mut out = Vec::new();
+impl Overlapping {
+    pub fn new(nodes: &[CompiledNode], start: usize) -> Self {
+        return Self {
+            current: Threads::new(nodes.len()),
+            next: Threads::new(nodes.len()),
+            stack: Vec::new(),
+            index: start,
+            finished: false,
+            starts: VecDeque::new(),
+        };
+    }
 
-    if let Some(root_node) = root_node {
-        match root_node.run(string_bytes, split_at) {
-            Some(idx) => {
-                stack1.insert(StackItem {
-                    node_index: root_node.child,
-                    ..Default::default()
-                });
-                split_at = idx;
-                string_index = idx;
+    // The next start with a match and where its matches end, either just the preferred one or all of them in order
+    pub fn next<H: Haystack + ?Sized>(&mut self, nodes: &[CompiledNode], start_node_index: usize, input: &Input<H>, all_ends: bool) -> Option<(usize, Vec<usize>)> {
+        let string = input.haystack();
+        let mut search = Search { nodes, input, stack: std::mem::take(&mut self.stack) };
+        let found = loop {
+            // Threads are in order of where they started, so the earliest start is done once the first thread started later
+            if let Some(&(start, _)) = self.starts.front() {
+                if self.current.threads.first().is_none_or(|(_, thread_start)| *thread_start > start) {
+                    let (start, ends) = self.starts.pop_front().unwrap();
+                    if ends.is_empty() {
+                        continue;
+                    }
+                    break Some((start, ends));
+                }
+            }
+            if self.finished {
+                break None;
             }
-            None => return out,
-        }
-    } else {
-        stack1.insert(StackItem {
-            node_index: start_node_index,
-            ..Default::default()
-        });
-    }
 
-    // let mut offset = 0;
-    '_outer: loop {
-        let cached: Option<(char, usize)> = decode_utf8(&string_bytes[split_at..]);
-        let (current_stack, to_add_stack) = if stack_alt {
-            (&mut stack1, &mut stack2)
-        } else {
-            (&mut stack2, &mut stack1)
-        };
-        'inner: while let Some(StackItem {
-            node_index: node_idx,
-            stacktrace,
-        }) = current_stack.pop()
-        {
-            let node = unsafe { nodes.get_unchecked(node_idx) };
-            let success: bool;
-            match &node.node {
-                CNode::Match(match_node) => match cached {
-                    Some(t) => {
-                        let character = &t.0;
-                        success = match_node.is_match(character);
-                        if success {
-                            match &node.children {
-                                Children::Multiple(vec) => {
-                                    for (index, child) in vec.iter().enumerate() {
-                                        let mut stacktrace = stacktrace.clone();
-                                        stacktrace.push(index);
-                                        to_add_stack.insert(StackItem {
-                                            node_index: *child,
-                                            stacktrace,
-                                        });
-                                    }
-                                }
-                                Children::Single(s) => {
-                                    to_add_stack.insert(StackItem { node_index: *s, stacktrace });
-                                }
-                                Children::None => panic!("Match node has no children"),
+            let index = self.index;
+            self.current.next_group();
+            search.add_thread(&mut self.current, start_node_index, index, index);
+            self.starts.push_back((index, Vec::new()));
+
+            let character = char_before_end(string, index, input.end());
+            let mut group = None;
+            let mut cut = None;
+            for &(node_index, thread_start) in self.current.threads.iter() {
+                if group != Some(thread_start) {
+                    group = Some(thread_start);
+                    self.next.next_group();
+                }
+                if cut == Some(thread_start) {
+                    continue;
+                }
+                let node = unsafe { nodes.get_unchecked(node_index) };
+                match &node.node {
+                    CNode::End => {
+                        let i = self.starts.binary_search_by_key(&thread_start, |(start, _)| *start).unwrap();
+                        let ends = &mut self.starts[i].1;
+                        if all_ends {
+                            if ends.last() != Some(&index) {
+                                ends.push(index);
                             }
+                        } else {
+                            // Threads further on in the group have a lower priority, and any later match from a thread before this one beats it
+                            *ends = vec![index];
+                            cut = Some(thread_start);
                         }
                     }
-                    None => continue 'inner,
-                },
-                CNode::Anchor(anchor_node) => {
-                    success = anchor_node.is_match(split_at, string_bytes, cached);
-                    if success {
-                        match &node.children {
-                            Children::Multiple(vec) => {
-                                for (index, child) in vec.iter().enumerate() {
-                                    let mut stacktrace = stacktrace.clone();
-                                    stacktrace.push(index);
-                                    current_stack.insert(StackItem {
-                                        node_index: *child,
-                                        stacktrace,
-                                    });
-                                }
-                            }
-                            Children::Single(s) => {
-                                current_stack.insert(StackItem { node_index: *s, stacktrace });
-                            }
-                            Children::None => panic!("Anchor node has no children"),
+                    CNode::Match(match_node) => {
+                        if let Some((c, len)) = character {
+                            search.step(&mut self.next, node, match_node, c, thread_start, index + len);
                         }
                     }
+                    _ => unreachable!(),
                 }
-                CNode::Behaviour(_) => match &node.children {
-                    Children::Multiple(vec) => {
-                        for (index, child) in vec.iter().enumerate() {
-                            let mut stacktrace = stacktrace.clone();
-                            stacktrace.push(index);
-                            current_stack.insert(StackItem {
-                                node_index: *child,
-                                stacktrace,
-                            });
-                        }
-                    }
-                    Children::Single(s) => {
-                        current_stack.insert(StackItem { node_index: *s, stacktrace });
-                    }
-                    Children::None => panic!("Behaviour node has no children"),
-                },
-                CNode::Special(_) => panic!("Special Nodes not supported on the BFS engine"),
-                CNode::End => {
-                    acceptors.push(AcceptorState(stacktrace, split_at));
-                },
-            };
-        }
-        if to_add_stack.is_empty() {
-            if !acceptors.is_empty() {
-                let accepted = acceptors.iter().min().unwrap();
-                out.push((string_index, accepted.1));
             }
-            string_index = next_utf8(string_bytes, string_index);
-            if string_index < string_bytes.len() {
-                if let Some(root_node) = root_node {
-                    match root_node.run(string_bytes, split_at) {
-                        Some(idx) => {
-                            to_add_stack.insert(StackItem{node_index: root_node.child, ..Default::default()});
-                            split_at = idx;
-                            string_index = idx;
-                        }
-                        None => return out,
-                    }
-                } else {
-                    to_add_stack.insert(StackItem{node_index: start_node_index, ..Default::default()});
-                    split_at = string_index;
-                }
-            } else {
-                return out;
+            match character {
+                Some((_, len)) => self.index += len,
+                None => self.finished = true,
             }
-        }  
-        stack_alt = !stack_alt;
-
-        if let Some((_, len)) = cached {
-            split_at += len;
-        }
+            std::mem::swap(&mut self.current, &mut self.next);
+            self.next.clear();
+        };
+        self.stack = search.stack;
+        return found;
     }
 }
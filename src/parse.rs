@@ -1,4 +1,4 @@
-use super::{compiled_node::CompiledNode, constants::*, nfa::*, optimize::*, regex::*, utils::*, *};
+use super::{compiled_node::CompiledNode, config::RegexConfig, constants::*, error::Error, nfa::*, optimize::*, regex::*, haystack::{Units, INVALID}, utils::*, *};
 
 enum ParseMode {
     SquareBrackets(Vec<char>, u16),
@@ -28,8 +28,30 @@ impl ParseToken {
 use ParseToken::*;
 
 impl Regex {
-    pub(crate) fn parse_expression(&mut self) {
-        let mut nodes = parse(str_to_char_vec(&self.expr));
+    pub(crate) fn parse_expression(&mut self) -> Result<(), Error> {
+        let mut group_names = fxhash::FxHashMap::default();
+        let expr = str_to_char_vec(&self.expr);
+        self.units = if !self.config.unicode || turns_off_unicode(&expr)? { Units::Bytes } else { Units::Utf8 };
+        if self.units == Units::Utf8 && expr.contains(&INVALID) {
+            return Err(Error::InvalidPattern { reason: String::from("U+10FFFF stands for invalid input and can't be matched") });
+        }
+        let nodes = parse(expr, &self.config, self.units, &mut group_names)?;
+        self.group_names = std::sync::Arc::new(group_names);
+        return self.compile_nodes(nodes);
+    }
+
+    pub(crate) fn compile_nodes(&mut self, mut nodes: Vec<Node>) -> Result<(), Error> {
+        if nodes.len() > self.config.size_limit {
+            return Err(Error::SizeLimitExceeded { limit: self.config.size_limit });
+        }
+        self.captures_len = 1 + nodes
+            .iter()
+            .filter_map(|node| match node {
+                Node::CapGroup { number, .. } => Some(*number as usize),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0);
         optimize(&mut nodes);
         let (x, y, f) = CompiledNode::compile(nodes);
         self.node_vec = x;
@@ -39,39 +61,139 @@ impl Regex {
             EngineFlag::Other => MatchingEngine::ParallelNFA {},
         });
         self.optimized_root_node = crate::root_node_optimizer::RootNode::generate(&self.node_vec, y, None);
+        return Ok(());
+    }
+}
+
+// The engines can only go through the haystack one way for the whole pattern, so (?-u) has to come first
+fn turns_off_unicode(string: &[char]) -> Result<bool, Error> {
+    let found = (1..string.len().saturating_sub(3)).any(|i| string[i..i + 4] == ['(', '?', '-', 'u'] && !check_if_escaped(string, i));
+    if found {
+        return Err(Error::InvalidPattern { reason: String::from("(?-u) is only supported at the start of the pattern") });
+    }
+    return Ok(string.starts_with(&['(', '?', '-', 'u']));
+}
+
+// Going byte by byte a character outside ASCII is matched by each byte of its UTF-8 encoding in turn
+fn add_literal(c: char, case_insensitive: bool, units: Units, node_vec: &mut Vec<Node>, callstack: &mut Vec<ParseToken>) {
+    if units == Units::Bytes && !c.is_ascii() {
+        let mut buffer = [0; 4];
+        for byte in c.encode_utf8(&mut buffer).bytes() {
+            add_character(byte as char, node_vec, callstack);
+        }
+        return;
     }
+    add_character_with_case(c, case_insensitive, node_vec, callstack);
 }
 
-fn parse(mut string: Vec<char>) -> Vec<Node> {
+// Quantifiers need something in front of them that consumes input, otherwise they'd loop on nothing
+fn check_repeatable(node_vec: &[Node], callstack: &[ParseToken]) -> Result<(), Error> {
+    let repeatable = match callstack.last() {
+        Some(S(index)) => matches!(
+            node_vec[*index],
+            Node::MatchOne { .. }
+                | Node::Inclusive { .. }
+                | Node::Exclusive { .. }
+                | Node::InclusiveRange { .. }
+                | Node::ExclusiveRange { .. }
+                | Node::MatchAll { .. }
+                | Node::MatchAllandNL { .. }
+                | Node::ExclusiveNodes { .. }
+                | Node::GraphemeCluster { .. }
+                | Node::LineBreak { .. }
+        ),
+        Some(M { .. }) => true,
+        None => false,
+    };
+    if !repeatable {
+        return Err(Error::InvalidPattern { reason: "nothing to repeat".to_string() });
+    }
+    return Ok(());
+}
+
+// A loop put around a group goes in front of where the group was entered, so the next one goes in front of the loop
+fn set_group_entry(callstack: &mut [ParseToken], entry: usize) {
+    if let Some(M { before, .. }) = callstack.last_mut() {
+        *before = entry;
+    }
+}
+
+// Names of named groups go into group_names along with their number
+fn parse(mut string: Vec<char>, config: &RegexConfig, units: Units, group_names: &mut fxhash::FxHashMap<String, usize>) -> Result<Vec<Node>, Error> {
     let mut _node_vec = vec![Node::new_transition(), Node::End];
     let ref mut node_vec = _node_vec;
     let mut callstack = vec![S(0), S(0)];
     let mut upcoming_transition_stack = vec![1];
     let mut state_stack = vec![ParseMode::Normal];
     let mut string_index = 0;
-    let mut case_insensitive = false;
-    let mut comment_mode = false;
+    let mut case_insensitive = config.case_insensitive;
+    let mut comment_mode = config.ignore_whitespace;
+    let mut unicode = config.unicode;
+    // Inline flags only last until the end of the group they're in
+    let mut flag_stack = Vec::new();
     // let mut looking_back = false;
     let mut current_cap_group = 1;
+    let mut repeat_counters = 0;
+    let invalid = |reason: &str| Error::InvalidPattern { reason: reason.to_string() };
     // let mut closing_bracket = false;
     while string_index < string.len() {
         // println!("{:?}", node_vec);
+        if node_vec.len() > config.size_limit {
+            return Err(Error::SizeLimitExceeded { limit: config.size_limit });
+        }
         let character = string[string_index];
         match state_stack.last_mut().unwrap() {
             ParseMode::Normal => {
+                // Whitespace and comments in (?x) mode are only there for readability
+                if comment_mode && (character.is_whitespace() || character == '#') {
+                    if character == '#' {
+                        state_stack.push(ParseMode::Comment);
+                    }
+                    string_index += 1;
+                    continue;
+                }
                 match character {
                     BACKSLASH => {
                         state_stack.push(ParseMode::Escaped);
                     }
                     '(' => {
+                        // The bottom of the stack belongs to the whole pattern rather than a group
+                        if upcoming_transition_stack.len() > config.nest_limit {
+                            return Err(Error::NestLimitExceeded { limit: config.nest_limit });
+                        }
                         let before_index: usize = node_vec.len();
                         let mut before = Node::new_transition();
                         let mut after = Node::new_transition();
                         let parse_rest: bool;
                         let mut remove_brackets = false;
-                        if string[string_index + 1] == '?' {
+                        // (?<name>...) and (?P<name>...), lookbehinds also start with (?<
+                        let named = string.get(string_index + 1) == Some(&'?')
+                            && (string.get(string_index + 2) == Some(&'P')
+                                || (string.get(string_index + 2) == Some(&'<') && string.get(string_index + 3) != Some(&'=') && string.get(string_index + 3) != Some(&'!')));
+                        if named {
+                            if string[string_index + 2] == 'P' && string.get(string_index + 3) != Some(&'<') {
+                                return Err(invalid("unsupported group (?P"));
+                            }
+                            let name_start = string_index + if string[string_index + 2] == 'P' { 4 } else { 3 };
+                            let name_end = string[name_start..].iter().position(|c| *c == '>').ok_or_else(|| invalid("unclosed group name"))? + name_start;
+                            let name = &string[name_start..name_end];
+                            // Templates only see names made of letters, digits and underscores
+                            if name.is_empty() || !name.iter().all(|c| *c == '_' || c.is_ascii_alphanumeric()) {
+                                return Err(Error::InvalidPattern { reason: format!("invalid group name {}", name.iter().collect::<String>()) });
+                            }
+                            group_names.insert(name.iter().collect(), current_cap_group as usize);
+                            string_index = name_end;
+                        }
+                        if !named && string.get(string_index + 1) == Some(&'?') {
                             string_index += 2;
-                            match string[string_index] {
+                            let flag = *string.get(string_index).ok_or_else(|| invalid("unclosed group"))?;
+                            // Flags and (?R) are only whole groups on their own
+                            if (matches!(flag, 'R' | 'i' | 'x' | 'u') && string.get(string_index + 1) != Some(&')'))
+                                || (flag == '-' && !matches!(string.get(string_index + 1..string_index + 3), Some(&['i' | 'x' | 'u', ')'])))
+                            {
+                                return Err(Error::InvalidPattern { reason: format!("unsupported group (?{}", flag) });
+                            }
+                            match flag {
                                 ':' => {
                                     parse_rest = true;
                                 }
@@ -91,12 +213,28 @@ fn parse(mut string: Vec<char>) -> Vec<Node> {
                                     parse_rest = true;
                                 }
                                 'i' => {
-                                    case_insensitive = !case_insensitive;
+                                    case_insensitive = true;
                                     parse_rest = false;
                                     remove_brackets = true;
                                 }
                                 'x' => {
-                                    comment_mode = !comment_mode;
+                                    comment_mode = true;
+                                    parse_rest = false;
+                                    remove_brackets = true;
+                                }
+                                'u' => {
+                                    unicode = true;
+                                    parse_rest = false;
+                                    remove_brackets = true;
+                                }
+                                '-' => {
+                                    match string[string_index + 1] {
+                                        'i' => case_insensitive = false,
+                                        'x' => comment_mode = false,
+                                        _ => unicode = false,
+                                    }
+                                    // Leaves (?i), (?x) or (?u) to be removed like the other flags
+                                    string.remove(string_index);
                                     parse_rest = false;
                                     remove_brackets = true;
                                 }
@@ -110,7 +248,7 @@ fn parse(mut string: Vec<char>) -> Vec<Node> {
                                     after = Node::EndNegativeLookAhead { children: vec![] };
                                     parse_rest = true;
                                 }
-                                _ => unimplemented!(),
+                                _ => return Err(Error::InvalidPattern { reason: format!("unsupported group (?{}", flag) }),
                             }
                         } else {
                             before = Node::CapGroup {
@@ -133,16 +271,30 @@ fn parse(mut string: Vec<char>) -> Vec<Node> {
                             callstack.push(S(len + 1));
                             callstack.push(S(len + 1));
                             upcoming_transition_stack.push(len + 2);
+                            flag_stack.push((case_insensitive, comment_mode, unicode));
                         } else if remove_brackets {
                             string.remove(string_index - 2);
                             string.remove(string_index - 2);
                             string.remove(string_index - 2);
                             string.remove(string_index - 2);
+                            // Carry on from whatever came after the brackets
+                            string_index -= 2;
+                            continue;
                         }
                     }
                     ')' => {
+                        // The bottom of the stack belongs to the whole pattern
+                        if upcoming_transition_stack.len() == 1 {
+                            return Err(invalid("unopened group"));
+                        }
                         let after_index = upcoming_transition_stack.pop().unwrap();
+                        let (outer_case_insensitive, outer_comment_mode, outer_unicode) = flag_stack.pop().unwrap();
+                        case_insensitive = outer_case_insensitive;
+                        comment_mode = outer_comment_mode;
+                        unicode = outer_unicode;
                         let current_last_node_index = callstack.pop().unwrap().idx();
+                        // Also drop where alternatives inside the group branch off from
+                        callstack.pop();
                         node_vec.get_mut(current_last_node_index).unwrap().push_child(after_index);
                         callstack.push(M{
                             after: after_index,
@@ -165,6 +317,7 @@ fn parse(mut string: Vec<char>) -> Vec<Node> {
                         // println!("After | Operator {:?}", callstack);
                     }
                     '+' => {
+                        check_repeatable(node_vec, &callstack)?;
                         let possessive = string_index < string.len() - 1 && '+' == string[string_index + 1] && !check_if_escaped(&string, string_index + 1);
                         let lazy = string_index < string.len() - 1 && '?' == string[string_index + 1] && !check_if_escaped(&string, string_index + 1);
                         if lazy || possessive {
@@ -191,15 +344,19 @@ fn parse(mut string: Vec<char>) -> Vec<Node> {
                                     node_vec.get_mut(i).unwrap().push_child(i)
                                 }
                             },
-                            M {before, after, first, last, ..} =>  {node_vec.get_mut(*after).unwrap().lazy_dependent_insert(*before, lazy);
+                            M {before, after, first, last, ..} =>  {
+                                let (first, last) = (*first, *last);
+                                let entry = add_group_loop(node_vec, first, *before, *after, lazy, &mut repeat_counters);
+                                set_group_entry(&mut callstack, entry);
                                 if possessive {
-                                    node_vec.get_mut(*first).unwrap().to_start_atomic();
-                                    node_vec.get_mut(*last).unwrap().to_end_atomic();
+                                    node_vec.get_mut(first).unwrap().to_start_atomic();
+                                    node_vec.get_mut(last).unwrap().to_end_atomic();
                                 }
                             },
                         }
                     }
                     '*' => {
+                        check_repeatable(node_vec, &callstack)?;
                         let possessive = string_index < string.len() - 1 && '+' == string[string_index + 1] && !check_if_escaped(&string, string_index + 1);
                         let lazy = string_index < string.len() - 1 && '?' == string[string_index + 1] && !check_if_escaped(&string, string_index + 1);
                         if lazy || possessive {
@@ -219,33 +376,42 @@ fn parse(mut string: Vec<char>) -> Vec<Node> {
                                     callstack.pop();
                                     callstack.push(S(node_vec.len() - 1));
                                 } else {
-                                    let mut node = node_vec.get(*last_node_index).unwrap().clone();
+                                    let last_node_index = *last_node_index;
+                                    let mut node = node_vec.get(last_node_index).unwrap().clone();
                                     let mut new_transition = Node::new_transition();
-                                    node.push_child(*last_node_index);
+                                    node.push_child(last_node_index);
                                     node_vec.push(node);
                                     match new_transition {
                                         Node::Transition { ref mut children, .. } => {
-                                            children.push(last_node_index + 1);
+                                            children.push(node_vec.len() - 1);
                                         }
                                         _ => panic!("Something went wrong here"),
                                     }
-                                    node_vec[*last_node_index] = new_transition;
+                                    node_vec[last_node_index] = new_transition;
+                                    if lazy {
+                                        // Link the way out now so it can go in front of the loop
+                                        add_node(Node::new_transition(), node_vec, &mut callstack);
+                                        node_vec[last_node_index].get_children_mut().unwrap().reverse();
+                                    }
                                 }
                             }
                             M {first, after, last, before, ..} => {
-                                node_vec.get_mut(*first).unwrap().lazy_dependent_insert(*last, lazy);
-                                node_vec.get_mut(*after).unwrap().lazy_dependent_insert(*before, lazy);
+                                let (first, last) = (*first, *last);
+                                node_vec.get_mut(first).unwrap().lazy_dependent_insert(last, lazy);
+                                let entry = add_group_loop(node_vec, first, *before, *after, lazy, &mut repeat_counters);
+                                set_group_entry(&mut callstack, entry);
                                 if possessive {
-                                    node_vec.get_mut(*first).unwrap().to_start_atomic();
-                                    node_vec.get_mut(*last).unwrap().to_end_atomic();
+                                    node_vec.get_mut(first).unwrap().to_start_atomic();
+                                    node_vec.get_mut(last).unwrap().to_end_atomic();
                                 }
                             }
                         }
                     }
                     '^' => add_node(Node::new_start_of_line(), node_vec, &mut callstack),
                     '$' => add_node(Node::new_end_of_line(), node_vec, &mut callstack),
-                    '.' => add_node(Node::new_match_all(), node_vec, &mut callstack),
+                    '.' => add_node(if config.dotall { Node::MatchAllandNL { children: vec![] } } else { Node::new_match_all() }, node_vec, &mut callstack),
                     '?' => {
+                        check_repeatable(node_vec, &callstack)?;
                         let possessive = string_index < string.len() - 1 && '+' == string[string_index + 1] && !check_if_escaped(&string, string_index + 1);
                         let lazy = string_index < string.len() - 1 && '?' == string[string_index + 1] && !check_if_escaped(&string, string_index + 1);
                         if lazy || possessive {
@@ -278,11 +444,12 @@ fn parse(mut string: Vec<char>) -> Vec<Node> {
                         }
                     }
                     '{' => {
+                        check_repeatable(node_vec, &callstack)?;
                         state_stack.push(ParseMode::CurlyBrackets(vec![]));
                         string.remove(string_index);
                         continue;
                     }
-                    _ => add_character(character, node_vec, &mut callstack),
+                    _ => add_literal(character, case_insensitive, units, node_vec, &mut callstack),
                 }
             }
             ParseMode::Escaped => {
@@ -298,28 +465,65 @@ fn parse(mut string: Vec<char>) -> Vec<Node> {
                     'W' => {
                         add_node(Node::new_from_chars(W.to_vec(), true), node_vec, &mut callstack);
                     }
+                    'h' => add_node(Node::new_from_chars(HORIZONTAL_SPACE.to_vec(), false), node_vec, &mut callstack),
+                    'H' => add_node(Node::new_from_chars(HORIZONTAL_SPACE.to_vec(), true), node_vec, &mut callstack),
+                    'v' => add_node(Node::new_from_chars(VERTICAL_SPACE.to_vec(), false), node_vec, &mut callstack),
+                    'V' => add_node(Node::new_from_chars(VERTICAL_SPACE.to_vec(), true), node_vec, &mut callstack),
+                    'X' => add_node(Node::GraphemeCluster { children: vec![] }, node_vec, &mut callstack),
+                    'R' => add_node(Node::LineBreak { children: vec![] }, node_vec, &mut callstack),
+                    'b' if string.get(string_index + 1) == Some(&'{') => {
+                        let end = string[string_index..].iter().position(|c| *c == '}').ok_or_else(|| invalid("unclosed boundary name"))? + string_index;
+                        let node = match string[string_index + 2..end].iter().collect::<String>().as_str() {
+                            "w" => Node::UnicodeWordBoundary { children: vec![] },
+                            "g" => Node::GraphemeBoundary { children: vec![] },
+                            "start" => Node::StartOfWord { children: vec![] },
+                            "end" => Node::EndOfWord { children: vec![] },
+                            name => return Err(Error::InvalidPattern { reason: format!("unknown boundary type \\b{{{}}}", name) }),
+                        };
+                        add_node(node, node_vec, &mut callstack);
+                        string_index = end;
+                    }
                     'b' => {
                         add_node(Node::WordBoundary { children: vec![] }, node_vec, &mut callstack);
                     }
+                    '<' => add_node(Node::StartOfWord { children: vec![] }, node_vec, &mut callstack),
+                    '>' => add_node(Node::EndOfWord { children: vec![] }, node_vec, &mut callstack),
                     'B' => {
                         add_node(Node::NotWordBoundary { children: vec![] }, node_vec, &mut callstack);
                     }
                     'c' => {
                         string_index += 1;
-                        let character = string[string_index];
-                        if let Some(c) = character.to_digit(26) {
-                            add_node(Node::new_from_char(c as u8 as char), node_vec, &mut callstack);
+                        match string.get(string_index).and_then(|character| character.to_digit(26)) {
+                            Some(c) => add_node(Node::new_from_char(c as u8 as char), node_vec, &mut callstack),
+                            None => return Err(invalid("invalid control character")),
+                        }
+                    }
+                    'x' => {
+                        let (value, last) = parse_hex_escape(&string, string_index + 1)?;
+                        if unicode {
+                            let c = char::from_u32(value).ok_or_else(|| Error::InvalidPattern { reason: format!("\\x{{{:X}}} isn't a Unicode scalar value", value) })?;
+                            add_literal(c, case_insensitive, units, node_vec, &mut callstack);
                         } else {
-                            panic!("Invalid Control Character");
+                            // Without Unicode it's a single byte, whether or not it's valid UTF-8 on its own
+                            if value > 0xFF {
+                                return Err(Error::InvalidPattern { reason: format!("\\x{{{:X}}} doesn't fit in a byte without Unicode", value) });
+                            }
+                            let byte = value as u8;
+                            add_character_with_case(byte as char, case_insensitive && byte.is_ascii(), node_vec, &mut callstack);
                         }
+                        string_index = last;
                     }
                     _ => {
-                        add_character(character, node_vec, &mut callstack);
+                        add_literal(character, case_insensitive, units, node_vec, &mut callstack);
                     }
                 };
                 state_stack.pop();
             }
-            ParseMode::Comment => {}
+            ParseMode::Comment => {
+                if character == '\n' {
+                    state_stack.pop();
+                }
+            }
             ParseMode::CurlyBrackets(expr) => {
                 if character == '}' {
                     string.remove(string_index);
@@ -328,6 +532,7 @@ fn parse(mut string: Vec<char>) -> Vec<Node> {
                     if string_index < string.len() && string[string_index] == '+' {
                         possessive = true;
                         lazy = false;
+                        string_index += 1;
                     }
                     else if string_index < string.len() && string[string_index] == '?' {
                         lazy = true;
@@ -337,7 +542,7 @@ fn parse(mut string: Vec<char>) -> Vec<Node> {
                         lazy = false;
                         possessive = false;
                     }
-                    parse_curly_brackets(&expr, node_vec, &mut callstack, lazy, possessive);
+                    parse_curly_brackets(expr, node_vec, &mut callstack, lazy, possessive, &mut repeat_counters, config)?;
                     string_index -= 1;
                     state_stack.pop();
                 } else {
@@ -353,7 +558,7 @@ fn parse(mut string: Vec<char>) -> Vec<Node> {
                 }
                 if character == ']' {
                     if !check_if_escaped(&string, string_index) {
-                        parse_square_brackets(expr, node_vec, &mut callstack);
+                        parse_square_brackets(expr, case_insensitive, node_vec, &mut callstack)?;
                         state_stack.pop();
                         string_index += 1;
                         continue;
@@ -367,10 +572,19 @@ fn parse(mut string: Vec<char>) -> Vec<Node> {
         }
         string_index += 1;
     }
+    match state_stack.last().unwrap() {
+        ParseMode::Escaped => return Err(invalid("pattern ends with a backslash")),
+        ParseMode::SquareBrackets(..) => return Err(invalid("unclosed character class")),
+        ParseMode::CurlyBrackets(..) => return Err(invalid("unclosed counted repetition")),
+        ParseMode::Normal | ParseMode::Comment => (),
+    }
+    if upcoming_transition_stack.len() > 1 {
+        return Err(invalid("unclosed group"));
+    }
     let index = callstack.last().unwrap();
     node_vec.get_mut(index.idx()).unwrap().push_child(1);
     // for (index, node) in node_vec.iter_mut().enumerate() {
     //     println!("{} --- {:?}", index, node);
     // }
-    return _node_vec;
+    return Ok(_node_vec);
 }
\ No newline at end of file
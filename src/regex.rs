@@ -1,9 +1,13 @@
 use super::compiled_node::CompiledNode;
 use super::config::*;
+use super::error::Error;
 use super::nfa::*;
+use fxhash::FxHashMap;
 use crate::root_node_optimizer::RootNode;
+use crate::haystack::{Haystack, Units};
 use std::alloc;
-use std::sync::Mutex;
+use std::ops::Index;
+use std::sync::{Arc, Mutex, OnceLock};
 
 #[derive(Debug)]
 pub struct Regex {
@@ -13,11 +17,40 @@ pub struct Regex {
     pub(crate) optimized_root_node: Option<RootNode>,
     pub(crate) engine: Mutex<MatchingEngine>,
     pub(crate) anchored: bool,
+    pub(crate) config: RegexConfig,
+    // Number of capture groups counting the whole match as group 0
+    pub(crate) captures_len: usize,
+    // Group number of every named group
+    pub(crate) group_names: Arc<FxHashMap<String, usize>>,
+    // Copy of the program that can only end at the end of the string and its start node, built the first time it's needed
+    pub(crate) full_match_program: OnceLock<(Vec<CompiledNode>, usize)>,
+    // Program with every edge flipped for searching right to left, None when the pattern needs the backtracker
+    pub(crate) reverse_program: OnceLock<Option<(Vec<CompiledNode>, usize)>>,
+    // Bytes when the pattern starts with (?-u), only bytes::Regex can be built like that
+    pub(crate) units: Units,
 }
 
 impl Clone for Regex {
     fn clone(&self) -> Self {
-        return Self::new(&self.expr);
+        // The engine only holds scratch space for a search, so the clone gets an empty one of the same kind
+        let engine = match *self.engine.lock().unwrap() {
+            MatchingEngine::Backtrack { .. } => MatchingEngine::default(),
+            ref other => other.clone(),
+        };
+        return Regex {
+            expr: self.expr.clone(),
+            node_vec: self.node_vec.clone(),
+            root_node_idx: self.root_node_idx,
+            optimized_root_node: self.optimized_root_node.clone(),
+            engine: Mutex::new(engine),
+            anchored: self.anchored,
+            config: self.config,
+            captures_len: self.captures_len,
+            group_names: Arc::clone(&self.group_names),
+            full_match_program: self.full_match_program.clone(),
+            reverse_program: self.reverse_program.clone(),
+            units: self.units,
+        };
     }
 }
 
@@ -31,17 +64,501 @@ impl Regex {
             optimized_root_node: None,
             engine: Mutex::new(MatchingEngine::default()),
             anchored: false,
+            config: RegexConfig::default(),
+            captures_len: 1,
+            group_names: Arc::new(FxHashMap::default()),
+            full_match_program: OnceLock::new(),
+            reverse_program: OnceLock::new(),
+            units: Units::Utf8,
         };
     }
 
+    // Panics if the pattern goes over any of the default limits, use try_new for patterns that aren't trusted
     pub fn new(regex: &str) -> Self {
+        return Self::try_new(regex).unwrap();
+    }
+
+    pub fn try_new(regex: &str) -> Result<Self, Error> {
+        return Self::with_config(regex, RegexConfig::default());
+    }
+
+    pub fn with_config(regex: &str, config: RegexConfig) -> Result<Self, Error> {
+        return Self::build(regex, config, false);
+    }
+
+    // Matching single bytes could split a character in two, so only haystacks that aren't str allow it
+    pub(crate) fn build(regex: &str, config: RegexConfig, allow_bytes: bool) -> Result<Self, Error> {
         let mut r = Self::base();
         r.expr = regex.to_string();
-        r.parse_expression();
+        r.config = config;
+        r.parse_expression()?;
+        if r.units == Units::Bytes && !allow_bytes {
+            return Err(Error::UnicodeRequired);
+        }
+        return Ok(r);
+    }
+
+    // Builds the MatchOne chain directly instead of going through the parser.
+    // The root node becomes a substring search so the engine only ever starts on an actual occurrence.
+    pub fn literal(literal: &str) -> Self {
+        use crate::parse::ParseToken;
+        use crate::utils::{add_character, escape};
+        let mut r = Self::base();
+        r.expr = escape(literal);
+        let mut nodes = vec![Node::new_transition(), Node::End];
+        let mut callstack = vec![ParseToken::S(0)];
+        for c in literal.chars() {
+            add_character(c, &mut nodes, &mut callstack);
+        }
+        nodes.get_mut(callstack.last().unwrap().idx()).unwrap().push_child(1);
+        r.compile_nodes(nodes).unwrap();
+        if !literal.is_empty() {
+            r.optimized_root_node = Some(RootNode::literal(literal, r.root_node_idx));
+        }
         return r;
     }
 }
 
+// A match in the haystack, positions are in the haystack's own units, which are bytes for str
+#[derive(Debug, PartialEq, Eq)]
+pub struct Match<'h, H: ?Sized = str> {
+    haystack: &'h H,
+    start: usize,
+    end: usize,
+}
+
+// Derived Clone and Copy would want H to be Copy, same as Input
+impl<'h, H: ?Sized> Clone for Match<'h, H> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'h, H: ?Sized> Copy for Match<'h, H> {}
+
+impl<'h, H: ?Sized> Match<'h, H> {
+    pub(crate) fn new(haystack: &'h H, start: usize, end: usize) -> Self {
+        return Self { haystack, start, end };
+    }
+
+    pub fn start(&self) -> usize {
+        return self.start;
+    }
+
+    pub fn end(&self) -> usize {
+        return self.end;
+    }
+
+    pub fn range(&self) -> std::ops::Range<usize> {
+        return self.start..self.end;
+    }
+}
+
+impl<'h, H: ?Sized + Index<std::ops::Range<usize>, Output = H>> Match<'h, H> {
+    // The part of the haystack that matched
+    pub(crate) fn slice(&self) -> &'h H {
+        return &self.haystack[self.start..self.end];
+    }
+}
+
+impl<'h> Match<'h> {
+    pub fn as_str(&self) -> &'h str {
+        return self.slice();
+    }
+}
+
+// What to search and how, positions are in the haystack's own units, which are bytes for str
+// Only the span is searched but anchors like ^ and \b still look at the characters around it
+// The haystack is a str unless it comes from bytes::Regex, utf16::Regex or one of the search_range methods
+#[derive(Debug, PartialEq, Eq)]
+pub struct Input<'h, H: ?Sized = str> {
+    haystack: &'h H,
+    start: usize,
+    end: usize,
+    // Only look for a match starting right at the start of the span
+    anchored: bool,
+    // Stop at the first position a match is known to exist, the end of the match may be off
+    earliest: bool,
+}
+
+// Derived Clone and Copy would want H to be Copy, which str and [u8] aren't
+impl<'h, H: ?Sized> Clone for Input<'h, H> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'h, H: ?Sized> Copy for Input<'h, H> {}
+
+impl<'h, H: ?Sized + Haystack> Input<'h, H> {
+    pub fn new(haystack: &'h H) -> Self {
+        return Self {
+            haystack,
+            start: 0,
+            end: haystack.len(),
+            anchored: false,
+            earliest: false,
+        };
+    }
+
+    // Panics if the span is out of bounds or the haystack says it can't start or end where it does
+    pub fn span(mut self, span: std::ops::Range<usize>) -> Self {
+        if span.start > span.end || span.end > self.haystack.len() {
+            panic!("Invalid span {:?} for a haystack of length {}", span, self.haystack.len());
+        }
+        if !self.haystack.is_char_boundary(span.start) || !self.haystack.is_char_boundary(span.end) {
+            panic!("Span {:?} doesn't fall on character boundaries", span);
+        }
+        self.start = span.start;
+        self.end = span.end;
+        return self;
+    }
+}
+
+impl<'h, H: ?Sized> Input<'h, H> {
+    pub fn anchored(mut self, anchored: bool) -> Self {
+        self.anchored = anchored;
+        return self;
+    }
+
+    pub fn earliest(mut self, earliest: bool) -> Self {
+        self.earliest = earliest;
+        return self;
+    }
+
+    pub fn haystack(&self) -> &'h H {
+        return self.haystack;
+    }
+
+    pub fn start(&self) -> usize {
+        return self.start;
+    }
+
+    pub fn end(&self) -> usize {
+        return self.end;
+    }
+
+    pub fn is_anchored(&self) -> bool {
+        return self.anchored;
+    }
+
+    pub fn is_earliest(&self) -> bool {
+        return self.earliest;
+    }
+
+    // Same search over another haystack with the same positions
+    pub(crate) fn with_haystack<'a, G: ?Sized>(&self, haystack: &'a G) -> Input<'a, G> {
+        return Input {
+            haystack,
+            start: self.start,
+            end: self.end,
+            anchored: self.anchored,
+            earliest: self.earliest,
+        };
+    }
+}
+
+// Where each capture group matched, group 0 being the whole match
+#[derive(Debug, PartialEq, Eq)]
+pub struct Captures<'h, H: ?Sized = str> {
+    haystack: &'h H,
+    // Start and end of every group next to each other
+    slots: Vec<Option<usize>>,
+    group_names: Arc<FxHashMap<String, usize>>,
+}
+
+impl<'h, H: ?Sized> Clone for Captures<'h, H> {
+    fn clone(&self) -> Self {
+        return Self {
+            haystack: self.haystack,
+            slots: self.slots.clone(),
+            group_names: self.group_names.clone(),
+        };
+    }
+}
+
+impl<'h, H: ?Sized> Captures<'h, H> {
+    pub(crate) fn new(haystack: &'h H, slots: Vec<Option<usize>>, group_names: Arc<FxHashMap<String, usize>>) -> Self {
+        return Self { haystack, slots, group_names };
+    }
+
+    pub fn name(&self, name: &str) -> Option<Match<'h, H>> {
+        return self.get(*self.group_names.get(name)?);
+    }
+
+    // Groups first..first + len as their own set of captures, for a pattern that was put inside a bigger one
+    pub(crate) fn sub_captures(&self, first: usize, len: usize, group_names: Arc<FxHashMap<String, usize>>) -> Captures<'h, H> {
+        return Captures::new(self.haystack, self.slots[first * 2..(first + len) * 2].to_vec(), group_names);
+    }
+
+    // Number of the group with this name if the regex has one
+    pub(crate) fn group_number(&self, name: &str) -> Option<usize> {
+        return self.group_names.get(name).copied();
+    }
+
+    // None if the group didn't take part in the match
+    pub fn get(&self, group: usize) -> Option<Match<'h, H>> {
+        let start = (*self.slots.get(group * 2)?)?;
+        let end = (*self.slots.get(group * 2 + 1)?)?;
+        return Some(Match::new(self.haystack, start, end));
+    }
+
+    pub fn len(&self) -> usize {
+        return self.slots.len() / 2;
+    }
+
+    // Never true for captures from a match since group 0 is always there
+    pub fn is_empty(&self) -> bool {
+        return self.slots.is_empty();
+    }
+}
+
+impl<'h, H: ?Sized + Index<std::ops::Range<usize>, Output = H>> Index<usize> for Captures<'h, H> {
+    type Output = H;
+
+    fn index(&self, group: usize) -> &H {
+        return self.get(group).map(|m| m.slice()).unwrap_or_else(|| panic!("No match for group {}", group));
+    }
+}
+
+impl<'h, 'n, H: ?Sized + Index<std::ops::Range<usize>, Output = H>> Index<&'n str> for Captures<'h, H> {
+    type Output = H;
+
+    fn index(&self, name: &'n str) -> &H {
+        return self.name(name).map(|m| m.slice()).unwrap_or_else(|| panic!("No match for group {}", name));
+    }
+}
+
+// What the iterators need from a regex, which is all that differs between regex::Regex and the ones for other haystacks
+pub(crate) trait FindMatches<H: ?Sized>: std::fmt::Debug {
+    fn search_span(&self, input: &Input<H>) -> Option<(usize, usize)>;
+
+    fn search_captures<'h>(&self, input: &Input<'h, H>) -> Option<Captures<'h, H>>;
+
+    // Where the search carries on from after an empty match at `at`
+    fn next_index(&self, haystack: &H, at: usize) -> usize;
+}
+
+impl<H: ?Sized + Haystack> FindMatches<H> for Regex {
+    fn search_span(&self, input: &Input<H>) -> Option<(usize, usize)> {
+        return Regex::search_span(self, input);
+    }
+
+    fn search_captures<'h>(&self, input: &Input<'h, H>) -> Option<Captures<'h, H>> {
+        let slots = self.search_slots(input)?;
+        return Some(Captures::new(input.haystack(), slots, self.group_names.clone()));
+    }
+
+    fn next_index(&self, haystack: &H, at: usize) -> usize {
+        return crate::haystack::next_index(haystack, at);
+    }
+}
+
+// Successive non-overlapping matches, each search picks up where the last match ended
+// An empty match right where the previous match ended is skipped, and after an empty match the search moves on by one character
+#[derive(Debug)]
+pub struct Matches<'r, 'h, H: ?Sized = str> {
+    regex: &'r dyn FindMatches<H>,
+    input: Input<'h, H>,
+    position: usize,
+    last_match_end: Option<usize>,
+}
+
+impl<'r, 'h, H: ?Sized> Matches<'r, 'h, H> {
+    pub(crate) fn new(regex: &'r dyn FindMatches<H>, input: Input<'h, H>) -> Self {
+        return Self {
+            regex,
+            input,
+            position: input.start(),
+            last_match_end: None,
+        };
+    }
+}
+
+impl<'r, 'h, H: ?Sized + Haystack> Iterator for Matches<'r, 'h, H> {
+    type Item = Match<'h, H>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.position > self.input.end() {
+                return None;
+            }
+            let (start, end) = self.regex.search_span(&self.input.span(self.position..self.input.end()))?;
+            if start == end {
+                self.position = self.regex.next_index(self.input.haystack(), end);
+                if self.last_match_end == Some(end) {
+                    continue;
+                }
+            } else {
+                self.position = end;
+            }
+            self.last_match_end = Some(end);
+            return Some(Match::new(self.input.haystack(), start, end));
+        }
+    }
+}
+
+// Capture groups of each match find_iter would return
+#[derive(Debug)]
+pub struct CaptureMatches<'r, 'h, H: ?Sized = str> {
+    matches: Matches<'r, 'h, H>,
+}
+
+impl<'r, 'h, H: ?Sized> CaptureMatches<'r, 'h, H> {
+    pub(crate) fn new(matches: Matches<'r, 'h, H>) -> Self {
+        return Self { matches };
+    }
+}
+
+impl<'r, 'h, H: ?Sized + Haystack> Iterator for CaptureMatches<'r, 'h, H> {
+    type Item = Captures<'h, H>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let m = self.matches.next()?;
+        // The preferred match starting where this one does is this one
+        let input = self.matches.input.span(m.start()..self.matches.input.end()).anchored(true);
+        return self.matches.regex.search_captures(&input);
+    }
+}
+
+// Matches find_iter would give for any haystack, as ranges in the haystack's own units
+#[derive(Debug)]
+pub struct RangeMatches<'r, 'h, H: ?Sized> {
+    matches: Matches<'r, 'h, H>,
+}
+
+impl<'r, 'h, H: ?Sized + Haystack> RangeMatches<'r, 'h, H> {
+    pub(crate) fn new(regex: &'r Regex, input: Input<'h, H>) -> Self {
+        return Self {
+            matches: Matches::new(regex, input),
+        };
+    }
+}
+
+impl<'r, 'h, H: ?Sized + Haystack> Iterator for RangeMatches<'r, 'h, H> {
+    type Item = std::ops::Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        return self.matches.next().map(|m| m.range());
+    }
+}
+
+// The matches find_iter gives in reverse order
+// The haystack is searched forwards a piece at a time from the end, each piece starting where no match crosses
+#[derive(Debug)]
+pub struct RMatches<'r, 'h> {
+    regex: &'r Regex,
+    haystack: &'h str,
+    // End of the part of the haystack still to be searched, None once the start has been passed
+    end: Option<usize>,
+    // Matches in the last piece searched, handed out from the back
+    found: Vec<(usize, usize)>,
+    // Length of the next piece, grows while pieces have no matches so a search for one that's far back doesn't redo the end over and over
+    distance: usize,
+}
+
+impl<'r, 'h> RMatches<'r, 'h> {
+    pub(crate) fn new(regex: &'r Regex, haystack: &'h str) -> Self {
+        return Self {
+            regex,
+            haystack,
+            end: Some(haystack.len()),
+            found: Vec::new(),
+            distance: 64,
+        };
+    }
+}
+
+impl<'r, 'h> Iterator for RMatches<'r, 'h> {
+    type Item = Match<'h>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((start, end)) = self.found.pop() {
+                return Some(Match::new(self.haystack, start, end));
+            }
+            let end = self.end?;
+            let start = self.regex.sync_point(self.haystack, end, self.distance);
+            let input = Input::new(self.haystack).span(start..end);
+            // An empty match at the end of a piece belongs to the piece after it
+            self.found = Matches::new(self.regex, input)
+                .filter(|m| end == self.haystack.len() || m.start() < end)
+                .map(|m| (m.start(), m.end()))
+                .collect();
+            self.distance = if self.found.is_empty() { 2 * (end - start) } else { 64 };
+            self.end = if start == 0 { None } else { Some(start) };
+        }
+    }
+}
+
+// Matches starting at every position that has one, each search starts one character after the previous match started
+// With all_ends set each start gives a match for every place it can end instead of just the preferred one, shortest first
+#[derive(Debug)]
+pub struct OverlappingMatches<'r, 'h> {
+    regex: &'r Regex,
+    haystack: &'h str,
+    position: usize,
+    all_ends: bool,
+    // Start and the ends that haven't been returned yet
+    pending: Option<(usize, std::vec::IntoIter<usize>)>,
+    // The parallel engine finds the matches for every start in one pass, the backtracker searches again from each start
+    overlapping: Option<crate::parallel_nfa::Overlapping>,
+}
+
+impl<'r, 'h> OverlappingMatches<'r, 'h> {
+    pub(crate) fn new(regex: &'r Regex, haystack: &'h str) -> Self {
+        let parallel = matches!(*regex.engine.lock().unwrap(), MatchingEngine::ParallelNFA {});
+        return Self {
+            regex,
+            haystack,
+            position: 0,
+            all_ends: false,
+            pending: None,
+            overlapping: if parallel { Some(crate::parallel_nfa::Overlapping::new(&regex.node_vec, 0)) } else { None },
+        };
+    }
+
+    pub fn all_ends(mut self, all_ends: bool) -> Self {
+        self.all_ends = all_ends;
+        return self;
+    }
+}
+
+impl<'r, 'h> Iterator for OverlappingMatches<'r, 'h> {
+    type Item = Match<'h>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use crate::utf_8::next_utf8;
+        if let Some((start, ends)) = &mut self.pending {
+            if let Some(end) = ends.next() {
+                return Some(Match::new(self.haystack, *start, end));
+            }
+            self.pending = None;
+        }
+        if let Some(overlapping) = &mut self.overlapping {
+            let (start, ends) = overlapping.next(&self.regex.node_vec, self.regex.root_node_idx, &Input::new(self.haystack), self.all_ends)?;
+            let mut ends = ends.into_iter();
+            let end = ends.next()?;
+            self.pending = Some((start, ends));
+            return Some(Match::new(self.haystack, start, end));
+        }
+        let len = self.haystack.len();
+        if self.position > len {
+            return None;
+        }
+        let input = Input::new(self.haystack).span(self.position..len);
+        let (start, end) = self.regex.search_span(&input)?;
+        self.position = next_utf8(self.haystack.as_bytes(), start);
+        if !self.all_ends {
+            return Some(Match::new(self.haystack, start, end));
+        }
+        let mut ends = self.regex.match_ends(&input.span(start..len)).into_iter();
+        let end = ends.next()?;
+        self.pending = Some((start, ends));
+        return Some(Match::new(self.haystack, start, end));
+    }
+}
+
 // struct RegexSet {
 //     // not exactly sure what the plural of regex is...
 //     pub regexes: Vec<Regex>,
@@ -53,8 +570,6 @@ impl Regex {
 //     }
 // }
 
-use fxhash::FxHashMap;
-
 #[derive(Copy, Debug, Clone, PartialEq)]
 pub enum EngineFlag {
     Backtrack,
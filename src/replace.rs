@@ -1,105 +1,576 @@
-// use super::regex::*;
-// use super::utils::*;
-
-// impl Regex {
-//     pub fn replace_first(&self, s: &str, r: &str) -> String {
-//         // let chars = str_to_char_vec(s);
-//         // let mut indices: Option<(usize, usize)> = None;
-//         // for i in 0..chars.len() {
-//         //     match get_index_match(&self.node_vec, &chars, &0, i) {
-//         //         None => (),
-//         //         Some(x) => {
-//         //             indices = Some((i, x));
-//         //             break;
-//         //         }
-//         //     }
-//         // }
-//         // if let Some(x) = indices {
-//         //     let replace_with = str_to_char_vec(r);
-//         //     let (start, end) = x;
-//         //     return char_vec_to_string(&replace_range(start, end, &replace_with, &chars));
-//         // }
-//         return s.to_string();
-//     }
-
-//     pub fn replace_first_mapped<F: FnMut(String) -> String>(&self, s: &str, mut func: F) -> String {
-//         // let chars = str_to_char_vec(s);
-//         // let mut indices: Option<(usize, usize)> = None;
-//         // for i in 0..chars.len() {
-//         //     if let Some(x) = get_index_match(&self.node_vec, &chars, &0, i) {
-//         //         indices = Some((i, x));
-//         //         break;
-//         //     }
-//         // }
-//         // if let Some(x) = indices {
-//         //     let (start, end) = x;
-//         //     return char_vec_to_string(&replace_mapped(start, end, &mut func, &chars));
-//         // }
-//         return s.to_string();
-//     }
-
-//     pub fn replace_all(&self, s: &str, r: &str) -> String {
-//         let mut chars = str_to_char_vec(s);
-//         // let mut indices: Vec<(usize, usize)> = Vec::new();
-//         // let mut i = 0usize;
-//         // while i < chars.len() {
-//         //     if let Some(end) = get_index_match(&self.node_vec, &chars, &0, i) {
-//         //         indices.push((i, end));
-//         //         i = end;
-//         //     }
-//         //     i += 1;
-//         // }
-//         // indices.reverse();
-//         // let replace_with = str_to_char_vec(r);
-//         // for (start, end) in indices {
-//         //     chars = replace_range(start, end, &replace_with, &chars);
-//         // }
-//         return char_vec_to_string(&chars);
-//     }
-
-//     pub fn replace_all_mapped<F: FnMut(String) -> String>(&self, s: &str, mut func: F) -> String {
-//         let mut chars = str_to_char_vec(s);
-//         // let mut indices: Vec<(usize, usize)> = Vec::new();
-//         // let mut i = 0usize;
-//         // while i < chars.len() {
-//         //     match get_index_match(&self.node_vec, &chars, &0, i) {
-//         //         None => (),
-//         //         Some(end) => {
-//         //             indices.push((i, end));
-//         //             i = end;
-//         //         }
-//         //     }
-//         //     i += 1;
-//         // }
-//         // if indices.is_empty() {
-//         //     return s.to_string();
-//         // }
-//         // indices.reverse();
-//         // for (start, end) in indices {
-//         //     chars = replace_mapped(start, end, &mut func, &chars);
-//         // }
-//         return char_vec_to_string(&chars);
-//     }
-// }
-
-// fn replace_range(start: usize, end: usize, r: &[char], chars: &[char]) -> Vec<char> {
-//     let mut out = Vec::new();
-//     let (v1, _) = chars.split_at(start);
-//     let (_, v2) = chars.split_at(end);
-//     out.extend(v1);
-//     out.extend(r);
-//     out.extend(v2);
-//     return out;
-// }
-
-// fn replace_mapped<F: FnMut(String) -> String>(start: usize, end: usize, func: &mut F, chars: &[char]) -> Vec<char> {
-//     let mut out = Vec::new();
-//     let (v1, v3) = chars.split_at(start);
-//     let (_, v2) = chars.split_at(end);
-//     let (m, _) = v3.split_at(end - v1.len());
-//     let r = str_to_char_vec(&func(char_vec_to_string(m)));
-//     out.extend(v1);
-//     out.extend(r);
-//     out.extend(v2);
-//     return out;
-// }
+use super::config::RegexConfig;
+use super::error::Error;
+use super::regex::*;
+use super::utils::is_meta_character;
+use super::stream::Searcher;
+use std::borrow::Cow;
+use std::io::{self, BufRead, Write};
+use std::ops::DerefMut;
+
+// What a match gets replaced with
+pub trait Replacer {
+    fn replace_append(&mut self, caps: &Captures, dst: &mut String);
+
+    // Replacements that don't depend on the match can skip finding capture groups altogether
+    fn no_expansion(&mut self) -> Option<Cow<'_, str>> {
+        return None;
+    }
+
+    // Replacements that are templates can be parsed once for every match of a call instead of once per match
+    fn template(&mut self) -> Option<Template> {
+        return None;
+    }
+}
+
+// Strings are templates, see Template
+impl Replacer for &str {
+    fn replace_append(&mut self, caps: &Captures, dst: &mut String) {
+        caps.expand(self, dst);
+    }
+
+    fn no_expansion(&mut self) -> Option<Cow<'_, str>> {
+        if self.contains('$') {
+            return None;
+        }
+        return Some(Cow::Borrowed(self));
+    }
+
+    fn template(&mut self) -> Option<Template> {
+        return Some(Template::parse(self));
+    }
+}
+
+impl Replacer for &String {
+    fn replace_append(&mut self, caps: &Captures, dst: &mut String) {
+        self.as_str().replace_append(caps, dst);
+    }
+
+    fn no_expansion(&mut self) -> Option<Cow<'_, str>> {
+        if self.contains('$') {
+            return None;
+        }
+        return Some(Cow::Borrowed(self.as_str()));
+    }
+
+    fn template(&mut self) -> Option<Template> {
+        return Some(Template::parse(self));
+    }
+}
+
+// Replaces with the string as it is, without expanding $
+#[derive(Copy, Clone, Debug)]
+pub struct NoExpand<'a>(pub &'a str);
+
+impl<'a> Replacer for NoExpand<'a> {
+    fn replace_append(&mut self, _caps: &Captures, dst: &mut String) {
+        dst.push_str(self.0);
+    }
+
+    fn no_expansion(&mut self) -> Option<Cow<'_, str>> {
+        return Some(Cow::Borrowed(self.0));
+    }
+}
+
+// Parsed once so it can be used for any number of matches
+// Strict templates were checked against their regex when made strict, with any other regex missing groups expand to nothing
+impl Replacer for &Template {
+    fn replace_append(&mut self, caps: &Captures, dst: &mut String) {
+        let _ = self.expand_with(caps, dst, false);
+    }
+
+    fn no_expansion(&mut self) -> Option<Cow<'_, str>> {
+        return match self.pieces.as_slice() {
+            [] => Some(Cow::Borrowed("")),
+            [Piece::Literal(literal)] => Some(Cow::Borrowed(literal.as_str())),
+            _ => None,
+        };
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Case {
+    Upper,
+    Lower,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Piece {
+    Literal(String),
+    Group(usize),
+    Named(String),
+    // \U and \L start changing the case of everything after them, \E stops it
+    Span(Option<Case>),
+    // \u and \l only change the next character
+    Next(Case),
+}
+
+// Replacement string where $1 or $name is swapped for what that group matched and $$ is a literal $
+// The longest run of letters, digits and underscores after the $ is taken as the name, ${name} marks where it ends
+// A $ that isn't followed by a name is left as it is
+// Extended templates also take \U, \L, \E, \u and \l to change case like Perl and sed, and \\ for a backslash
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Template {
+    pieces: Vec<Piece>,
+    // Whether referring to a group the regex doesn't have is an error rather than expanding to nothing
+    strict: bool,
+}
+
+impl Template {
+    pub fn parse(template: &str) -> Self {
+        return Self::parse_with(template, false);
+    }
+
+    pub fn parse_extended(template: &str) -> Self {
+        return Self::parse_with(template, true);
+    }
+
+    fn parse_with(template: &str, extended: bool) -> Self {
+        let mut pieces = Vec::new();
+        let mut literal = String::new();
+        let mut rest = template;
+        while let Some(special) = rest.find(|c: char| c == '$' || (extended && c == '\\')) {
+            literal.push_str(&rest[..special]);
+            rest = &rest[special..];
+            if rest.starts_with('\\') {
+                let piece = match rest[1..].chars().next() {
+                    Some('U') => Piece::Span(Some(Case::Upper)),
+                    Some('L') => Piece::Span(Some(Case::Lower)),
+                    Some('E') => Piece::Span(None),
+                    Some('u') => Piece::Next(Case::Upper),
+                    Some('l') => Piece::Next(Case::Lower),
+                    Some('\\') => {
+                        literal.push('\\');
+                        rest = &rest[2..];
+                        continue;
+                    }
+                    // Any other escape is left as it is
+                    _ => {
+                        literal.push('\\');
+                        rest = &rest[1..];
+                        continue;
+                    }
+                };
+                if !literal.is_empty() {
+                    pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+                }
+                pieces.push(piece);
+                rest = &rest[2..];
+                continue;
+            }
+            if rest.starts_with("$$") {
+                literal.push('$');
+                rest = &rest[2..];
+                continue;
+            }
+            let (name, len) = if rest[1..].starts_with('{') {
+                match rest.find('}') {
+                    Some(end) => (&rest[2..end], end + 1),
+                    None => ("", 0),
+                }
+            } else {
+                let end = rest[1..].find(|c: char| !(c == '_' || c.is_ascii_alphanumeric())).map_or(rest.len(), |end| end + 1);
+                (&rest[1..end], end)
+            };
+            if name.is_empty() {
+                literal.push('$');
+                rest = &rest[1..];
+                continue;
+            }
+            if !literal.is_empty() {
+                pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+            }
+            pieces.push(match name.parse::<usize>() {
+                Ok(number) => Piece::Group(number),
+                Err(_) => Piece::Named(name.to_string()),
+            });
+            rest = &rest[len..];
+        }
+        literal.push_str(rest);
+        if !literal.is_empty() {
+            pieces.push(Piece::Literal(literal));
+        }
+        return Self { pieces, strict: false };
+    }
+
+    // Makes referring to a group the regex doesn't have an error, which is checked here once and for all
+    pub fn strict(mut self, regex: &Regex) -> Result<Self, Error> {
+        self.validate(regex)?;
+        self.strict = true;
+        return Ok(self);
+    }
+
+    // Checks every group the template refers to exists in the regex
+    pub fn validate(&self, regex: &Regex) -> Result<(), Error> {
+        for piece in self.pieces.iter() {
+            match piece {
+                Piece::Group(number) if *number >= regex.captures_len => return Err(Error::UnknownGroup { group: number.to_string() }),
+                Piece::Named(name) if !regex.group_names.contains_key(name) => return Err(Error::UnknownGroup { group: name.clone() }),
+                _ => (),
+            }
+        }
+        return Ok(());
+    }
+
+    // Groups that didn't take part in the match expand to nothing
+    pub fn expand(&self, caps: &Captures, dst: &mut String) -> Result<(), Error> {
+        return self.expand_with(caps, dst, self.strict);
+    }
+
+    fn expand_with(&self, caps: &Captures, dst: &mut String, strict: bool) -> Result<(), Error> {
+        let mut span = None;
+        let mut next = None;
+        for piece in self.pieces.iter() {
+            let group = match piece {
+                Piece::Literal(literal) => {
+                    push_cased(dst, literal, span, &mut next);
+                    continue;
+                }
+                Piece::Span(case) => {
+                    span = *case;
+                    continue;
+                }
+                Piece::Next(case) => {
+                    next = Some(*case);
+                    continue;
+                }
+                Piece::Group(number) if *number < caps.len() => Some(*number),
+                Piece::Named(name) => caps.group_number(name),
+                Piece::Group(_) => None,
+            };
+            match group {
+                Some(group) => push_cased(dst, caps.get(group).map_or("", |m| m.as_str()), span, &mut next),
+                None if strict => {
+                    return Err(Error::UnknownGroup {
+                        group: match piece {
+                            Piece::Group(number) => number.to_string(),
+                            Piece::Named(name) => name.clone(),
+                            _ => unreachable!(),
+                        },
+                    })
+                }
+                None => (),
+            }
+        }
+        return Ok(());
+    }
+}
+
+// A single character change takes priority over the span it's in and carries over empty text
+fn push_cased(dst: &mut String, text: &str, span: Option<Case>, next: &mut Option<Case>) {
+    if span.is_none() && next.is_none() {
+        dst.push_str(text);
+        return;
+    }
+    for c in text.chars() {
+        match next.take().or(span) {
+            Some(Case::Upper) => dst.extend(c.to_uppercase()),
+            Some(Case::Lower) => dst.extend(c.to_lowercase()),
+            None => dst.push(c),
+        }
+    }
+}
+
+impl<'h> Captures<'h> {
+    // Appends the template with groups filled in from this match, references to groups that don't exist expand to nothing
+    pub fn expand(&self, template: &str, dst: &mut String) {
+        Template::parse(template).expand(self, dst).unwrap();
+    }
+}
+
+impl<F, T> Replacer for F
+where
+    F: FnMut(&Captures) -> T,
+    T: AsRef<str>,
+{
+    fn replace_append(&mut self, caps: &Captures, dst: &mut String) {
+        dst.push_str(self(caps).as_ref());
+    }
+}
+
+impl Regex {
+    // Only the first match is replaced
+    pub fn replace<'h, R: Replacer>(&self, string: &'h str, replacement: R) -> Cow<'h, str> {
+        return self.replacen(string, 1, replacement);
+    }
+
+    pub fn replace_all<'h, R: Replacer>(&self, string: &'h str, replacement: R) -> Cow<'h, str> {
+        return self.replacen(string, 0, replacement);
+    }
+
+    // Replaces the first limit matches, or all of them if limit is 0
+    // The string is only copied if something matched
+    pub fn replacen<'h, R: Replacer>(&self, string: &'h str, limit: usize, mut replacement: R) -> Cow<'h, str> {
+        let limit = if limit == 0 { usize::MAX } else { limit };
+        let mut out = String::new();
+        let mut last = 0;
+        let mut replaced = false;
+        if let Some(literal) = replacement.no_expansion() {
+            for m in self.find_iter(string).take(limit) {
+                out.push_str(&string[last..m.start()]);
+                out.push_str(&literal);
+                last = m.end();
+                replaced = true;
+            }
+        } else {
+            let template = replacement.template();
+            for caps in self.captures_iter(string).take(limit) {
+                let m = caps.get(0).unwrap();
+                out.push_str(&string[last..m.start()]);
+                match &template {
+                    Some(template) => template.expand(&caps, &mut out).unwrap(),
+                    None => replacement.replace_append(&caps, &mut out),
+                }
+                last = m.end();
+                replaced = true;
+            }
+        }
+        if !replaced {
+            return Cow::Borrowed(string);
+        }
+        out.push_str(&string[last..]);
+        return Cow::Owned(out);
+    }
+}
+
+impl Regex {
+    // Same as replace_all but reads and writes as it goes, only holding on to text a match might still need
+    // The search carries on from where the last chunk left it, and patterns that would have to keep the whole input
+    // around, which are the ones StreamMatcher turns down, are an InvalidInput error before anything is read
+    pub fn replace_all_to_writer<R: BufRead, W: Write, P: Replacer>(&self, mut reader: R, mut writer: W, mut replacement: P) -> io::Result<()> {
+        use crate::utf_8::decode_last_utf8;
+        let mut searcher = Searcher::new(self).map_err(|reason| io::Error::new(io::ErrorKind::InvalidInput, Error::Unstreamable { reason: reason.to_string() }))?;
+        let literal = replacement.no_expansion().map(|literal| literal.into_owned());
+        let template = replacement.template();
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "stream did not contain valid UTF-8");
+        // Text that hasn't been written yet, along with one character before it so anchors can see what came before
+        let mut buffer = Vec::new();
+        // Where the buffer starts in the input
+        let mut offset = 0;
+        let mut written = 0;
+        loop {
+            let chunk = reader.fill_buf()?;
+            let eof = chunk.is_empty();
+            buffer.extend_from_slice(chunk);
+            let consumed = chunk.len();
+            reader.consume(consumed);
+
+            let valid = match std::str::from_utf8(&buffer) {
+                Ok(text) => text.len(),
+                // A character cut off by the end of the chunk gets finished off by the next one
+                Err(error) if error.error_len().is_none() && !eof => error.valid_up_to(),
+                Err(_) => return Err(invalid()),
+            };
+            let text = unsafe { std::str::from_utf8_unchecked(&buffer[..valid]) };
+            // Anchors at the end of the span need to see the character after it, so the last one waits for the next chunk
+            let end = if eof { valid } else { decode_last_utf8(text.as_bytes()).map_or(0, |(_, len)| valid - len) };
+
+            let mut out = String::new();
+            for (start, match_end) in searcher.search(self, text.as_bytes(), offset, end, eof) {
+                let (start, match_end) = (start - offset, match_end - offset);
+                out.push_str(&text[written..start]);
+                match &literal {
+                    Some(literal) => out.push_str(literal),
+                    None => {
+                        let caps = self.search_captures(&Input::new(text).span(start..end).anchored(true)).unwrap();
+                        match &template {
+                            Some(template) => template.expand(&caps, &mut out).unwrap(),
+                            None => replacement.replace_append(&caps, &mut out),
+                        }
+                    }
+                }
+                written = match_end;
+            }
+            if eof {
+                out.push_str(&text[written..]);
+                writer.write_all(out.as_bytes())?;
+                return writer.flush();
+            }
+            // Nothing before where the next match could start is going to be replaced
+            let safe = searcher.earliest_start() - offset;
+            if written < safe {
+                out.push_str(&text[written..safe]);
+                written = safe;
+            }
+            writer.write_all(out.as_bytes())?;
+
+            // Drop what's been dealt with apart from the character before it
+            let keep = decode_last_utf8(&buffer[..written]).map_or(written, |(_, len)| written - len);
+            buffer.drain(..keep);
+            offset += keep;
+            written -= keep;
+        }
+    }
+}
+
+// Applies a list of rules in one pass, each match of any rule is replaced and nothing it puts in is matched again
+// Matches are found leftmost first over all rules together, when rules match at the same place the earlier one wins
+// even if a later one would match more
+#[derive(Clone, Debug)]
+pub struct MultiReplacer {
+    // Every rule's pattern in its own group, one after another in an alternation
+    regex: Option<Regex>,
+    rules: Vec<Rule>,
+}
+
+#[derive(Clone, Debug)]
+struct Rule {
+    // Group around the rule's pattern in the combined regex, its own groups come straight after
+    group: usize,
+    captures_len: usize,
+    group_names: std::sync::Arc<fxhash::FxHashMap<String, usize>>,
+    replacement: Template,
+}
+
+impl MultiReplacer {
+    // Replacements are templates, referring to a group the rule's pattern doesn't have is an error
+    pub fn new<P: AsRef<str>, R: AsRef<str>>(rules: &[(P, R)]) -> Result<Self, Error> {
+        let mut combined = Vec::new();
+        let mut compiled = Vec::new();
+        let mut group = 1;
+        for (pattern, replacement) in rules.iter() {
+            let regex = Regex::try_new(pattern.as_ref())?;
+            let replacement = Template::parse(replacement.as_ref()).strict(&regex)?;
+            // Each rule is checked on its own above, the (?x) and newline end a # comment it finishes in so the
+            // comment can't swallow the rules after it, and the group keeps its flags to itself
+            combined.push(format!("({}(?x)\n)", pattern.as_ref()));
+            compiled.push(Rule {
+                group,
+                captures_len: regex.captures_len,
+                group_names: regex.group_names.clone(),
+                replacement,
+            });
+            group += regex.captures_len;
+        }
+        let regex = if combined.is_empty() { None } else { Some(Regex::try_new(&combined.join("|"))?) };
+        return Ok(Self { regex, rules: compiled });
+    }
+
+    pub fn replace_all<'h>(&self, string: &'h str) -> Cow<'h, str> {
+        let regex = match &self.regex {
+            Some(regex) => regex,
+            None => return Cow::Borrowed(string),
+        };
+        let mut out = String::new();
+        let mut last = 0;
+        let mut replaced = false;
+        for caps in regex.captures_iter(string) {
+            let rule = self.rules.iter().find(|rule| caps.get(rule.group).is_some()).unwrap();
+            let m = caps.get(0).unwrap();
+            out.push_str(&string[last..m.start()]);
+            let rule_caps = caps.sub_captures(rule.group, rule.captures_len, rule.group_names.clone());
+            rule.replacement.expand(&rule_caps, &mut out).unwrap();
+            last = m.end();
+            replaced = true;
+        }
+        if !replaced {
+            return Cow::Borrowed(string);
+        }
+        out.push_str(&string[last..]);
+        return Cow::Owned(out);
+    }
+}
+
+// A sed style s/pattern/replacement/flags command, any character other than a backslash or a newline can be the delimiter
+// The replacement is an extended template and a delimiter inside either part is escaped with a backslash
+// Flags: g replaces every match, i ignores case, s lets . match newlines, x allows comments and whitespace,
+// a number replaces only that match or with g every match from it onwards, m is accepted but ^ and $ already match at lines
+#[derive(Clone, Debug)]
+pub struct Substitution {
+    regex: Regex,
+    replacement: Template,
+    global: bool,
+    // Counting from 1
+    occurrence: usize,
+}
+
+impl Substitution {
+    pub fn parse(command: &str) -> Result<Self, Error> {
+        let invalid = |reason: &str| Error::InvalidSubstitution { reason: reason.to_string() };
+        let mut chars = command.chars();
+        if chars.next() != Some('s') {
+            return Err(invalid("doesn't start with s"));
+        }
+        let delimiter = match chars.next() {
+            Some('\\') | Some('\n') | None => return Err(invalid("missing delimiter")),
+            Some(delimiter) => delimiter,
+        };
+        // An escaped delimiter is a literal delimiter, which still needs escaping in the pattern if it means something there
+        let pattern_delimiter = if is_meta_character(delimiter) { format!("\\{}", delimiter) } else { delimiter.to_string() };
+        let replacement_delimiter = if delimiter == '$' { String::from("$$") } else { delimiter.to_string() };
+        let pattern = split_part(&mut chars, delimiter, &pattern_delimiter).ok_or_else(|| invalid("unterminated pattern"))?;
+        let replacement = split_part(&mut chars, delimiter, &replacement_delimiter).ok_or_else(|| invalid("unterminated replacement"))?;
+
+        let mut config = RegexConfig::default();
+        let mut global = false;
+        let mut occurrence = String::new();
+        for flag in chars {
+            match flag {
+                'g' => global = true,
+                'i' => config.case_insensitive = true,
+                'x' => config.ignore_whitespace = true,
+                's' => config.dotall = true,
+                'm' => (),
+                '0'..='9' => occurrence.push(flag),
+                _ => return Err(invalid(&format!("unknown flag {}", flag))),
+            }
+        }
+        let occurrence = match occurrence.as_str() {
+            "" => 1,
+            number => match number.parse::<usize>() {
+                Ok(0) | Err(_) => return Err(invalid("occurrence has to be a number from 1")),
+                Ok(number) => number,
+            },
+        };
+        let regex = Regex::with_config(&pattern, config)?;
+        let replacement = Template::parse_extended(&replacement).strict(&regex)?;
+        return Ok(Self {
+            regex,
+            replacement,
+            global,
+            occurrence,
+        });
+    }
+
+    pub fn regex(&self) -> &Regex {
+        return &self.regex;
+    }
+
+    pub fn apply<'h>(&self, string: &'h str) -> Cow<'h, str> {
+        let mut out = String::new();
+        let mut last = 0;
+        let mut replaced = false;
+        for caps in self.regex.captures_iter(string).skip(self.occurrence - 1) {
+            if replaced && !self.global {
+                break;
+            }
+            let m = caps.get(0).unwrap();
+            out.push_str(&string[last..m.start()]);
+            self.replacement.expand(&caps, &mut out).unwrap();
+            last = m.end();
+            replaced = true;
+        }
+        if !replaced {
+            return Cow::Borrowed(string);
+        }
+        out.push_str(&string[last..]);
+        return Cow::Owned(out);
+    }
+}
+
+// Takes everything up to the next unescaped delimiter, None if there isn't one
+fn split_part(chars: &mut std::str::Chars, delimiter: char, escaped_delimiter: &str) -> Option<String> {
+    let mut part = String::new();
+    loop {
+        match chars.next()? {
+            '\\' => match chars.next()? {
+                c if c == delimiter => part.push_str(escaped_delimiter),
+                c => {
+                    part.push('\\');
+                    part.push(c);
+                }
+            },
+            c if c == delimiter => return Some(part),
+            c => part.push(c),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::compiled_node::{One, *};
+use crate::haystack::{next_index, Haystack};
 use crate::utils::RangeUtils;
 
 // Brings the core loop spent over most of the characters as low as possible with as few branches as possible
@@ -11,6 +12,8 @@ pub(crate) struct RootNode {
     pub node: CNode,
     pub child: usize,
     advance_on_match: bool,
+    // Set for literal regexes, the node is ignored and run becomes a plain substring search
+    needle: Option<String>,
 }
 
 impl RootNode {
@@ -25,6 +28,7 @@ impl RootNode {
                         node: start_node.node.clone(),
                         advance_on_match: children.as_ref().map(|_| false).unwrap_or(true),
                         child: children.unwrap_or(*child),
+                        needle: None,
                     })
                 }
                 _ => {
@@ -32,6 +36,7 @@ impl RootNode {
                         node: start_node.node.clone(),
                         advance_on_match: false,
                         child: children.unwrap_or(start),
+                        needle: None,
                     })
                 }
             },
@@ -72,6 +77,7 @@ impl RootNode {
                             return Some(Self {
                                 advance_on_match: false,
                                 child: children.unwrap_or(start),
+                                needle: None,
                                 node: CNode::Match(MatchNode::Range(Range::Exclusive(crate::sorted_vec::SortedVec::from(
                                     no_match_characters,
                                 )))),
@@ -112,6 +118,7 @@ impl RootNode {
                                 node: CNode::Match(MatchNode::Range(Range::InclusiveRange(match_ranges))),
                                 advance_on_match: false,
                                 child: children.unwrap_or(start),
+                                needle: None,
                             });
                         } else {
                             for (start, end) in match_ranges {
@@ -121,6 +128,7 @@ impl RootNode {
                                 node: CNode::Match(MatchNode::Range(Range::Inclusive(crate::sorted_vec::SortedVec::from(match_characters)))),
                                 advance_on_match: false,
                                 child: children.unwrap_or(start),
+                                needle: None,
                             });
                         }
                     }
@@ -136,6 +144,7 @@ impl RootNode {
                         node: start_node.node.clone(),
                         advance_on_match: false,
                         child: children.unwrap_or(*child),
+                        needle: None,
                     })
                 }
                 _ => {
@@ -143,6 +152,7 @@ impl RootNode {
                         node: start_node.node.clone(),
                         advance_on_match: false,
                         child: children.unwrap_or(start),
+                        needle: None,
                     })
                 }
             },
@@ -150,10 +160,35 @@ impl RootNode {
         }
     }
 
+    pub fn literal(needle: &str, child: usize) -> Self {
+        return Self {
+            node: CNode::End,
+            child,
+            advance_on_match: false,
+            needle: Some(needle.to_string()),
+        };
+    }
+
+    // Where the next possible match starting at or after index begins, and where the rest of the program picks up from
+    // The two only differ when the root node has already consumed the first character
     #[inline(always)]
-    pub fn run(&self, string: &[u8], mut index: usize) -> Option<usize> /* String index */ {
-        use crate::utf_8::*;
+    pub fn run<H: Haystack + ?Sized>(&self, string: &H, index: usize) -> Option<(usize, usize)> {
+        let start = self.find_start(string, index)?;
+        if self.advance_on_match {
+            return Some((start, next_index(string, start)));
+        }
+        return Some((start, start));
+    }
+
+    #[inline(always)]
+    fn find_start<H: Haystack + ?Sized>(&self, string: &H, mut index: usize) -> Option<usize> {
         use CNode::*;
+        if index > string.len() {
+            return None;
+        }
+        if let Some(needle) = &self.needle {
+            return string.find_literal(index, needle);
+        }
         match &self.node {
             Match(match_node) => {
                 match match_node {
@@ -161,11 +196,8 @@ impl RootNode {
                         One::MatchOne(c) => {
                             let c = *c;
                             while index < string.len() {
-                                if let Some((character, len)) = decode_utf8(&string[index..]) {
+                                if let Some((character, len)) = string.next_char(index) {
                                     if character == c {
-                                        if self.advance_on_match {
-                                            index += len;
-                                        }
                                         return Some(index);
                                     } else {
                                         index += len;
@@ -178,11 +210,8 @@ impl RootNode {
                         One::NotMatchOne(c) => {
                             let c = *c;
                             while index < string.len() {
-                                if let Some((character, len)) = decode_utf8(&string[index..]) {
+                                if let Some((character, len)) = string.next_char(index) {
                                     if character != c {
-                                        if self.advance_on_match {
-                                            index += len;
-                                        }
                                         return Some(index);
                                     } else {
                                         index += len;
@@ -197,11 +226,8 @@ impl RootNode {
                     MatchNode::Range(match_node) => match match_node {
                         Range::Inclusive(chars) => {
                             while index < string.len() {
-                                if let Some((character, len)) = decode_utf8(&string[index..]) {
+                                if let Some((character, len)) = string.next_char(index) {
                                     if chars.contains(&character) {
-                                        if self.advance_on_match {
-                                            index += len;
-                                        }
                                         return Some(index);
                                     } else {
                                         index += len;
@@ -213,11 +239,8 @@ impl RootNode {
                         }
                         Range::Exclusive(chars) => {
                             while index < string.len() {
-                                if let Some((character, len)) = decode_utf8(&string[index..]) {
+                                if let Some((character, len)) = string.next_char(index) {
                                     if !chars.contains(&character) {
-                                        if self.advance_on_match {
-                                            index += len;
-                                        }
                                         return Some(index);
                                     } else {
                                         index += len;
@@ -229,11 +252,8 @@ impl RootNode {
                         }
                         Range::InclusiveRange(ranges) => {
                             while index < string.len() {
-                                if let Some((character, len)) = decode_utf8(&string[index..]) {
+                                if let Some((character, len)) = string.next_char(index) {
                                     if ranges.find(&character) {
-                                        if self.advance_on_match {
-                                            index += len;
-                                        }
                                         return Some(index);
                                     }
                                     index += len;
@@ -244,11 +264,8 @@ impl RootNode {
                         }
                         Range::ExclusiveRange(ranges) => {
                             while index < string.len() {
-                                if let Some((character, len)) = decode_utf8(&string[index..]) {
+                                if let Some((character, len)) = string.next_char(index) {
                                     if !ranges.find(&character) {
-                                        if self.advance_on_match {
-                                            index += len;
-                                        }
                                         return Some(index);
                                     }
                                     index += len;
@@ -266,7 +283,7 @@ impl RootNode {
                     if index > string.len() {
                         return None;
                     }
-                    let character = decode_utf8(&string[index..]);
+                    let character = string.next_char(index);
                     if index == 0 {
                         if let Some((character, len)) = character {
                             if character._is_alphanumeric() {
@@ -279,16 +296,16 @@ impl RootNode {
                         }
                     }
 
-                    let mut last_character = decode_last_utf8(&string[..index]);
+                    let mut last_character = string.prev_char(index);
                     while index < string.len() {
-                        if let Some((new_character, len)) = decode_utf8(&string[index..]) {
+                        if let Some((new_character, len)) = string.next_char(index) {
                             if let Some((last_character, _)) = last_character {
                                 if (new_character._is_alphanumeric() && !last_character._is_alphanumeric())
                                     || (!new_character._is_alphanumeric() && last_character._is_alphanumeric())
                                 {
                                     return Some(index);
                                 }
-                            } else if let Some((last_character, _)) = decode_last_utf8(&string[..index]) {
+                            } else if let Some((last_character, _)) = string.prev_char(index) {
                                 if (new_character._is_alphanumeric() && !last_character._is_alphanumeric())
                                     || (!new_character._is_alphanumeric() && last_character._is_alphanumeric())
                                 {
@@ -316,7 +333,7 @@ impl RootNode {
                     if index > string.len() {
                         return None;
                     }
-                    let character = decode_utf8(&string[index..]);
+                    let character = string.next_char(index);
                     if index == 0 {
                         if let Some((character, len)) = character {
                             if !character._is_alphanumeric() {
@@ -329,16 +346,16 @@ impl RootNode {
                         }
                     }
 
-                    let mut last_character = decode_last_utf8(&string[..index]);
+                    let mut last_character = string.prev_char(index);
                     while index < string.len() {
-                        if let Some((new_character, len)) = decode_utf8(&string[index..]) {
+                        if let Some((new_character, len)) = string.next_char(index) {
                             if let Some((last_character, _)) = last_character {
                                 if !((new_character._is_alphanumeric() && !last_character._is_alphanumeric())
                                     || (!new_character._is_alphanumeric() && last_character._is_alphanumeric()))
                                 {
                                     return Some(index);
                                 }
-                            } else if let Some((last_character, _)) = decode_last_utf8(&string[..index]) {
+                            } else if let Some((last_character, _)) = string.prev_char(index) {
                                 if !((new_character._is_alphanumeric() && !last_character._is_alphanumeric())
                                     || (!new_character._is_alphanumeric() && last_character._is_alphanumeric()))
                                 {
@@ -362,6 +379,14 @@ impl RootNode {
                         return None;
                     }
                 }
+                AnchorNode::UnicodeWordBoundary | AnchorNode::GraphemeBoundary | AnchorNode::StartOfWord | AnchorNode::EndOfWord => {
+                    while index <= string.len() {
+                        if anchor_node.is_match(index, string, string.next_char(index)) {
+                            return Some(index);
+                        }
+                        index = next_index(string, index);
+                    }
+                }
                 AnchorNode::StartOfString => {
                     if index == 0 {
                         return Some(0);
@@ -381,12 +406,12 @@ impl RootNode {
                         return Some(0);
                     }
                     let mut last_character: Option<(char, usize)> = None;
-                    while index < string.len() {
+                    while index <= string.len() {
                         if let Some((character, _)) = last_character {
                             if character == '\n' {
                                 return Some(index);
                             }
-                        } else if let Some((character, _)) = decode_last_utf8(&string[..index]) {
+                        } else if let Some((character, _)) = string.prev_char(index) {
                             if character == '\n' {
                                 return Some(index);
                             }
@@ -394,7 +419,7 @@ impl RootNode {
                             return None;
                         }
 
-                        if let Some(t) = decode_utf8(&string[index..]) {
+                        if let Some(t) = string.next_char(index) {
                             index += t.1;
                             last_character = Some(t);
                         } else {
@@ -403,11 +428,8 @@ impl RootNode {
                     }
                 }
                 AnchorNode::EndOfLine => {
-                    if index == string.len() {
-                        return Some(string.len());
-                    }
                     while index < string.len() {
-                        if let Some((character, len)) = decode_utf8(&string[..index]) {
+                        if let Some((character, len)) = string.next_char(index) {
                             if character == '\n' {
                                 return Some(index);
                             } else {
@@ -417,6 +439,9 @@ impl RootNode {
                             return None;
                         }
                     }
+                    if index == string.len() {
+                        return Some(index);
+                    }
                 }
             },
             _ => unreachable!("Only match and anchor nodes supported in the root node"),
@@ -0,0 +1,258 @@
+// Unicode text segmentation (UAX #29) used by \X and the grapheme/word boundary assertions.
+// The property tables are in segmentation_tables.rs, generated from the Unicode data files by segmentation.js
+
+use crate::haystack::Haystack;
+use crate::segmentation_tables::{EXTENDED_PICTOGRAPHIC, GRAPHEME_BREAK, WORD_BREAK};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum GraphemeBreak {
+    CR,
+    LF,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    LV,
+    Lvt,
+    Other,
+}
+
+pub(crate) fn grapheme_break(c: char) -> GraphemeBreak {
+    return lookup(c, GRAPHEME_BREAK).unwrap_or(GraphemeBreak::Other);
+}
+
+pub(crate) fn is_extended_pictographic(c: char) -> bool {
+    return in_table(c, EXTENDED_PICTOGRAPHIC);
+}
+
+// Tracks the parts of the left context that the pairwise rules can't see: how many regional indicators are in a row (GB12/GB13)
+// and whether we're in an emoji sequence that a ZWJ can continue (GB11)
+#[derive(Copy, Clone, Debug, Default)]
+pub(crate) struct GraphemeState {
+    regional_indicators: usize,
+    emoji: bool,
+    emoji_zwj: bool,
+}
+
+impl GraphemeState {
+    pub fn advance(&mut self, c: char) {
+        let property = grapheme_break(c);
+        if property == GraphemeBreak::RegionalIndicator {
+            self.regional_indicators += 1;
+        } else {
+            self.regional_indicators = 0;
+        }
+        if is_extended_pictographic(c) {
+            self.emoji = true;
+            self.emoji_zwj = false;
+        } else if property == GraphemeBreak::Zwj {
+            self.emoji_zwj = self.emoji;
+            self.emoji = false;
+        } else if property != GraphemeBreak::Extend {
+            self.emoji = false;
+            self.emoji_zwj = false;
+        }
+    }
+
+    // Whether there is a grapheme cluster boundary between the last character passed to advance and `next`
+    pub fn is_break(&self, previous: char, next: char) -> bool {
+        use GraphemeBreak::*;
+        return match (grapheme_break(previous), grapheme_break(next)) {
+            (CR, LF) => false,
+            (CR, _) | (LF, _) | (Control, _) => true,
+            (_, CR) | (_, LF) | (_, Control) => true,
+            (L, L) | (L, V) | (L, LV) | (L, Lvt) => false,
+            (LV, V) | (V, V) | (LV, T) | (V, T) => false,
+            (Lvt, T) | (T, T) => false,
+            (_, Extend) | (_, Zwj) | (_, SpacingMark) => false,
+            (Prepend, _) => false,
+            (Zwj, _) if self.emoji_zwj && is_extended_pictographic(next) => false,
+            (RegionalIndicator, RegionalIndicator) => self.regional_indicators.is_multiple_of(2),
+            _ => true,
+        };
+    }
+}
+
+// Grapheme cluster boundary (\b{g}) at a position in the haystack, rebuilding the left context the pairwise rules need by looking backwards
+pub(crate) fn is_grapheme_boundary<H: Haystack + ?Sized>(string: &H, index: usize) -> bool {
+    let (next, previous, previous_len) = match (string.next_char(index), string.prev_char(index)) {
+        (Some((next, _)), Some((previous, len))) => (next, previous, len),
+        // Start and end of text, unless the text is empty
+        _ => return !string.is_empty(),
+    };
+    let mut state = GraphemeState::default();
+    let mut i = index;
+    while let Some((c, len)) = string.prev_char(i) {
+        if grapheme_break(c) != GraphemeBreak::RegionalIndicator {
+            break;
+        }
+        state.regional_indicators += 1;
+        i -= len;
+    }
+    if grapheme_break(previous) == GraphemeBreak::Zwj {
+        let mut i = index - previous_len;
+        while let Some((c, len)) = string.prev_char(i) {
+            if is_extended_pictographic(c) {
+                state.emoji_zwj = true;
+                break;
+            } else if grapheme_break(c) != GraphemeBreak::Extend {
+                break;
+            }
+            i -= len;
+        }
+    }
+    return state.is_break(previous, next);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum WordBreak {
+    CR,
+    LF,
+    Newline,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Format,
+    Katakana,
+    HebrewLetter,
+    ALetter,
+    SingleQuote,
+    DoubleQuote,
+    MidNumLet,
+    MidLetter,
+    MidNum,
+    Numeric,
+    ExtendNumLet,
+    WSegSpace,
+    Other,
+}
+
+pub(crate) fn word_break(c: char) -> WordBreak {
+    return lookup(c, WORD_BREAK).unwrap_or(WordBreak::Other);
+}
+
+// Word boundary (\b{w}) at a position in the haystack. Extend, Format and ZWJ characters are skipped over (WB4)
+// so up to two characters on each side of the index are looked at.
+pub(crate) fn is_word_boundary<H: Haystack + ?Sized>(string: &H, index: usize) -> bool {
+    use WordBreak::*;
+    let (next_char, next_len, raw_previous) = match (string.next_char(index), string.prev_char(index)) {
+        (Some((next, len)), Some((previous, _))) => (next, len, previous),
+        _ => return !string.is_empty(),
+    };
+    let next = word_break(next_char);
+    // WB3 - WB4 only look at the characters directly around the index
+    match (word_break(raw_previous), next) {
+        (CR, LF) => return false,
+        (CR, _) | (LF, _) | (Newline, _) | (_, CR) | (_, LF) | (_, Newline) => return true,
+        (Zwj, _) if is_extended_pictographic(next_char) => return false,
+        (WSegSpace, WSegSpace) => return false,
+        (_, Extend) | (_, Format) | (_, Zwj) => return false,
+        _ => {}
+    }
+    let ignored = |w: WordBreak| w == Extend || w == Format || w == Zwj;
+    // Walks backwards over the characters before `end`, skipping the ignored ones
+    let before = |mut end: usize| -> Option<(WordBreak, usize)> {
+        while let Some((c, len)) = string.prev_char(end) {
+            end -= len;
+            let w = word_break(c);
+            if !ignored(w) {
+                return Some((w, end));
+            }
+        }
+        return None;
+    };
+    let after = |mut start: usize| -> Option<WordBreak> {
+        while let Some((c, len)) = string.next_char(start) {
+            start += len;
+            let w = word_break(c);
+            if !ignored(w) {
+                return Some(w);
+            }
+        }
+        return None;
+    };
+    let (previous, previous_start) = match before(index) {
+        Some(t) => t,
+        None => return true,
+    };
+    let before_previous = before(previous_start).map(|t| t.0);
+    let after_next = after(index + next_len);
+    let ah_letter = |w: WordBreak| w == ALetter || w == HebrewLetter;
+    let mid_letter = |w: WordBreak| w == MidLetter || w == MidNumLet || w == SingleQuote;
+    let mid_num = |w: WordBreak| w == MidNum || w == MidNumLet || w == SingleQuote;
+    let is = |w: Option<WordBreak>, f: &dyn Fn(WordBreak) -> bool| w.map(f).unwrap_or(false);
+
+    // WB5 - WB13b
+    if ah_letter(previous) && ah_letter(next) {
+        return false;
+    }
+    if ah_letter(previous) && mid_letter(next) && is(after_next, &ah_letter) {
+        return false;
+    }
+    if is(before_previous, &ah_letter) && mid_letter(previous) && ah_letter(next) {
+        return false;
+    }
+    if previous == HebrewLetter && next == SingleQuote {
+        return false;
+    }
+    if previous == HebrewLetter && next == DoubleQuote && after_next == Some(HebrewLetter) {
+        return false;
+    }
+    if before_previous == Some(HebrewLetter) && previous == DoubleQuote && next == HebrewLetter {
+        return false;
+    }
+    if (previous == Numeric || ah_letter(previous)) && (next == Numeric || ah_letter(next)) {
+        return false;
+    }
+    if before_previous == Some(Numeric) && mid_num(previous) && next == Numeric {
+        return false;
+    }
+    if previous == Numeric && mid_num(next) && after_next == Some(Numeric) {
+        return false;
+    }
+    if previous == Katakana && next == Katakana {
+        return false;
+    }
+    if (ah_letter(previous) || previous == Numeric || previous == Katakana || previous == ExtendNumLet) && next == ExtendNumLet {
+        return false;
+    }
+    if previous == ExtendNumLet && (ah_letter(next) || next == Numeric || next == Katakana) {
+        return false;
+    }
+    // WB15 and WB16, regional indicators pair up
+    if previous == RegionalIndicator && next == RegionalIndicator {
+        let mut count = 1;
+        let mut end = previous_start;
+        while let Some((RegionalIndicator, start)) = before(end) {
+            count += 1;
+            end = start;
+        }
+        return count % 2 == 0;
+    }
+    return true;
+}
+
+fn in_table(c: char, table: &[(char, char)]) -> bool {
+    return table.binary_search_by(|(start, end)| compare_range(c, *start, *end)).is_ok();
+}
+
+// The property value of the range c is in, the tables are sorted and don't overlap
+fn lookup<T: Copy>(c: char, table: &[(char, char, T)]) -> Option<T> {
+    return table.binary_search_by(|(start, end, _)| compare_range(c, *start, *end)).ok().map(|i| table[i].2);
+}
+
+fn compare_range(c: char, start: char, end: char) -> std::cmp::Ordering {
+    use std::cmp::Ordering::*;
+    if c < start {
+        return Greater;
+    } else if c > end {
+        return Less;
+    } else {
+        return Equal;
+    }
+}
@@ -0,0 +1,2477 @@
+// Generated by segmentation.js from the Unicode 14.0.0 data files, don't edit by hand
+// Characters that aren't in a table have the property value Other
+
+use crate::segmentation::{GraphemeBreak, WordBreak};
+
+pub(crate) const GRAPHEME_BREAK: &[(char, char, GraphemeBreak)] = &[
+    ('\u{0}', '\u{9}', GraphemeBreak::Control),
+    ('\u{A}', '\u{A}', GraphemeBreak::LF),
+    ('\u{B}', '\u{C}', GraphemeBreak::Control),
+    ('\u{D}', '\u{D}', GraphemeBreak::CR),
+    ('\u{E}', '\u{1F}', GraphemeBreak::Control),
+    ('\u{7F}', '\u{9F}', GraphemeBreak::Control),
+    ('\u{AD}', '\u{AD}', GraphemeBreak::Control),
+    ('\u{300}', '\u{36F}', GraphemeBreak::Extend),
+    ('\u{483}', '\u{489}', GraphemeBreak::Extend),
+    ('\u{591}', '\u{5BD}', GraphemeBreak::Extend),
+    ('\u{5BF}', '\u{5BF}', GraphemeBreak::Extend),
+    ('\u{5C1}', '\u{5C2}', GraphemeBreak::Extend),
+    ('\u{5C4}', '\u{5C5}', GraphemeBreak::Extend),
+    ('\u{5C7}', '\u{5C7}', GraphemeBreak::Extend),
+    ('\u{600}', '\u{605}', GraphemeBreak::Prepend),
+    ('\u{610}', '\u{61A}', GraphemeBreak::Extend),
+    ('\u{61C}', '\u{61C}', GraphemeBreak::Control),
+    ('\u{64B}', '\u{65F}', GraphemeBreak::Extend),
+    ('\u{670}', '\u{670}', GraphemeBreak::Extend),
+    ('\u{6D6}', '\u{6DC}', GraphemeBreak::Extend),
+    ('\u{6DD}', '\u{6DD}', GraphemeBreak::Prepend),
+    ('\u{6DF}', '\u{6E4}', GraphemeBreak::Extend),
+    ('\u{6E7}', '\u{6E8}', GraphemeBreak::Extend),
+    ('\u{6EA}', '\u{6ED}', GraphemeBreak::Extend),
+    ('\u{70F}', '\u{70F}', GraphemeBreak::Prepend),
+    ('\u{711}', '\u{711}', GraphemeBreak::Extend),
+    ('\u{730}', '\u{74A}', GraphemeBreak::Extend),
+    ('\u{7A6}', '\u{7B0}', GraphemeBreak::Extend),
+    ('\u{7EB}', '\u{7F3}', GraphemeBreak::Extend),
+    ('\u{7FD}', '\u{7FD}', GraphemeBreak::Extend),
+    ('\u{816}', '\u{819}', GraphemeBreak::Extend),
+    ('\u{81B}', '\u{823}', GraphemeBreak::Extend),
+    ('\u{825}', '\u{827}', GraphemeBreak::Extend),
+    ('\u{829}', '\u{82D}', GraphemeBreak::Extend),
+    ('\u{859}', '\u{85B}', GraphemeBreak::Extend),
+    ('\u{890}', '\u{891}', GraphemeBreak::Prepend),
+    ('\u{898}', '\u{89F}', GraphemeBreak::Extend),
+    ('\u{8CA}', '\u{8E1}', GraphemeBreak::Extend),
+    ('\u{8E2}', '\u{8E2}', GraphemeBreak::Prepend),
+    ('\u{8E3}', '\u{902}', GraphemeBreak::Extend),
+    ('\u{903}', '\u{903}', GraphemeBreak::SpacingMark),
+    ('\u{93A}', '\u{93A}', GraphemeBreak::Extend),
+    ('\u{93B}', '\u{93B}', GraphemeBreak::SpacingMark),
+    ('\u{93C}', '\u{93C}', GraphemeBreak::Extend),
+    ('\u{93E}', '\u{940}', GraphemeBreak::SpacingMark),
+    ('\u{941}', '\u{948}', GraphemeBreak::Extend),
+    ('\u{949}', '\u{94C}', GraphemeBreak::SpacingMark),
+    ('\u{94D}', '\u{94D}', GraphemeBreak::Extend),
+    ('\u{94E}', '\u{94F}', GraphemeBreak::SpacingMark),
+    ('\u{951}', '\u{957}', GraphemeBreak::Extend),
+    ('\u{962}', '\u{963}', GraphemeBreak::Extend),
+    ('\u{981}', '\u{981}', GraphemeBreak::Extend),
+    ('\u{982}', '\u{983}', GraphemeBreak::SpacingMark),
+    ('\u{9BC}', '\u{9BC}', GraphemeBreak::Extend),
+    ('\u{9BE}', '\u{9BE}', GraphemeBreak::Extend),
+    ('\u{9BF}', '\u{9C0}', GraphemeBreak::SpacingMark),
+    ('\u{9C1}', '\u{9C4}', GraphemeBreak::Extend),
+    ('\u{9C7}', '\u{9C8}', GraphemeBreak::SpacingMark),
+    ('\u{9CB}', '\u{9CC}', GraphemeBreak::SpacingMark),
+    ('\u{9CD}', '\u{9CD}', GraphemeBreak::Extend),
+    ('\u{9D7}', '\u{9D7}', GraphemeBreak::Extend),
+    ('\u{9E2}', '\u{9E3}', GraphemeBreak::Extend),
+    ('\u{9FE}', '\u{9FE}', GraphemeBreak::Extend),
+    ('\u{A01}', '\u{A02}', GraphemeBreak::Extend),
+    ('\u{A03}', '\u{A03}', GraphemeBreak::SpacingMark),
+    ('\u{A3C}', '\u{A3C}', GraphemeBreak::Extend),
+    ('\u{A3E}', '\u{A40}', GraphemeBreak::SpacingMark),
+    ('\u{A41}', '\u{A42}', GraphemeBreak::Extend),
+    ('\u{A47}', '\u{A48}', GraphemeBreak::Extend),
+    ('\u{A4B}', '\u{A4D}', GraphemeBreak::Extend),
+    ('\u{A51}', '\u{A51}', GraphemeBreak::Extend),
+    ('\u{A70}', '\u{A71}', GraphemeBreak::Extend),
+    ('\u{A75}', '\u{A75}', GraphemeBreak::Extend),
+    ('\u{A81}', '\u{A82}', GraphemeBreak::Extend),
+    ('\u{A83}', '\u{A83}', GraphemeBreak::SpacingMark),
+    ('\u{ABC}', '\u{ABC}', GraphemeBreak::Extend),
+    ('\u{ABE}', '\u{AC0}', GraphemeBreak::SpacingMark),
+    ('\u{AC1}', '\u{AC5}', GraphemeBreak::Extend),
+    ('\u{AC7}', '\u{AC8}', GraphemeBreak::Extend),
+    ('\u{AC9}', '\u{AC9}', GraphemeBreak::SpacingMark),
+    ('\u{ACB}', '\u{ACC}', GraphemeBreak::SpacingMark),
+    ('\u{ACD}', '\u{ACD}', GraphemeBreak::Extend),
+    ('\u{AE2}', '\u{AE3}', GraphemeBreak::Extend),
+    ('\u{AFA}', '\u{AFF}', GraphemeBreak::Extend),
+    ('\u{B01}', '\u{B01}', GraphemeBreak::Extend),
+    ('\u{B02}', '\u{B03}', GraphemeBreak::SpacingMark),
+    ('\u{B3C}', '\u{B3C}', GraphemeBreak::Extend),
+    ('\u{B3E}', '\u{B3F}', GraphemeBreak::Extend),
+    ('\u{B40}', '\u{B40}', GraphemeBreak::SpacingMark),
+    ('\u{B41}', '\u{B44}', GraphemeBreak::Extend),
+    ('\u{B47}', '\u{B48}', GraphemeBreak::SpacingMark),
+    ('\u{B4B}', '\u{B4C}', GraphemeBreak::SpacingMark),
+    ('\u{B4D}', '\u{B4D}', GraphemeBreak::Extend),
+    ('\u{B55}', '\u{B57}', GraphemeBreak::Extend),
+    ('\u{B62}', '\u{B63}', GraphemeBreak::Extend),
+    ('\u{B82}', '\u{B82}', GraphemeBreak::Extend),
+    ('\u{BBE}', '\u{BBE}', GraphemeBreak::Extend),
+    ('\u{BBF}', '\u{BBF}', GraphemeBreak::SpacingMark),
+    ('\u{BC0}', '\u{BC0}', GraphemeBreak::Extend),
+    ('\u{BC1}', '\u{BC2}', GraphemeBreak::SpacingMark),
+    ('\u{BC6}', '\u{BC8}', GraphemeBreak::SpacingMark),
+    ('\u{BCA}', '\u{BCC}', GraphemeBreak::SpacingMark),
+    ('\u{BCD}', '\u{BCD}', GraphemeBreak::Extend),
+    ('\u{BD7}', '\u{BD7}', GraphemeBreak::Extend),
+    ('\u{C00}', '\u{C00}', GraphemeBreak::Extend),
+    ('\u{C01}', '\u{C03}', GraphemeBreak::SpacingMark),
+    ('\u{C04}', '\u{C04}', GraphemeBreak::Extend),
+    ('\u{C3C}', '\u{C3C}', GraphemeBreak::Extend),
+    ('\u{C3E}', '\u{C40}', GraphemeBreak::Extend),
+    ('\u{C41}', '\u{C44}', GraphemeBreak::SpacingMark),
+    ('\u{C46}', '\u{C48}', GraphemeBreak::Extend),
+    ('\u{C4A}', '\u{C4D}', GraphemeBreak::Extend),
+    ('\u{C55}', '\u{C56}', GraphemeBreak::Extend),
+    ('\u{C62}', '\u{C63}', GraphemeBreak::Extend),
+    ('\u{C81}', '\u{C81}', GraphemeBreak::Extend),
+    ('\u{C82}', '\u{C83}', GraphemeBreak::SpacingMark),
+    ('\u{CBC}', '\u{CBC}', GraphemeBreak::Extend),
+    ('\u{CBE}', '\u{CBE}', GraphemeBreak::SpacingMark),
+    ('\u{CBF}', '\u{CBF}', GraphemeBreak::Extend),
+    ('\u{CC0}', '\u{CC1}', GraphemeBreak::SpacingMark),
+    ('\u{CC2}', '\u{CC2}', GraphemeBreak::Extend),
+    ('\u{CC3}', '\u{CC4}', GraphemeBreak::SpacingMark),
+    ('\u{CC6}', '\u{CC6}', GraphemeBreak::Extend),
+    ('\u{CC7}', '\u{CC8}', GraphemeBreak::SpacingMark),
+    ('\u{CCA}', '\u{CCB}', GraphemeBreak::SpacingMark),
+    ('\u{CCC}', '\u{CCD}', GraphemeBreak::Extend),
+    ('\u{CD5}', '\u{CD6}', GraphemeBreak::Extend),
+    ('\u{CE2}', '\u{CE3}', GraphemeBreak::Extend),
+    ('\u{D00}', '\u{D01}', GraphemeBreak::Extend),
+    ('\u{D02}', '\u{D03}', GraphemeBreak::SpacingMark),
+    ('\u{D3B}', '\u{D3C}', GraphemeBreak::Extend),
+    ('\u{D3E}', '\u{D3E}', GraphemeBreak::Extend),
+    ('\u{D3F}', '\u{D40}', GraphemeBreak::SpacingMark),
+    ('\u{D41}', '\u{D44}', GraphemeBreak::Extend),
+    ('\u{D46}', '\u{D48}', GraphemeBreak::SpacingMark),
+    ('\u{D4A}', '\u{D4C}', GraphemeBreak::SpacingMark),
+    ('\u{D4D}', '\u{D4D}', GraphemeBreak::Extend),
+    ('\u{D4E}', '\u{D4E}', GraphemeBreak::Prepend),
+    ('\u{D57}', '\u{D57}', GraphemeBreak::Extend),
+    ('\u{D62}', '\u{D63}', GraphemeBreak::Extend),
+    ('\u{D81}', '\u{D81}', GraphemeBreak::Extend),
+    ('\u{D82}', '\u{D83}', GraphemeBreak::SpacingMark),
+    ('\u{DCA}', '\u{DCA}', GraphemeBreak::Extend),
+    ('\u{DCF}', '\u{DCF}', GraphemeBreak::Extend),
+    ('\u{DD0}', '\u{DD1}', GraphemeBreak::SpacingMark),
+    ('\u{DD2}', '\u{DD4}', GraphemeBreak::Extend),
+    ('\u{DD6}', '\u{DD6}', GraphemeBreak::Extend),
+    ('\u{DD8}', '\u{DDE}', GraphemeBreak::SpacingMark),
+    ('\u{DDF}', '\u{DDF}', GraphemeBreak::Extend),
+    ('\u{DF2}', '\u{DF3}', GraphemeBreak::SpacingMark),
+    ('\u{E31}', '\u{E31}', GraphemeBreak::Extend),
+    ('\u{E33}', '\u{E33}', GraphemeBreak::SpacingMark),
+    ('\u{E34}', '\u{E3A}', GraphemeBreak::Extend),
+    ('\u{E47}', '\u{E4E}', GraphemeBreak::Extend),
+    ('\u{EB1}', '\u{EB1}', GraphemeBreak::Extend),
+    ('\u{EB3}', '\u{EB3}', GraphemeBreak::SpacingMark),
+    ('\u{EB4}', '\u{EBC}', GraphemeBreak::Extend),
+    ('\u{EC8}', '\u{ECD}', GraphemeBreak::Extend),
+    ('\u{F18}', '\u{F19}', GraphemeBreak::Extend),
+    ('\u{F35}', '\u{F35}', GraphemeBreak::Extend),
+    ('\u{F37}', '\u{F37}', GraphemeBreak::Extend),
+    ('\u{F39}', '\u{F39}', GraphemeBreak::Extend),
+    ('\u{F3E}', '\u{F3F}', GraphemeBreak::SpacingMark),
+    ('\u{F71}', '\u{F7E}', GraphemeBreak::Extend),
+    ('\u{F7F}', '\u{F7F}', GraphemeBreak::SpacingMark),
+    ('\u{F80}', '\u{F84}', GraphemeBreak::Extend),
+    ('\u{F86}', '\u{F87}', GraphemeBreak::Extend),
+    ('\u{F8D}', '\u{F97}', GraphemeBreak::Extend),
+    ('\u{F99}', '\u{FBC}', GraphemeBreak::Extend),
+    ('\u{FC6}', '\u{FC6}', GraphemeBreak::Extend),
+    ('\u{102D}', '\u{1030}', GraphemeBreak::Extend),
+    ('\u{1031}', '\u{1031}', GraphemeBreak::SpacingMark),
+    ('\u{1032}', '\u{1037}', GraphemeBreak::Extend),
+    ('\u{1039}', '\u{103A}', GraphemeBreak::Extend),
+    ('\u{103B}', '\u{103C}', GraphemeBreak::SpacingMark),
+    ('\u{103D}', '\u{103E}', GraphemeBreak::Extend),
+    ('\u{1056}', '\u{1057}', GraphemeBreak::SpacingMark),
+    ('\u{1058}', '\u{1059}', GraphemeBreak::Extend),
+    ('\u{105E}', '\u{1060}', GraphemeBreak::Extend),
+    ('\u{1071}', '\u{1074}', GraphemeBreak::Extend),
+    ('\u{1082}', '\u{1082}', GraphemeBreak::Extend),
+    ('\u{1084}', '\u{1084}', GraphemeBreak::SpacingMark),
+    ('\u{1085}', '\u{1086}', GraphemeBreak::Extend),
+    ('\u{108D}', '\u{108D}', GraphemeBreak::Extend),
+    ('\u{109D}', '\u{109D}', GraphemeBreak::Extend),
+    ('\u{1100}', '\u{115F}', GraphemeBreak::L),
+    ('\u{1160}', '\u{11A7}', GraphemeBreak::V),
+    ('\u{11A8}', '\u{11FF}', GraphemeBreak::T),
+    ('\u{135D}', '\u{135F}', GraphemeBreak::Extend),
+    ('\u{1712}', '\u{1714}', GraphemeBreak::Extend),
+    ('\u{1715}', '\u{1715}', GraphemeBreak::SpacingMark),
+    ('\u{1732}', '\u{1733}', GraphemeBreak::Extend),
+    ('\u{1734}', '\u{1734}', GraphemeBreak::SpacingMark),
+    ('\u{1752}', '\u{1753}', GraphemeBreak::Extend),
+    ('\u{1772}', '\u{1773}', GraphemeBreak::Extend),
+    ('\u{17B4}', '\u{17B5}', GraphemeBreak::Extend),
+    ('\u{17B6}', '\u{17B6}', GraphemeBreak::SpacingMark),
+    ('\u{17B7}', '\u{17BD}', GraphemeBreak::Extend),
+    ('\u{17BE}', '\u{17C5}', GraphemeBreak::SpacingMark),
+    ('\u{17C6}', '\u{17C6}', GraphemeBreak::Extend),
+    ('\u{17C7}', '\u{17C8}', GraphemeBreak::SpacingMark),
+    ('\u{17C9}', '\u{17D3}', GraphemeBreak::Extend),
+    ('\u{17DD}', '\u{17DD}', GraphemeBreak::Extend),
+    ('\u{180B}', '\u{180D}', GraphemeBreak::Extend),
+    ('\u{180E}', '\u{180E}', GraphemeBreak::Control),
+    ('\u{180F}', '\u{180F}', GraphemeBreak::Extend),
+    ('\u{1885}', '\u{1886}', GraphemeBreak::Extend),
+    ('\u{18A9}', '\u{18A9}', GraphemeBreak::Extend),
+    ('\u{1920}', '\u{1922}', GraphemeBreak::Extend),
+    ('\u{1923}', '\u{1926}', GraphemeBreak::SpacingMark),
+    ('\u{1927}', '\u{1928}', GraphemeBreak::Extend),
+    ('\u{1929}', '\u{192B}', GraphemeBreak::SpacingMark),
+    ('\u{1930}', '\u{1931}', GraphemeBreak::SpacingMark),
+    ('\u{1932}', '\u{1932}', GraphemeBreak::Extend),
+    ('\u{1933}', '\u{1938}', GraphemeBreak::SpacingMark),
+    ('\u{1939}', '\u{193B}', GraphemeBreak::Extend),
+    ('\u{1A17}', '\u{1A18}', GraphemeBreak::Extend),
+    ('\u{1A19}', '\u{1A1A}', GraphemeBreak::SpacingMark),
+    ('\u{1A1B}', '\u{1A1B}', GraphemeBreak::Extend),
+    ('\u{1A55}', '\u{1A55}', GraphemeBreak::SpacingMark),
+    ('\u{1A56}', '\u{1A56}', GraphemeBreak::Extend),
+    ('\u{1A57}', '\u{1A57}', GraphemeBreak::SpacingMark),
+    ('\u{1A58}', '\u{1A5E}', GraphemeBreak::Extend),
+    ('\u{1A60}', '\u{1A60}', GraphemeBreak::Extend),
+    ('\u{1A62}', '\u{1A62}', GraphemeBreak::Extend),
+    ('\u{1A65}', '\u{1A6C}', GraphemeBreak::Extend),
+    ('\u{1A6D}', '\u{1A72}', GraphemeBreak::SpacingMark),
+    ('\u{1A73}', '\u{1A7C}', GraphemeBreak::Extend),
+    ('\u{1A7F}', '\u{1A7F}', GraphemeBreak::Extend),
+    ('\u{1AB0}', '\u{1ACE}', GraphemeBreak::Extend),
+    ('\u{1B00}', '\u{1B03}', GraphemeBreak::Extend),
+    ('\u{1B04}', '\u{1B04}', GraphemeBreak::SpacingMark),
+    ('\u{1B34}', '\u{1B3A}', GraphemeBreak::Extend),
+    ('\u{1B3B}', '\u{1B3B}', GraphemeBreak::SpacingMark),
+    ('\u{1B3C}', '\u{1B3C}', GraphemeBreak::Extend),
+    ('\u{1B3D}', '\u{1B41}', GraphemeBreak::SpacingMark),
+    ('\u{1B42}', '\u{1B42}', GraphemeBreak::Extend),
+    ('\u{1B43}', '\u{1B44}', GraphemeBreak::SpacingMark),
+    ('\u{1B6B}', '\u{1B73}', GraphemeBreak::Extend),
+    ('\u{1B80}', '\u{1B81}', GraphemeBreak::Extend),
+    ('\u{1B82}', '\u{1B82}', GraphemeBreak::SpacingMark),
+    ('\u{1BA1}', '\u{1BA1}', GraphemeBreak::SpacingMark),
+    ('\u{1BA2}', '\u{1BA5}', GraphemeBreak::Extend),
+    ('\u{1BA6}', '\u{1BA7}', GraphemeBreak::SpacingMark),
+    ('\u{1BA8}', '\u{1BA9}', GraphemeBreak::Extend),
+    ('\u{1BAA}', '\u{1BAA}', GraphemeBreak::SpacingMark),
+    ('\u{1BAB}', '\u{1BAD}', GraphemeBreak::Extend),
+    ('\u{1BE6}', '\u{1BE6}', GraphemeBreak::Extend),
+    ('\u{1BE7}', '\u{1BE7}', GraphemeBreak::SpacingMark),
+    ('\u{1BE8}', '\u{1BE9}', GraphemeBreak::Extend),
+    ('\u{1BEA}', '\u{1BEC}', GraphemeBreak::SpacingMark),
+    ('\u{1BED}', '\u{1BED}', GraphemeBreak::Extend),
+    ('\u{1BEE}', '\u{1BEE}', GraphemeBreak::SpacingMark),
+    ('\u{1BEF}', '\u{1BF1}', GraphemeBreak::Extend),
+    ('\u{1BF2}', '\u{1BF3}', GraphemeBreak::SpacingMark),
+    ('\u{1C24}', '\u{1C2B}', GraphemeBreak::SpacingMark),
+    ('\u{1C2C}', '\u{1C33}', GraphemeBreak::Extend),
+    ('\u{1C34}', '\u{1C35}', GraphemeBreak::SpacingMark),
+    ('\u{1C36}', '\u{1C37}', GraphemeBreak::Extend),
+    ('\u{1CD0}', '\u{1CD2}', GraphemeBreak::Extend),
+    ('\u{1CD4}', '\u{1CE0}', GraphemeBreak::Extend),
+    ('\u{1CE1}', '\u{1CE1}', GraphemeBreak::SpacingMark),
+    ('\u{1CE2}', '\u{1CE8}', GraphemeBreak::Extend),
+    ('\u{1CED}', '\u{1CED}', GraphemeBreak::Extend),
+    ('\u{1CF4}', '\u{1CF4}', GraphemeBreak::Extend),
+    ('\u{1CF7}', '\u{1CF7}', GraphemeBreak::SpacingMark),
+    ('\u{1CF8}', '\u{1CF9}', GraphemeBreak::Extend),
+    ('\u{1DC0}', '\u{1DFF}', GraphemeBreak::Extend),
+    ('\u{200B}', '\u{200B}', GraphemeBreak::Control),
+    ('\u{200C}', '\u{200C}', GraphemeBreak::Extend),
+    ('\u{200D}', '\u{200D}', GraphemeBreak::Zwj),
+    ('\u{200E}', '\u{200F}', GraphemeBreak::Control),
+    ('\u{2028}', '\u{202E}', GraphemeBreak::Control),
+    ('\u{2060}', '\u{206F}', GraphemeBreak::Control),
+    ('\u{20D0}', '\u{20F0}', GraphemeBreak::Extend),
+    ('\u{2CEF}', '\u{2CF1}', GraphemeBreak::Extend),
+    ('\u{2D7F}', '\u{2D7F}', GraphemeBreak::Extend),
+    ('\u{2DE0}', '\u{2DFF}', GraphemeBreak::Extend),
+    ('\u{302A}', '\u{302F}', GraphemeBreak::Extend),
+    ('\u{3099}', '\u{309A}', GraphemeBreak::Extend),
+    ('\u{A66F}', '\u{A672}', GraphemeBreak::Extend),
+    ('\u{A674}', '\u{A67D}', GraphemeBreak::Extend),
+    ('\u{A69E}', '\u{A69F}', GraphemeBreak::Extend),
+    ('\u{A6F0}', '\u{A6F1}', GraphemeBreak::Extend),
+    ('\u{A802}', '\u{A802}', GraphemeBreak::Extend),
+    ('\u{A806}', '\u{A806}', GraphemeBreak::Extend),
+    ('\u{A80B}', '\u{A80B}', GraphemeBreak::Extend),
+    ('\u{A823}', '\u{A824}', GraphemeBreak::SpacingMark),
+    ('\u{A825}', '\u{A826}', GraphemeBreak::Extend),
+    ('\u{A827}', '\u{A827}', GraphemeBreak::SpacingMark),
+    ('\u{A82C}', '\u{A82C}', GraphemeBreak::Extend),
+    ('\u{A880}', '\u{A881}', GraphemeBreak::SpacingMark),
+    ('\u{A8B4}', '\u{A8C3}', GraphemeBreak::SpacingMark),
+    ('\u{A8C4}', '\u{A8C5}', GraphemeBreak::Extend),
+    ('\u{A8E0}', '\u{A8F1}', GraphemeBreak::Extend),
+    ('\u{A8FF}', '\u{A8FF}', GraphemeBreak::Extend),
+    ('\u{A926}', '\u{A92D}', GraphemeBreak::Extend),
+    ('\u{A947}', '\u{A951}', GraphemeBreak::Extend),
+    ('\u{A952}', '\u{A953}', GraphemeBreak::SpacingMark),
+    ('\u{A960}', '\u{A97C}', GraphemeBreak::L),
+    ('\u{A980}', '\u{A982}', GraphemeBreak::Extend),
+    ('\u{A983}', '\u{A983}', GraphemeBreak::SpacingMark),
+    ('\u{A9B3}', '\u{A9B3}', GraphemeBreak::Extend),
+    ('\u{A9B4}', '\u{A9B5}', GraphemeBreak::SpacingMark),
+    ('\u{A9B6}', '\u{A9B9}', GraphemeBreak::Extend),
+    ('\u{A9BA}', '\u{A9BB}', GraphemeBreak::SpacingMark),
+    ('\u{A9BC}', '\u{A9BD}', GraphemeBreak::Extend),
+    ('\u{A9BE}', '\u{A9C0}', GraphemeBreak::SpacingMark),
+    ('\u{A9E5}', '\u{A9E5}', GraphemeBreak::Extend),
+    ('\u{AA29}', '\u{AA2E}', GraphemeBreak::Extend),
+    ('\u{AA2F}', '\u{AA30}', GraphemeBreak::SpacingMark),
+    ('\u{AA31}', '\u{AA32}', GraphemeBreak::Extend),
+    ('\u{AA33}', '\u{AA34}', GraphemeBreak::SpacingMark),
+    ('\u{AA35}', '\u{AA36}', GraphemeBreak::Extend),
+    ('\u{AA43}', '\u{AA43}', GraphemeBreak::Extend),
+    ('\u{AA4C}', '\u{AA4C}', GraphemeBreak::Extend),
+    ('\u{AA4D}', '\u{AA4D}', GraphemeBreak::SpacingMark),
+    ('\u{AA7C}', '\u{AA7C}', GraphemeBreak::Extend),
+    ('\u{AAB0}', '\u{AAB0}', GraphemeBreak::Extend),
+    ('\u{AAB2}', '\u{AAB4}', GraphemeBreak::Extend),
+    ('\u{AAB7}', '\u{AAB8}', GraphemeBreak::Extend),
+    ('\u{AABE}', '\u{AABF}', GraphemeBreak::Extend),
+    ('\u{AAC1}', '\u{AAC1}', GraphemeBreak::Extend),
+    ('\u{AAEB}', '\u{AAEB}', GraphemeBreak::SpacingMark),
+    ('\u{AAEC}', '\u{AAED}', GraphemeBreak::Extend),
+    ('\u{AAEE}', '\u{AAEF}', GraphemeBreak::SpacingMark),
+    ('\u{AAF5}', '\u{AAF5}', GraphemeBreak::SpacingMark),
+    ('\u{AAF6}', '\u{AAF6}', GraphemeBreak::Extend),
+    ('\u{ABE3}', '\u{ABE4}', GraphemeBreak::SpacingMark),
+    ('\u{ABE5}', '\u{ABE5}', GraphemeBreak::Extend),
+    ('\u{ABE6}', '\u{ABE7}', GraphemeBreak::SpacingMark),
+    ('\u{ABE8}', '\u{ABE8}', GraphemeBreak::Extend),
+    ('\u{ABE9}', '\u{ABEA}', GraphemeBreak::SpacingMark),
+    ('\u{ABEC}', '\u{ABEC}', GraphemeBreak::SpacingMark),
+    ('\u{ABED}', '\u{ABED}', GraphemeBreak::Extend),
+    ('\u{AC00}', '\u{AC00}', GraphemeBreak::LV),
+    ('\u{AC01}', '\u{AC1B}', GraphemeBreak::Lvt),
+    ('\u{AC1C}', '\u{AC1C}', GraphemeBreak::LV),
+    ('\u{AC1D}', '\u{AC37}', GraphemeBreak::Lvt),
+    ('\u{AC38}', '\u{AC38}', GraphemeBreak::LV),
+    ('\u{AC39}', '\u{AC53}', GraphemeBreak::Lvt),
+    ('\u{AC54}', '\u{AC54}', GraphemeBreak::LV),
+    ('\u{AC55}', '\u{AC6F}', GraphemeBreak::Lvt),
+    ('\u{AC70}', '\u{AC70}', GraphemeBreak::LV),
+    ('\u{AC71}', '\u{AC8B}', GraphemeBreak::Lvt),
+    ('\u{AC8C}', '\u{AC8C}', GraphemeBreak::LV),
+    ('\u{AC8D}', '\u{ACA7}', GraphemeBreak::Lvt),
+    ('\u{ACA8}', '\u{ACA8}', GraphemeBreak::LV),
+    ('\u{ACA9}', '\u{ACC3}', GraphemeBreak::Lvt),
+    ('\u{ACC4}', '\u{ACC4}', GraphemeBreak::LV),
+    ('\u{ACC5}', '\u{ACDF}', GraphemeBreak::Lvt),
+    ('\u{ACE0}', '\u{ACE0}', GraphemeBreak::LV),
+    ('\u{ACE1}', '\u{ACFB}', GraphemeBreak::Lvt),
+    ('\u{ACFC}', '\u{ACFC}', GraphemeBreak::LV),
+    ('\u{ACFD}', '\u{AD17}', GraphemeBreak::Lvt),
+    ('\u{AD18}', '\u{AD18}', GraphemeBreak::LV),
+    ('\u{AD19}', '\u{AD33}', GraphemeBreak::Lvt),
+    ('\u{AD34}', '\u{AD34}', GraphemeBreak::LV),
+    ('\u{AD35}', '\u{AD4F}', GraphemeBreak::Lvt),
+    ('\u{AD50}', '\u{AD50}', GraphemeBreak::LV),
+    ('\u{AD51}', '\u{AD6B}', GraphemeBreak::Lvt),
+    ('\u{AD6C}', '\u{AD6C}', GraphemeBreak::LV),
+    ('\u{AD6D}', '\u{AD87}', GraphemeBreak::Lvt),
+    ('\u{AD88}', '\u{AD88}', GraphemeBreak::LV),
+    ('\u{AD89}', '\u{ADA3}', GraphemeBreak::Lvt),
+    ('\u{ADA4}', '\u{ADA4}', GraphemeBreak::LV),
+    ('\u{ADA5}', '\u{ADBF}', GraphemeBreak::Lvt),
+    ('\u{ADC0}', '\u{ADC0}', GraphemeBreak::LV),
+    ('\u{ADC1}', '\u{ADDB}', GraphemeBreak::Lvt),
+    ('\u{ADDC}', '\u{ADDC}', GraphemeBreak::LV),
+    ('\u{ADDD}', '\u{ADF7}', GraphemeBreak::Lvt),
+    ('\u{ADF8}', '\u{ADF8}', GraphemeBreak::LV),
+    ('\u{ADF9}', '\u{AE13}', GraphemeBreak::Lvt),
+    ('\u{AE14}', '\u{AE14}', GraphemeBreak::LV),
+    ('\u{AE15}', '\u{AE2F}', GraphemeBreak::Lvt),
+    ('\u{AE30}', '\u{AE30}', GraphemeBreak::LV),
+    ('\u{AE31}', '\u{AE4B}', GraphemeBreak::Lvt),
+    ('\u{AE4C}', '\u{AE4C}', GraphemeBreak::LV),
+    ('\u{AE4D}', '\u{AE67}', GraphemeBreak::Lvt),
+    ('\u{AE68}', '\u{AE68}', GraphemeBreak::LV),
+    ('\u{AE69}', '\u{AE83}', GraphemeBreak::Lvt),
+    ('\u{AE84}', '\u{AE84}', GraphemeBreak::LV),
+    ('\u{AE85}', '\u{AE9F}', GraphemeBreak::Lvt),
+    ('\u{AEA0}', '\u{AEA0}', GraphemeBreak::LV),
+    ('\u{AEA1}', '\u{AEBB}', GraphemeBreak::Lvt),
+    ('\u{AEBC}', '\u{AEBC}', GraphemeBreak::LV),
+    ('\u{AEBD}', '\u{AED7}', GraphemeBreak::Lvt),
+    ('\u{AED8}', '\u{AED8}', GraphemeBreak::LV),
+    ('\u{AED9}', '\u{AEF3}', GraphemeBreak::Lvt),
+    ('\u{AEF4}', '\u{AEF4}', GraphemeBreak::LV),
+    ('\u{AEF5}', '\u{AF0F}', GraphemeBreak::Lvt),
+    ('\u{AF10}', '\u{AF10}', GraphemeBreak::LV),
+    ('\u{AF11}', '\u{AF2B}', GraphemeBreak::Lvt),
+    ('\u{AF2C}', '\u{AF2C}', GraphemeBreak::LV),
+    ('\u{AF2D}', '\u{AF47}', GraphemeBreak::Lvt),
+    ('\u{AF48}', '\u{AF48}', GraphemeBreak::LV),
+    ('\u{AF49}', '\u{AF63}', GraphemeBreak::Lvt),
+    ('\u{AF64}', '\u{AF64}', GraphemeBreak::LV),
+    ('\u{AF65}', '\u{AF7F}', GraphemeBreak::Lvt),
+    ('\u{AF80}', '\u{AF80}', GraphemeBreak::LV),
+    ('\u{AF81}', '\u{AF9B}', GraphemeBreak::Lvt),
+    ('\u{AF9C}', '\u{AF9C}', GraphemeBreak::LV),
+    ('\u{AF9D}', '\u{AFB7}', GraphemeBreak::Lvt),
+    ('\u{AFB8}', '\u{AFB8}', GraphemeBreak::LV),
+    ('\u{AFB9}', '\u{AFD3}', GraphemeBreak::Lvt),
+    ('\u{AFD4}', '\u{AFD4}', GraphemeBreak::LV),
+    ('\u{AFD5}', '\u{AFEF}', GraphemeBreak::Lvt),
+    ('\u{AFF0}', '\u{AFF0}', GraphemeBreak::LV),
+    ('\u{AFF1}', '\u{B00B}', GraphemeBreak::Lvt),
+    ('\u{B00C}', '\u{B00C}', GraphemeBreak::LV),
+    ('\u{B00D}', '\u{B027}', GraphemeBreak::Lvt),
+    ('\u{B028}', '\u{B028}', GraphemeBreak::LV),
+    ('\u{B029}', '\u{B043}', GraphemeBreak::Lvt),
+    ('\u{B044}', '\u{B044}', GraphemeBreak::LV),
+    ('\u{B045}', '\u{B05F}', GraphemeBreak::Lvt),
+    ('\u{B060}', '\u{B060}', GraphemeBreak::LV),
+    ('\u{B061}', '\u{B07B}', GraphemeBreak::Lvt),
+    ('\u{B07C}', '\u{B07C}', GraphemeBreak::LV),
+    ('\u{B07D}', '\u{B097}', GraphemeBreak::Lvt),
+    ('\u{B098}', '\u{B098}', GraphemeBreak::LV),
+    ('\u{B099}', '\u{B0B3}', GraphemeBreak::Lvt),
+    ('\u{B0B4}', '\u{B0B4}', GraphemeBreak::LV),
+    ('\u{B0B5}', '\u{B0CF}', GraphemeBreak::Lvt),
+    ('\u{B0D0}', '\u{B0D0}', GraphemeBreak::LV),
+    ('\u{B0D1}', '\u{B0EB}', GraphemeBreak::Lvt),
+    ('\u{B0EC}', '\u{B0EC}', GraphemeBreak::LV),
+    ('\u{B0ED}', '\u{B107}', GraphemeBreak::Lvt),
+    ('\u{B108}', '\u{B108}', GraphemeBreak::LV),
+    ('\u{B109}', '\u{B123}', GraphemeBreak::Lvt),
+    ('\u{B124}', '\u{B124}', GraphemeBreak::LV),
+    ('\u{B125}', '\u{B13F}', GraphemeBreak::Lvt),
+    ('\u{B140}', '\u{B140}', GraphemeBreak::LV),
+    ('\u{B141}', '\u{B15B}', GraphemeBreak::Lvt),
+    ('\u{B15C}', '\u{B15C}', GraphemeBreak::LV),
+    ('\u{B15D}', '\u{B177}', GraphemeBreak::Lvt),
+    ('\u{B178}', '\u{B178}', GraphemeBreak::LV),
+    ('\u{B179}', '\u{B193}', GraphemeBreak::Lvt),
+    ('\u{B194}', '\u{B194}', GraphemeBreak::LV),
+    ('\u{B195}', '\u{B1AF}', GraphemeBreak::Lvt),
+    ('\u{B1B0}', '\u{B1B0}', GraphemeBreak::LV),
+    ('\u{B1B1}', '\u{B1CB}', GraphemeBreak::Lvt),
+    ('\u{B1CC}', '\u{B1CC}', GraphemeBreak::LV),
+    ('\u{B1CD}', '\u{B1E7}', GraphemeBreak::Lvt),
+    ('\u{B1E8}', '\u{B1E8}', GraphemeBreak::LV),
+    ('\u{B1E9}', '\u{B203}', GraphemeBreak::Lvt),
+    ('\u{B204}', '\u{B204}', GraphemeBreak::LV),
+    ('\u{B205}', '\u{B21F}', GraphemeBreak::Lvt),
+    ('\u{B220}', '\u{B220}', GraphemeBreak::LV),
+    ('\u{B221}', '\u{B23B}', GraphemeBreak::Lvt),
+    ('\u{B23C}', '\u{B23C}', GraphemeBreak::LV),
+    ('\u{B23D}', '\u{B257}', GraphemeBreak::Lvt),
+    ('\u{B258}', '\u{B258}', GraphemeBreak::LV),
+    ('\u{B259}', '\u{B273}', GraphemeBreak::Lvt),
+    ('\u{B274}', '\u{B274}', GraphemeBreak::LV),
+    ('\u{B275}', '\u{B28F}', GraphemeBreak::Lvt),
+    ('\u{B290}', '\u{B290}', GraphemeBreak::LV),
+    ('\u{B291}', '\u{B2AB}', GraphemeBreak::Lvt),
+    ('\u{B2AC}', '\u{B2AC}', GraphemeBreak::LV),
+    ('\u{B2AD}', '\u{B2C7}', GraphemeBreak::Lvt),
+    ('\u{B2C8}', '\u{B2C8}', GraphemeBreak::LV),
+    ('\u{B2C9}', '\u{B2E3}', GraphemeBreak::Lvt),
+    ('\u{B2E4}', '\u{B2E4}', GraphemeBreak::LV),
+    ('\u{B2E5}', '\u{B2FF}', GraphemeBreak::Lvt),
+    ('\u{B300}', '\u{B300}', GraphemeBreak::LV),
+    ('\u{B301}', '\u{B31B}', GraphemeBreak::Lvt),
+    ('\u{B31C}', '\u{B31C}', GraphemeBreak::LV),
+    ('\u{B31D}', '\u{B337}', GraphemeBreak::Lvt),
+    ('\u{B338}', '\u{B338}', GraphemeBreak::LV),
+    ('\u{B339}', '\u{B353}', GraphemeBreak::Lvt),
+    ('\u{B354}', '\u{B354}', GraphemeBreak::LV),
+    ('\u{B355}', '\u{B36F}', GraphemeBreak::Lvt),
+    ('\u{B370}', '\u{B370}', GraphemeBreak::LV),
+    ('\u{B371}', '\u{B38B}', GraphemeBreak::Lvt),
+    ('\u{B38C}', '\u{B38C}', GraphemeBreak::LV),
+    ('\u{B38D}', '\u{B3A7}', GraphemeBreak::Lvt),
+    ('\u{B3A8}', '\u{B3A8}', GraphemeBreak::LV),
+    ('\u{B3A9}', '\u{B3C3}', GraphemeBreak::Lvt),
+    ('\u{B3C4}', '\u{B3C4}', GraphemeBreak::LV),
+    ('\u{B3C5}', '\u{B3DF}', GraphemeBreak::Lvt),
+    ('\u{B3E0}', '\u{B3E0}', GraphemeBreak::LV),
+    ('\u{B3E1}', '\u{B3FB}', GraphemeBreak::Lvt),
+    ('\u{B3FC}', '\u{B3FC}', GraphemeBreak::LV),
+    ('\u{B3FD}', '\u{B417}', GraphemeBreak::Lvt),
+    ('\u{B418}', '\u{B418}', GraphemeBreak::LV),
+    ('\u{B419}', '\u{B433}', GraphemeBreak::Lvt),
+    ('\u{B434}', '\u{B434}', GraphemeBreak::LV),
+    ('\u{B435}', '\u{B44F}', GraphemeBreak::Lvt),
+    ('\u{B450}', '\u{B450}', GraphemeBreak::LV),
+    ('\u{B451}', '\u{B46B}', GraphemeBreak::Lvt),
+    ('\u{B46C}', '\u{B46C}', GraphemeBreak::LV),
+    ('\u{B46D}', '\u{B487}', GraphemeBreak::Lvt),
+    ('\u{B488}', '\u{B488}', GraphemeBreak::LV),
+    ('\u{B489}', '\u{B4A3}', GraphemeBreak::Lvt),
+    ('\u{B4A4}', '\u{B4A4}', GraphemeBreak::LV),
+    ('\u{B4A5}', '\u{B4BF}', GraphemeBreak::Lvt),
+    ('\u{B4C0}', '\u{B4C0}', GraphemeBreak::LV),
+    ('\u{B4C1}', '\u{B4DB}', GraphemeBreak::Lvt),
+    ('\u{B4DC}', '\u{B4DC}', GraphemeBreak::LV),
+    ('\u{B4DD}', '\u{B4F7}', GraphemeBreak::Lvt),
+    ('\u{B4F8}', '\u{B4F8}', GraphemeBreak::LV),
+    ('\u{B4F9}', '\u{B513}', GraphemeBreak::Lvt),
+    ('\u{B514}', '\u{B514}', GraphemeBreak::LV),
+    ('\u{B515}', '\u{B52F}', GraphemeBreak::Lvt),
+    ('\u{B530}', '\u{B530}', GraphemeBreak::LV),
+    ('\u{B531}', '\u{B54B}', GraphemeBreak::Lvt),
+    ('\u{B54C}', '\u{B54C}', GraphemeBreak::LV),
+    ('\u{B54D}', '\u{B567}', GraphemeBreak::Lvt),
+    ('\u{B568}', '\u{B568}', GraphemeBreak::LV),
+    ('\u{B569}', '\u{B583}', GraphemeBreak::Lvt),
+    ('\u{B584}', '\u{B584}', GraphemeBreak::LV),
+    ('\u{B585}', '\u{B59F}', GraphemeBreak::Lvt),
+    ('\u{B5A0}', '\u{B5A0}', GraphemeBreak::LV),
+    ('\u{B5A1}', '\u{B5BB}', GraphemeBreak::Lvt),
+    ('\u{B5BC}', '\u{B5BC}', GraphemeBreak::LV),
+    ('\u{B5BD}', '\u{B5D7}', GraphemeBreak::Lvt),
+    ('\u{B5D8}', '\u{B5D8}', GraphemeBreak::LV),
+    ('\u{B5D9}', '\u{B5F3}', GraphemeBreak::Lvt),
+    ('\u{B5F4}', '\u{B5F4}', GraphemeBreak::LV),
+    ('\u{B5F5}', '\u{B60F}', GraphemeBreak::Lvt),
+    ('\u{B610}', '\u{B610}', GraphemeBreak::LV),
+    ('\u{B611}', '\u{B62B}', GraphemeBreak::Lvt),
+    ('\u{B62C}', '\u{B62C}', GraphemeBreak::LV),
+    ('\u{B62D}', '\u{B647}', GraphemeBreak::Lvt),
+    ('\u{B648}', '\u{B648}', GraphemeBreak::LV),
+    ('\u{B649}', '\u{B663}', GraphemeBreak::Lvt),
+    ('\u{B664}', '\u{B664}', GraphemeBreak::LV),
+    ('\u{B665}', '\u{B67F}', GraphemeBreak::Lvt),
+    ('\u{B680}', '\u{B680}', GraphemeBreak::LV),
+    ('\u{B681}', '\u{B69B}', GraphemeBreak::Lvt),
+    ('\u{B69C}', '\u{B69C}', GraphemeBreak::LV),
+    ('\u{B69D}', '\u{B6B7}', GraphemeBreak::Lvt),
+    ('\u{B6B8}', '\u{B6B8}', GraphemeBreak::LV),
+    ('\u{B6B9}', '\u{B6D3}', GraphemeBreak::Lvt),
+    ('\u{B6D4}', '\u{B6D4}', GraphemeBreak::LV),
+    ('\u{B6D5}', '\u{B6EF}', GraphemeBreak::Lvt),
+    ('\u{B6F0}', '\u{B6F0}', GraphemeBreak::LV),
+    ('\u{B6F1}', '\u{B70B}', GraphemeBreak::Lvt),
+    ('\u{B70C}', '\u{B70C}', GraphemeBreak::LV),
+    ('\u{B70D}', '\u{B727}', GraphemeBreak::Lvt),
+    ('\u{B728}', '\u{B728}', GraphemeBreak::LV),
+    ('\u{B729}', '\u{B743}', GraphemeBreak::Lvt),
+    ('\u{B744}', '\u{B744}', GraphemeBreak::LV),
+    ('\u{B745}', '\u{B75F}', GraphemeBreak::Lvt),
+    ('\u{B760}', '\u{B760}', GraphemeBreak::LV),
+    ('\u{B761}', '\u{B77B}', GraphemeBreak::Lvt),
+    ('\u{B77C}', '\u{B77C}', GraphemeBreak::LV),
+    ('\u{B77D}', '\u{B797}', GraphemeBreak::Lvt),
+    ('\u{B798}', '\u{B798}', GraphemeBreak::LV),
+    ('\u{B799}', '\u{B7B3}', GraphemeBreak::Lvt),
+    ('\u{B7B4}', '\u{B7B4}', GraphemeBreak::LV),
+    ('\u{B7B5}', '\u{B7CF}', GraphemeBreak::Lvt),
+    ('\u{B7D0}', '\u{B7D0}', GraphemeBreak::LV),
+    ('\u{B7D1}', '\u{B7EB}', GraphemeBreak::Lvt),
+    ('\u{B7EC}', '\u{B7EC}', GraphemeBreak::LV),
+    ('\u{B7ED}', '\u{B807}', GraphemeBreak::Lvt),
+    ('\u{B808}', '\u{B808}', GraphemeBreak::LV),
+    ('\u{B809}', '\u{B823}', GraphemeBreak::Lvt),
+    ('\u{B824}', '\u{B824}', GraphemeBreak::LV),
+    ('\u{B825}', '\u{B83F}', GraphemeBreak::Lvt),
+    ('\u{B840}', '\u{B840}', GraphemeBreak::LV),
+    ('\u{B841}', '\u{B85B}', GraphemeBreak::Lvt),
+    ('\u{B85C}', '\u{B85C}', GraphemeBreak::LV),
+    ('\u{B85D}', '\u{B877}', GraphemeBreak::Lvt),
+    ('\u{B878}', '\u{B878}', GraphemeBreak::LV),
+    ('\u{B879}', '\u{B893}', GraphemeBreak::Lvt),
+    ('\u{B894}', '\u{B894}', GraphemeBreak::LV),
+    ('\u{B895}', '\u{B8AF}', GraphemeBreak::Lvt),
+    ('\u{B8B0}', '\u{B8B0}', GraphemeBreak::LV),
+    ('\u{B8B1}', '\u{B8CB}', GraphemeBreak::Lvt),
+    ('\u{B8CC}', '\u{B8CC}', GraphemeBreak::LV),
+    ('\u{B8CD}', '\u{B8E7}', GraphemeBreak::Lvt),
+    ('\u{B8E8}', '\u{B8E8}', GraphemeBreak::LV),
+    ('\u{B8E9}', '\u{B903}', GraphemeBreak::Lvt),
+    ('\u{B904}', '\u{B904}', GraphemeBreak::LV),
+    ('\u{B905}', '\u{B91F}', GraphemeBreak::Lvt),
+    ('\u{B920}', '\u{B920}', GraphemeBreak::LV),
+    ('\u{B921}', '\u{B93B}', GraphemeBreak::Lvt),
+    ('\u{B93C}', '\u{B93C}', GraphemeBreak::LV),
+    ('\u{B93D}', '\u{B957}', GraphemeBreak::Lvt),
+    ('\u{B958}', '\u{B958}', GraphemeBreak::LV),
+    ('\u{B959}', '\u{B973}', GraphemeBreak::Lvt),
+    ('\u{B974}', '\u{B974}', GraphemeBreak::LV),
+    ('\u{B975}', '\u{B98F}', GraphemeBreak::Lvt),
+    ('\u{B990}', '\u{B990}', GraphemeBreak::LV),
+    ('\u{B991}', '\u{B9AB}', GraphemeBreak::Lvt),
+    ('\u{B9AC}', '\u{B9AC}', GraphemeBreak::LV),
+    ('\u{B9AD}', '\u{B9C7}', GraphemeBreak::Lvt),
+    ('\u{B9C8}', '\u{B9C8}', GraphemeBreak::LV),
+    ('\u{B9C9}', '\u{B9E3}', GraphemeBreak::Lvt),
+    ('\u{B9E4}', '\u{B9E4}', GraphemeBreak::LV),
+    ('\u{B9E5}', '\u{B9FF}', GraphemeBreak::Lvt),
+    ('\u{BA00}', '\u{BA00}', GraphemeBreak::LV),
+    ('\u{BA01}', '\u{BA1B}', GraphemeBreak::Lvt),
+    ('\u{BA1C}', '\u{BA1C}', GraphemeBreak::LV),
+    ('\u{BA1D}', '\u{BA37}', GraphemeBreak::Lvt),
+    ('\u{BA38}', '\u{BA38}', GraphemeBreak::LV),
+    ('\u{BA39}', '\u{BA53}', GraphemeBreak::Lvt),
+    ('\u{BA54}', '\u{BA54}', GraphemeBreak::LV),
+    ('\u{BA55}', '\u{BA6F}', GraphemeBreak::Lvt),
+    ('\u{BA70}', '\u{BA70}', GraphemeBreak::LV),
+    ('\u{BA71}', '\u{BA8B}', GraphemeBreak::Lvt),
+    ('\u{BA8C}', '\u{BA8C}', GraphemeBreak::LV),
+    ('\u{BA8D}', '\u{BAA7}', GraphemeBreak::Lvt),
+    ('\u{BAA8}', '\u{BAA8}', GraphemeBreak::LV),
+    ('\u{BAA9}', '\u{BAC3}', GraphemeBreak::Lvt),
+    ('\u{BAC4}', '\u{BAC4}', GraphemeBreak::LV),
+    ('\u{BAC5}', '\u{BADF}', GraphemeBreak::Lvt),
+    ('\u{BAE0}', '\u{BAE0}', GraphemeBreak::LV),
+    ('\u{BAE1}', '\u{BAFB}', GraphemeBreak::Lvt),
+    ('\u{BAFC}', '\u{BAFC}', GraphemeBreak::LV),
+    ('\u{BAFD}', '\u{BB17}', GraphemeBreak::Lvt),
+    ('\u{BB18}', '\u{BB18}', GraphemeBreak::LV),
+    ('\u{BB19}', '\u{BB33}', GraphemeBreak::Lvt),
+    ('\u{BB34}', '\u{BB34}', GraphemeBreak::LV),
+    ('\u{BB35}', '\u{BB4F}', GraphemeBreak::Lvt),
+    ('\u{BB50}', '\u{BB50}', GraphemeBreak::LV),
+    ('\u{BB51}', '\u{BB6B}', GraphemeBreak::Lvt),
+    ('\u{BB6C}', '\u{BB6C}', GraphemeBreak::LV),
+    ('\u{BB6D}', '\u{BB87}', GraphemeBreak::Lvt),
+    ('\u{BB88}', '\u{BB88}', GraphemeBreak::LV),
+    ('\u{BB89}', '\u{BBA3}', GraphemeBreak::Lvt),
+    ('\u{BBA4}', '\u{BBA4}', GraphemeBreak::LV),
+    ('\u{BBA5}', '\u{BBBF}', GraphemeBreak::Lvt),
+    ('\u{BBC0}', '\u{BBC0}', GraphemeBreak::LV),
+    ('\u{BBC1}', '\u{BBDB}', GraphemeBreak::Lvt),
+    ('\u{BBDC}', '\u{BBDC}', GraphemeBreak::LV),
+    ('\u{BBDD}', '\u{BBF7}', GraphemeBreak::Lvt),
+    ('\u{BBF8}', '\u{BBF8}', GraphemeBreak::LV),
+    ('\u{BBF9}', '\u{BC13}', GraphemeBreak::Lvt),
+    ('\u{BC14}', '\u{BC14}', GraphemeBreak::LV),
+    ('\u{BC15}', '\u{BC2F}', GraphemeBreak::Lvt),
+    ('\u{BC30}', '\u{BC30}', GraphemeBreak::LV),
+    ('\u{BC31}', '\u{BC4B}', GraphemeBreak::Lvt),
+    ('\u{BC4C}', '\u{BC4C}', GraphemeBreak::LV),
+    ('\u{BC4D}', '\u{BC67}', GraphemeBreak::Lvt),
+    ('\u{BC68}', '\u{BC68}', GraphemeBreak::LV),
+    ('\u{BC69}', '\u{BC83}', GraphemeBreak::Lvt),
+    ('\u{BC84}', '\u{BC84}', GraphemeBreak::LV),
+    ('\u{BC85}', '\u{BC9F}', GraphemeBreak::Lvt),
+    ('\u{BCA0}', '\u{BCA0}', GraphemeBreak::LV),
+    ('\u{BCA1}', '\u{BCBB}', GraphemeBreak::Lvt),
+    ('\u{BCBC}', '\u{BCBC}', GraphemeBreak::LV),
+    ('\u{BCBD}', '\u{BCD7}', GraphemeBreak::Lvt),
+    ('\u{BCD8}', '\u{BCD8}', GraphemeBreak::LV),
+    ('\u{BCD9}', '\u{BCF3}', GraphemeBreak::Lvt),
+    ('\u{BCF4}', '\u{BCF4}', GraphemeBreak::LV),
+    ('\u{BCF5}', '\u{BD0F}', GraphemeBreak::Lvt),
+    ('\u{BD10}', '\u{BD10}', GraphemeBreak::LV),
+    ('\u{BD11}', '\u{BD2B}', GraphemeBreak::Lvt),
+    ('\u{BD2C}', '\u{BD2C}', GraphemeBreak::LV),
+    ('\u{BD2D}', '\u{BD47}', GraphemeBreak::Lvt),
+    ('\u{BD48}', '\u{BD48}', GraphemeBreak::LV),
+    ('\u{BD49}', '\u{BD63}', GraphemeBreak::Lvt),
+    ('\u{BD64}', '\u{BD64}', GraphemeBreak::LV),
+    ('\u{BD65}', '\u{BD7F}', GraphemeBreak::Lvt),
+    ('\u{BD80}', '\u{BD80}', GraphemeBreak::LV),
+    ('\u{BD81}', '\u{BD9B}', GraphemeBreak::Lvt),
+    ('\u{BD9C}', '\u{BD9C}', GraphemeBreak::LV),
+    ('\u{BD9D}', '\u{BDB7}', GraphemeBreak::Lvt),
+    ('\u{BDB8}', '\u{BDB8}', GraphemeBreak::LV),
+    ('\u{BDB9}', '\u{BDD3}', GraphemeBreak::Lvt),
+    ('\u{BDD4}', '\u{BDD4}', GraphemeBreak::LV),
+    ('\u{BDD5}', '\u{BDEF}', GraphemeBreak::Lvt),
+    ('\u{BDF0}', '\u{BDF0}', GraphemeBreak::LV),
+    ('\u{BDF1}', '\u{BE0B}', GraphemeBreak::Lvt),
+    ('\u{BE0C}', '\u{BE0C}', GraphemeBreak::LV),
+    ('\u{BE0D}', '\u{BE27}', GraphemeBreak::Lvt),
+    ('\u{BE28}', '\u{BE28}', GraphemeBreak::LV),
+    ('\u{BE29}', '\u{BE43}', GraphemeBreak::Lvt),
+    ('\u{BE44}', '\u{BE44}', GraphemeBreak::LV),
+    ('\u{BE45}', '\u{BE5F}', GraphemeBreak::Lvt),
+    ('\u{BE60}', '\u{BE60}', GraphemeBreak::LV),
+    ('\u{BE61}', '\u{BE7B}', GraphemeBreak::Lvt),
+    ('\u{BE7C}', '\u{BE7C}', GraphemeBreak::LV),
+    ('\u{BE7D}', '\u{BE97}', GraphemeBreak::Lvt),
+    ('\u{BE98}', '\u{BE98}', GraphemeBreak::LV),
+    ('\u{BE99}', '\u{BEB3}', GraphemeBreak::Lvt),
+    ('\u{BEB4}', '\u{BEB4}', GraphemeBreak::LV),
+    ('\u{BEB5}', '\u{BECF}', GraphemeBreak::Lvt),
+    ('\u{BED0}', '\u{BED0}', GraphemeBreak::LV),
+    ('\u{BED1}', '\u{BEEB}', GraphemeBreak::Lvt),
+    ('\u{BEEC}', '\u{BEEC}', GraphemeBreak::LV),
+    ('\u{BEED}', '\u{BF07}', GraphemeBreak::Lvt),
+    ('\u{BF08}', '\u{BF08}', GraphemeBreak::LV),
+    ('\u{BF09}', '\u{BF23}', GraphemeBreak::Lvt),
+    ('\u{BF24}', '\u{BF24}', GraphemeBreak::LV),
+    ('\u{BF25}', '\u{BF3F}', GraphemeBreak::Lvt),
+    ('\u{BF40}', '\u{BF40}', GraphemeBreak::LV),
+    ('\u{BF41}', '\u{BF5B}', GraphemeBreak::Lvt),
+    ('\u{BF5C}', '\u{BF5C}', GraphemeBreak::LV),
+    ('\u{BF5D}', '\u{BF77}', GraphemeBreak::Lvt),
+    ('\u{BF78}', '\u{BF78}', GraphemeBreak::LV),
+    ('\u{BF79}', '\u{BF93}', GraphemeBreak::Lvt),
+    ('\u{BF94}', '\u{BF94}', GraphemeBreak::LV),
+    ('\u{BF95}', '\u{BFAF}', GraphemeBreak::Lvt),
+    ('\u{BFB0}', '\u{BFB0}', GraphemeBreak::LV),
+    ('\u{BFB1}', '\u{BFCB}', GraphemeBreak::Lvt),
+    ('\u{BFCC}', '\u{BFCC}', GraphemeBreak::LV),
+    ('\u{BFCD}', '\u{BFE7}', GraphemeBreak::Lvt),
+    ('\u{BFE8}', '\u{BFE8}', GraphemeBreak::LV),
+    ('\u{BFE9}', '\u{C003}', GraphemeBreak::Lvt),
+    ('\u{C004}', '\u{C004}', GraphemeBreak::LV),
+    ('\u{C005}', '\u{C01F}', GraphemeBreak::Lvt),
+    ('\u{C020}', '\u{C020}', GraphemeBreak::LV),
+    ('\u{C021}', '\u{C03B}', GraphemeBreak::Lvt),
+    ('\u{C03C}', '\u{C03C}', GraphemeBreak::LV),
+    ('\u{C03D}', '\u{C057}', GraphemeBreak::Lvt),
+    ('\u{C058}', '\u{C058}', GraphemeBreak::LV),
+    ('\u{C059}', '\u{C073}', GraphemeBreak::Lvt),
+    ('\u{C074}', '\u{C074}', GraphemeBreak::LV),
+    ('\u{C075}', '\u{C08F}', GraphemeBreak::Lvt),
+    ('\u{C090}', '\u{C090}', GraphemeBreak::LV),
+    ('\u{C091}', '\u{C0AB}', GraphemeBreak::Lvt),
+    ('\u{C0AC}', '\u{C0AC}', GraphemeBreak::LV),
+    ('\u{C0AD}', '\u{C0C7}', GraphemeBreak::Lvt),
+    ('\u{C0C8}', '\u{C0C8}', GraphemeBreak::LV),
+    ('\u{C0C9}', '\u{C0E3}', GraphemeBreak::Lvt),
+    ('\u{C0E4}', '\u{C0E4}', GraphemeBreak::LV),
+    ('\u{C0E5}', '\u{C0FF}', GraphemeBreak::Lvt),
+    ('\u{C100}', '\u{C100}', GraphemeBreak::LV),
+    ('\u{C101}', '\u{C11B}', GraphemeBreak::Lvt),
+    ('\u{C11C}', '\u{C11C}', GraphemeBreak::LV),
+    ('\u{C11D}', '\u{C137}', GraphemeBreak::Lvt),
+    ('\u{C138}', '\u{C138}', GraphemeBreak::LV),
+    ('\u{C139}', '\u{C153}', GraphemeBreak::Lvt),
+    ('\u{C154}', '\u{C154}', GraphemeBreak::LV),
+    ('\u{C155}', '\u{C16F}', GraphemeBreak::Lvt),
+    ('\u{C170}', '\u{C170}', GraphemeBreak::LV),
+    ('\u{C171}', '\u{C18B}', GraphemeBreak::Lvt),
+    ('\u{C18C}', '\u{C18C}', GraphemeBreak::LV),
+    ('\u{C18D}', '\u{C1A7}', GraphemeBreak::Lvt),
+    ('\u{C1A8}', '\u{C1A8}', GraphemeBreak::LV),
+    ('\u{C1A9}', '\u{C1C3}', GraphemeBreak::Lvt),
+    ('\u{C1C4}', '\u{C1C4}', GraphemeBreak::LV),
+    ('\u{C1C5}', '\u{C1DF}', GraphemeBreak::Lvt),
+    ('\u{C1E0}', '\u{C1E0}', GraphemeBreak::LV),
+    ('\u{C1E1}', '\u{C1FB}', GraphemeBreak::Lvt),
+    ('\u{C1FC}', '\u{C1FC}', GraphemeBreak::LV),
+    ('\u{C1FD}', '\u{C217}', GraphemeBreak::Lvt),
+    ('\u{C218}', '\u{C218}', GraphemeBreak::LV),
+    ('\u{C219}', '\u{C233}', GraphemeBreak::Lvt),
+    ('\u{C234}', '\u{C234}', GraphemeBreak::LV),
+    ('\u{C235}', '\u{C24F}', GraphemeBreak::Lvt),
+    ('\u{C250}', '\u{C250}', GraphemeBreak::LV),
+    ('\u{C251}', '\u{C26B}', GraphemeBreak::Lvt),
+    ('\u{C26C}', '\u{C26C}', GraphemeBreak::LV),
+    ('\u{C26D}', '\u{C287}', GraphemeBreak::Lvt),
+    ('\u{C288}', '\u{C288}', GraphemeBreak::LV),
+    ('\u{C289}', '\u{C2A3}', GraphemeBreak::Lvt),
+    ('\u{C2A4}', '\u{C2A4}', GraphemeBreak::LV),
+    ('\u{C2A5}', '\u{C2BF}', GraphemeBreak::Lvt),
+    ('\u{C2C0}', '\u{C2C0}', GraphemeBreak::LV),
+    ('\u{C2C1}', '\u{C2DB}', GraphemeBreak::Lvt),
+    ('\u{C2DC}', '\u{C2DC}', GraphemeBreak::LV),
+    ('\u{C2DD}', '\u{C2F7}', GraphemeBreak::Lvt),
+    ('\u{C2F8}', '\u{C2F8}', GraphemeBreak::LV),
+    ('\u{C2F9}', '\u{C313}', GraphemeBreak::Lvt),
+    ('\u{C314}', '\u{C314}', GraphemeBreak::LV),
+    ('\u{C315}', '\u{C32F}', GraphemeBreak::Lvt),
+    ('\u{C330}', '\u{C330}', GraphemeBreak::LV),
+    ('\u{C331}', '\u{C34B}', GraphemeBreak::Lvt),
+    ('\u{C34C}', '\u{C34C}', GraphemeBreak::LV),
+    ('\u{C34D}', '\u{C367}', GraphemeBreak::Lvt),
+    ('\u{C368}', '\u{C368}', GraphemeBreak::LV),
+    ('\u{C369}', '\u{C383}', GraphemeBreak::Lvt),
+    ('\u{C384}', '\u{C384}', GraphemeBreak::LV),
+    ('\u{C385}', '\u{C39F}', GraphemeBreak::Lvt),
+    ('\u{C3A0}', '\u{C3A0}', GraphemeBreak::LV),
+    ('\u{C3A1}', '\u{C3BB}', GraphemeBreak::Lvt),
+    ('\u{C3BC}', '\u{C3BC}', GraphemeBreak::LV),
+    ('\u{C3BD}', '\u{C3D7}', GraphemeBreak::Lvt),
+    ('\u{C3D8}', '\u{C3D8}', GraphemeBreak::LV),
+    ('\u{C3D9}', '\u{C3F3}', GraphemeBreak::Lvt),
+    ('\u{C3F4}', '\u{C3F4}', GraphemeBreak::LV),
+    ('\u{C3F5}', '\u{C40F}', GraphemeBreak::Lvt),
+    ('\u{C410}', '\u{C410}', GraphemeBreak::LV),
+    ('\u{C411}', '\u{C42B}', GraphemeBreak::Lvt),
+    ('\u{C42C}', '\u{C42C}', GraphemeBreak::LV),
+    ('\u{C42D}', '\u{C447}', GraphemeBreak::Lvt),
+    ('\u{C448}', '\u{C448}', GraphemeBreak::LV),
+    ('\u{C449}', '\u{C463}', GraphemeBreak::Lvt),
+    ('\u{C464}', '\u{C464}', GraphemeBreak::LV),
+    ('\u{C465}', '\u{C47F}', GraphemeBreak::Lvt),
+    ('\u{C480}', '\u{C480}', GraphemeBreak::LV),
+    ('\u{C481}', '\u{C49B}', GraphemeBreak::Lvt),
+    ('\u{C49C}', '\u{C49C}', GraphemeBreak::LV),
+    ('\u{C49D}', '\u{C4B7}', GraphemeBreak::Lvt),
+    ('\u{C4B8}', '\u{C4B8}', GraphemeBreak::LV),
+    ('\u{C4B9}', '\u{C4D3}', GraphemeBreak::Lvt),
+    ('\u{C4D4}', '\u{C4D4}', GraphemeBreak::LV),
+    ('\u{C4D5}', '\u{C4EF}', GraphemeBreak::Lvt),
+    ('\u{C4F0}', '\u{C4F0}', GraphemeBreak::LV),
+    ('\u{C4F1}', '\u{C50B}', GraphemeBreak::Lvt),
+    ('\u{C50C}', '\u{C50C}', GraphemeBreak::LV),
+    ('\u{C50D}', '\u{C527}', GraphemeBreak::Lvt),
+    ('\u{C528}', '\u{C528}', GraphemeBreak::LV),
+    ('\u{C529}', '\u{C543}', GraphemeBreak::Lvt),
+    ('\u{C544}', '\u{C544}', GraphemeBreak::LV),
+    ('\u{C545}', '\u{C55F}', GraphemeBreak::Lvt),
+    ('\u{C560}', '\u{C560}', GraphemeBreak::LV),
+    ('\u{C561}', '\u{C57B}', GraphemeBreak::Lvt),
+    ('\u{C57C}', '\u{C57C}', GraphemeBreak::LV),
+    ('\u{C57D}', '\u{C597}', GraphemeBreak::Lvt),
+    ('\u{C598}', '\u{C598}', GraphemeBreak::LV),
+    ('\u{C599}', '\u{C5B3}', GraphemeBreak::Lvt),
+    ('\u{C5B4}', '\u{C5B4}', GraphemeBreak::LV),
+    ('\u{C5B5}', '\u{C5CF}', GraphemeBreak::Lvt),
+    ('\u{C5D0}', '\u{C5D0}', GraphemeBreak::LV),
+    ('\u{C5D1}', '\u{C5EB}', GraphemeBreak::Lvt),
+    ('\u{C5EC}', '\u{C5EC}', GraphemeBreak::LV),
+    ('\u{C5ED}', '\u{C607}', GraphemeBreak::Lvt),
+    ('\u{C608}', '\u{C608}', GraphemeBreak::LV),
+    ('\u{C609}', '\u{C623}', GraphemeBreak::Lvt),
+    ('\u{C624}', '\u{C624}', GraphemeBreak::LV),
+    ('\u{C625}', '\u{C63F}', GraphemeBreak::Lvt),
+    ('\u{C640}', '\u{C640}', GraphemeBreak::LV),
+    ('\u{C641}', '\u{C65B}', GraphemeBreak::Lvt),
+    ('\u{C65C}', '\u{C65C}', GraphemeBreak::LV),
+    ('\u{C65D}', '\u{C677}', GraphemeBreak::Lvt),
+    ('\u{C678}', '\u{C678}', GraphemeBreak::LV),
+    ('\u{C679}', '\u{C693}', GraphemeBreak::Lvt),
+    ('\u{C694}', '\u{C694}', GraphemeBreak::LV),
+    ('\u{C695}', '\u{C6AF}', GraphemeBreak::Lvt),
+    ('\u{C6B0}', '\u{C6B0}', GraphemeBreak::LV),
+    ('\u{C6B1}', '\u{C6CB}', GraphemeBreak::Lvt),
+    ('\u{C6CC}', '\u{C6CC}', GraphemeBreak::LV),
+    ('\u{C6CD}', '\u{C6E7}', GraphemeBreak::Lvt),
+    ('\u{C6E8}', '\u{C6E8}', GraphemeBreak::LV),
+    ('\u{C6E9}', '\u{C703}', GraphemeBreak::Lvt),
+    ('\u{C704}', '\u{C704}', GraphemeBreak::LV),
+    ('\u{C705}', '\u{C71F}', GraphemeBreak::Lvt),
+    ('\u{C720}', '\u{C720}', GraphemeBreak::LV),
+    ('\u{C721}', '\u{C73B}', GraphemeBreak::Lvt),
+    ('\u{C73C}', '\u{C73C}', GraphemeBreak::LV),
+    ('\u{C73D}', '\u{C757}', GraphemeBreak::Lvt),
+    ('\u{C758}', '\u{C758}', GraphemeBreak::LV),
+    ('\u{C759}', '\u{C773}', GraphemeBreak::Lvt),
+    ('\u{C774}', '\u{C774}', GraphemeBreak::LV),
+    ('\u{C775}', '\u{C78F}', GraphemeBreak::Lvt),
+    ('\u{C790}', '\u{C790}', GraphemeBreak::LV),
+    ('\u{C791}', '\u{C7AB}', GraphemeBreak::Lvt),
+    ('\u{C7AC}', '\u{C7AC}', GraphemeBreak::LV),
+    ('\u{C7AD}', '\u{C7C7}', GraphemeBreak::Lvt),
+    ('\u{C7C8}', '\u{C7C8}', GraphemeBreak::LV),
+    ('\u{C7C9}', '\u{C7E3}', GraphemeBreak::Lvt),
+    ('\u{C7E4}', '\u{C7E4}', GraphemeBreak::LV),
+    ('\u{C7E5}', '\u{C7FF}', GraphemeBreak::Lvt),
+    ('\u{C800}', '\u{C800}', GraphemeBreak::LV),
+    ('\u{C801}', '\u{C81B}', GraphemeBreak::Lvt),
+    ('\u{C81C}', '\u{C81C}', GraphemeBreak::LV),
+    ('\u{C81D}', '\u{C837}', GraphemeBreak::Lvt),
+    ('\u{C838}', '\u{C838}', GraphemeBreak::LV),
+    ('\u{C839}', '\u{C853}', GraphemeBreak::Lvt),
+    ('\u{C854}', '\u{C854}', GraphemeBreak::LV),
+    ('\u{C855}', '\u{C86F}', GraphemeBreak::Lvt),
+    ('\u{C870}', '\u{C870}', GraphemeBreak::LV),
+    ('\u{C871}', '\u{C88B}', GraphemeBreak::Lvt),
+    ('\u{C88C}', '\u{C88C}', GraphemeBreak::LV),
+    ('\u{C88D}', '\u{C8A7}', GraphemeBreak::Lvt),
+    ('\u{C8A8}', '\u{C8A8}', GraphemeBreak::LV),
+    ('\u{C8A9}', '\u{C8C3}', GraphemeBreak::Lvt),
+    ('\u{C8C4}', '\u{C8C4}', GraphemeBreak::LV),
+    ('\u{C8C5}', '\u{C8DF}', GraphemeBreak::Lvt),
+    ('\u{C8E0}', '\u{C8E0}', GraphemeBreak::LV),
+    ('\u{C8E1}', '\u{C8FB}', GraphemeBreak::Lvt),
+    ('\u{C8FC}', '\u{C8FC}', GraphemeBreak::LV),
+    ('\u{C8FD}', '\u{C917}', GraphemeBreak::Lvt),
+    ('\u{C918}', '\u{C918}', GraphemeBreak::LV),
+    ('\u{C919}', '\u{C933}', GraphemeBreak::Lvt),
+    ('\u{C934}', '\u{C934}', GraphemeBreak::LV),
+    ('\u{C935}', '\u{C94F}', GraphemeBreak::Lvt),
+    ('\u{C950}', '\u{C950}', GraphemeBreak::LV),
+    ('\u{C951}', '\u{C96B}', GraphemeBreak::Lvt),
+    ('\u{C96C}', '\u{C96C}', GraphemeBreak::LV),
+    ('\u{C96D}', '\u{C987}', GraphemeBreak::Lvt),
+    ('\u{C988}', '\u{C988}', GraphemeBreak::LV),
+    ('\u{C989}', '\u{C9A3}', GraphemeBreak::Lvt),
+    ('\u{C9A4}', '\u{C9A4}', GraphemeBreak::LV),
+    ('\u{C9A5}', '\u{C9BF}', GraphemeBreak::Lvt),
+    ('\u{C9C0}', '\u{C9C0}', GraphemeBreak::LV),
+    ('\u{C9C1}', '\u{C9DB}', GraphemeBreak::Lvt),
+    ('\u{C9DC}', '\u{C9DC}', GraphemeBreak::LV),
+    ('\u{C9DD}', '\u{C9F7}', GraphemeBreak::Lvt),
+    ('\u{C9F8}', '\u{C9F8}', GraphemeBreak::LV),
+    ('\u{C9F9}', '\u{CA13}', GraphemeBreak::Lvt),
+    ('\u{CA14}', '\u{CA14}', GraphemeBreak::LV),
+    ('\u{CA15}', '\u{CA2F}', GraphemeBreak::Lvt),
+    ('\u{CA30}', '\u{CA30}', GraphemeBreak::LV),
+    ('\u{CA31}', '\u{CA4B}', GraphemeBreak::Lvt),
+    ('\u{CA4C}', '\u{CA4C}', GraphemeBreak::LV),
+    ('\u{CA4D}', '\u{CA67}', GraphemeBreak::Lvt),
+    ('\u{CA68}', '\u{CA68}', GraphemeBreak::LV),
+    ('\u{CA69}', '\u{CA83}', GraphemeBreak::Lvt),
+    ('\u{CA84}', '\u{CA84}', GraphemeBreak::LV),
+    ('\u{CA85}', '\u{CA9F}', GraphemeBreak::Lvt),
+    ('\u{CAA0}', '\u{CAA0}', GraphemeBreak::LV),
+    ('\u{CAA1}', '\u{CABB}', GraphemeBreak::Lvt),
+    ('\u{CABC}', '\u{CABC}', GraphemeBreak::LV),
+    ('\u{CABD}', '\u{CAD7}', GraphemeBreak::Lvt),
+    ('\u{CAD8}', '\u{CAD8}', GraphemeBreak::LV),
+    ('\u{CAD9}', '\u{CAF3}', GraphemeBreak::Lvt),
+    ('\u{CAF4}', '\u{CAF4}', GraphemeBreak::LV),
+    ('\u{CAF5}', '\u{CB0F}', GraphemeBreak::Lvt),
+    ('\u{CB10}', '\u{CB10}', GraphemeBreak::LV),
+    ('\u{CB11}', '\u{CB2B}', GraphemeBreak::Lvt),
+    ('\u{CB2C}', '\u{CB2C}', GraphemeBreak::LV),
+    ('\u{CB2D}', '\u{CB47}', GraphemeBreak::Lvt),
+    ('\u{CB48}', '\u{CB48}', GraphemeBreak::LV),
+    ('\u{CB49}', '\u{CB63}', GraphemeBreak::Lvt),
+    ('\u{CB64}', '\u{CB64}', GraphemeBreak::LV),
+    ('\u{CB65}', '\u{CB7F}', GraphemeBreak::Lvt),
+    ('\u{CB80}', '\u{CB80}', GraphemeBreak::LV),
+    ('\u{CB81}', '\u{CB9B}', GraphemeBreak::Lvt),
+    ('\u{CB9C}', '\u{CB9C}', GraphemeBreak::LV),
+    ('\u{CB9D}', '\u{CBB7}', GraphemeBreak::Lvt),
+    ('\u{CBB8}', '\u{CBB8}', GraphemeBreak::LV),
+    ('\u{CBB9}', '\u{CBD3}', GraphemeBreak::Lvt),
+    ('\u{CBD4}', '\u{CBD4}', GraphemeBreak::LV),
+    ('\u{CBD5}', '\u{CBEF}', GraphemeBreak::Lvt),
+    ('\u{CBF0}', '\u{CBF0}', GraphemeBreak::LV),
+    ('\u{CBF1}', '\u{CC0B}', GraphemeBreak::Lvt),
+    ('\u{CC0C}', '\u{CC0C}', GraphemeBreak::LV),
+    ('\u{CC0D}', '\u{CC27}', GraphemeBreak::Lvt),
+    ('\u{CC28}', '\u{CC28}', GraphemeBreak::LV),
+    ('\u{CC29}', '\u{CC43}', GraphemeBreak::Lvt),
+    ('\u{CC44}', '\u{CC44}', GraphemeBreak::LV),
+    ('\u{CC45}', '\u{CC5F}', GraphemeBreak::Lvt),
+    ('\u{CC60}', '\u{CC60}', GraphemeBreak::LV),
+    ('\u{CC61}', '\u{CC7B}', GraphemeBreak::Lvt),
+    ('\u{CC7C}', '\u{CC7C}', GraphemeBreak::LV),
+    ('\u{CC7D}', '\u{CC97}', GraphemeBreak::Lvt),
+    ('\u{CC98}', '\u{CC98}', GraphemeBreak::LV),
+    ('\u{CC99}', '\u{CCB3}', GraphemeBreak::Lvt),
+    ('\u{CCB4}', '\u{CCB4}', GraphemeBreak::LV),
+    ('\u{CCB5}', '\u{CCCF}', GraphemeBreak::Lvt),
+    ('\u{CCD0}', '\u{CCD0}', GraphemeBreak::LV),
+    ('\u{CCD1}', '\u{CCEB}', GraphemeBreak::Lvt),
+    ('\u{CCEC}', '\u{CCEC}', GraphemeBreak::LV),
+    ('\u{CCED}', '\u{CD07}', GraphemeBreak::Lvt),
+    ('\u{CD08}', '\u{CD08}', GraphemeBreak::LV),
+    ('\u{CD09}', '\u{CD23}', GraphemeBreak::Lvt),
+    ('\u{CD24}', '\u{CD24}', GraphemeBreak::LV),
+    ('\u{CD25}', '\u{CD3F}', GraphemeBreak::Lvt),
+    ('\u{CD40}', '\u{CD40}', GraphemeBreak::LV),
+    ('\u{CD41}', '\u{CD5B}', GraphemeBreak::Lvt),
+    ('\u{CD5C}', '\u{CD5C}', GraphemeBreak::LV),
+    ('\u{CD5D}', '\u{CD77}', GraphemeBreak::Lvt),
+    ('\u{CD78}', '\u{CD78}', GraphemeBreak::LV),
+    ('\u{CD79}', '\u{CD93}', GraphemeBreak::Lvt),
+    ('\u{CD94}', '\u{CD94}', GraphemeBreak::LV),
+    ('\u{CD95}', '\u{CDAF}', GraphemeBreak::Lvt),
+    ('\u{CDB0}', '\u{CDB0}', GraphemeBreak::LV),
+    ('\u{CDB1}', '\u{CDCB}', GraphemeBreak::Lvt),
+    ('\u{CDCC}', '\u{CDCC}', GraphemeBreak::LV),
+    ('\u{CDCD}', '\u{CDE7}', GraphemeBreak::Lvt),
+    ('\u{CDE8}', '\u{CDE8}', GraphemeBreak::LV),
+    ('\u{CDE9}', '\u{CE03}', GraphemeBreak::Lvt),
+    ('\u{CE04}', '\u{CE04}', GraphemeBreak::LV),
+    ('\u{CE05}', '\u{CE1F}', GraphemeBreak::Lvt),
+    ('\u{CE20}', '\u{CE20}', GraphemeBreak::LV),
+    ('\u{CE21}', '\u{CE3B}', GraphemeBreak::Lvt),
+    ('\u{CE3C}', '\u{CE3C}', GraphemeBreak::LV),
+    ('\u{CE3D}', '\u{CE57}', GraphemeBreak::Lvt),
+    ('\u{CE58}', '\u{CE58}', GraphemeBreak::LV),
+    ('\u{CE59}', '\u{CE73}', GraphemeBreak::Lvt),
+    ('\u{CE74}', '\u{CE74}', GraphemeBreak::LV),
+    ('\u{CE75}', '\u{CE8F}', GraphemeBreak::Lvt),
+    ('\u{CE90}', '\u{CE90}', GraphemeBreak::LV),
+    ('\u{CE91}', '\u{CEAB}', GraphemeBreak::Lvt),
+    ('\u{CEAC}', '\u{CEAC}', GraphemeBreak::LV),
+    ('\u{CEAD}', '\u{CEC7}', GraphemeBreak::Lvt),
+    ('\u{CEC8}', '\u{CEC8}', GraphemeBreak::LV),
+    ('\u{CEC9}', '\u{CEE3}', GraphemeBreak::Lvt),
+    ('\u{CEE4}', '\u{CEE4}', GraphemeBreak::LV),
+    ('\u{CEE5}', '\u{CEFF}', GraphemeBreak::Lvt),
+    ('\u{CF00}', '\u{CF00}', GraphemeBreak::LV),
+    ('\u{CF01}', '\u{CF1B}', GraphemeBreak::Lvt),
+    ('\u{CF1C}', '\u{CF1C}', GraphemeBreak::LV),
+    ('\u{CF1D}', '\u{CF37}', GraphemeBreak::Lvt),
+    ('\u{CF38}', '\u{CF38}', GraphemeBreak::LV),
+    ('\u{CF39}', '\u{CF53}', GraphemeBreak::Lvt),
+    ('\u{CF54}', '\u{CF54}', GraphemeBreak::LV),
+    ('\u{CF55}', '\u{CF6F}', GraphemeBreak::Lvt),
+    ('\u{CF70}', '\u{CF70}', GraphemeBreak::LV),
+    ('\u{CF71}', '\u{CF8B}', GraphemeBreak::Lvt),
+    ('\u{CF8C}', '\u{CF8C}', GraphemeBreak::LV),
+    ('\u{CF8D}', '\u{CFA7}', GraphemeBreak::Lvt),
+    ('\u{CFA8}', '\u{CFA8}', GraphemeBreak::LV),
+    ('\u{CFA9}', '\u{CFC3}', GraphemeBreak::Lvt),
+    ('\u{CFC4}', '\u{CFC4}', GraphemeBreak::LV),
+    ('\u{CFC5}', '\u{CFDF}', GraphemeBreak::Lvt),
+    ('\u{CFE0}', '\u{CFE0}', GraphemeBreak::LV),
+    ('\u{CFE1}', '\u{CFFB}', GraphemeBreak::Lvt),
+    ('\u{CFFC}', '\u{CFFC}', GraphemeBreak::LV),
+    ('\u{CFFD}', '\u{D017}', GraphemeBreak::Lvt),
+    ('\u{D018}', '\u{D018}', GraphemeBreak::LV),
+    ('\u{D019}', '\u{D033}', GraphemeBreak::Lvt),
+    ('\u{D034}', '\u{D034}', GraphemeBreak::LV),
+    ('\u{D035}', '\u{D04F}', GraphemeBreak::Lvt),
+    ('\u{D050}', '\u{D050}', GraphemeBreak::LV),
+    ('\u{D051}', '\u{D06B}', GraphemeBreak::Lvt),
+    ('\u{D06C}', '\u{D06C}', GraphemeBreak::LV),
+    ('\u{D06D}', '\u{D087}', GraphemeBreak::Lvt),
+    ('\u{D088}', '\u{D088}', GraphemeBreak::LV),
+    ('\u{D089}', '\u{D0A3}', GraphemeBreak::Lvt),
+    ('\u{D0A4}', '\u{D0A4}', GraphemeBreak::LV),
+    ('\u{D0A5}', '\u{D0BF}', GraphemeBreak::Lvt),
+    ('\u{D0C0}', '\u{D0C0}', GraphemeBreak::LV),
+    ('\u{D0C1}', '\u{D0DB}', GraphemeBreak::Lvt),
+    ('\u{D0DC}', '\u{D0DC}', GraphemeBreak::LV),
+    ('\u{D0DD}', '\u{D0F7}', GraphemeBreak::Lvt),
+    ('\u{D0F8}', '\u{D0F8}', GraphemeBreak::LV),
+    ('\u{D0F9}', '\u{D113}', GraphemeBreak::Lvt),
+    ('\u{D114}', '\u{D114}', GraphemeBreak::LV),
+    ('\u{D115}', '\u{D12F}', GraphemeBreak::Lvt),
+    ('\u{D130}', '\u{D130}', GraphemeBreak::LV),
+    ('\u{D131}', '\u{D14B}', GraphemeBreak::Lvt),
+    ('\u{D14C}', '\u{D14C}', GraphemeBreak::LV),
+    ('\u{D14D}', '\u{D167}', GraphemeBreak::Lvt),
+    ('\u{D168}', '\u{D168}', GraphemeBreak::LV),
+    ('\u{D169}', '\u{D183}', GraphemeBreak::Lvt),
+    ('\u{D184}', '\u{D184}', GraphemeBreak::LV),
+    ('\u{D185}', '\u{D19F}', GraphemeBreak::Lvt),
+    ('\u{D1A0}', '\u{D1A0}', GraphemeBreak::LV),
+    ('\u{D1A1}', '\u{D1BB}', GraphemeBreak::Lvt),
+    ('\u{D1BC}', '\u{D1BC}', GraphemeBreak::LV),
+    ('\u{D1BD}', '\u{D1D7}', GraphemeBreak::Lvt),
+    ('\u{D1D8}', '\u{D1D8}', GraphemeBreak::LV),
+    ('\u{D1D9}', '\u{D1F3}', GraphemeBreak::Lvt),
+    ('\u{D1F4}', '\u{D1F4}', GraphemeBreak::LV),
+    ('\u{D1F5}', '\u{D20F}', GraphemeBreak::Lvt),
+    ('\u{D210}', '\u{D210}', GraphemeBreak::LV),
+    ('\u{D211}', '\u{D22B}', GraphemeBreak::Lvt),
+    ('\u{D22C}', '\u{D22C}', GraphemeBreak::LV),
+    ('\u{D22D}', '\u{D247}', GraphemeBreak::Lvt),
+    ('\u{D248}', '\u{D248}', GraphemeBreak::LV),
+    ('\u{D249}', '\u{D263}', GraphemeBreak::Lvt),
+    ('\u{D264}', '\u{D264}', GraphemeBreak::LV),
+    ('\u{D265}', '\u{D27F}', GraphemeBreak::Lvt),
+    ('\u{D280}', '\u{D280}', GraphemeBreak::LV),
+    ('\u{D281}', '\u{D29B}', GraphemeBreak::Lvt),
+    ('\u{D29C}', '\u{D29C}', GraphemeBreak::LV),
+    ('\u{D29D}', '\u{D2B7}', GraphemeBreak::Lvt),
+    ('\u{D2B8}', '\u{D2B8}', GraphemeBreak::LV),
+    ('\u{D2B9}', '\u{D2D3}', GraphemeBreak::Lvt),
+    ('\u{D2D4}', '\u{D2D4}', GraphemeBreak::LV),
+    ('\u{D2D5}', '\u{D2EF}', GraphemeBreak::Lvt),
+    ('\u{D2F0}', '\u{D2F0}', GraphemeBreak::LV),
+    ('\u{D2F1}', '\u{D30B}', GraphemeBreak::Lvt),
+    ('\u{D30C}', '\u{D30C}', GraphemeBreak::LV),
+    ('\u{D30D}', '\u{D327}', GraphemeBreak::Lvt),
+    ('\u{D328}', '\u{D328}', GraphemeBreak::LV),
+    ('\u{D329}', '\u{D343}', GraphemeBreak::Lvt),
+    ('\u{D344}', '\u{D344}', GraphemeBreak::LV),
+    ('\u{D345}', '\u{D35F}', GraphemeBreak::Lvt),
+    ('\u{D360}', '\u{D360}', GraphemeBreak::LV),
+    ('\u{D361}', '\u{D37B}', GraphemeBreak::Lvt),
+    ('\u{D37C}', '\u{D37C}', GraphemeBreak::LV),
+    ('\u{D37D}', '\u{D397}', GraphemeBreak::Lvt),
+    ('\u{D398}', '\u{D398}', GraphemeBreak::LV),
+    ('\u{D399}', '\u{D3B3}', GraphemeBreak::Lvt),
+    ('\u{D3B4}', '\u{D3B4}', GraphemeBreak::LV),
+    ('\u{D3B5}', '\u{D3CF}', GraphemeBreak::Lvt),
+    ('\u{D3D0}', '\u{D3D0}', GraphemeBreak::LV),
+    ('\u{D3D1}', '\u{D3EB}', GraphemeBreak::Lvt),
+    ('\u{D3EC}', '\u{D3EC}', GraphemeBreak::LV),
+    ('\u{D3ED}', '\u{D407}', GraphemeBreak::Lvt),
+    ('\u{D408}', '\u{D408}', GraphemeBreak::LV),
+    ('\u{D409}', '\u{D423}', GraphemeBreak::Lvt),
+    ('\u{D424}', '\u{D424}', GraphemeBreak::LV),
+    ('\u{D425}', '\u{D43F}', GraphemeBreak::Lvt),
+    ('\u{D440}', '\u{D440}', GraphemeBreak::LV),
+    ('\u{D441}', '\u{D45B}', GraphemeBreak::Lvt),
+    ('\u{D45C}', '\u{D45C}', GraphemeBreak::LV),
+    ('\u{D45D}', '\u{D477}', GraphemeBreak::Lvt),
+    ('\u{D478}', '\u{D478}', GraphemeBreak::LV),
+    ('\u{D479}', '\u{D493}', GraphemeBreak::Lvt),
+    ('\u{D494}', '\u{D494}', GraphemeBreak::LV),
+    ('\u{D495}', '\u{D4AF}', GraphemeBreak::Lvt),
+    ('\u{D4B0}', '\u{D4B0}', GraphemeBreak::LV),
+    ('\u{D4B1}', '\u{D4CB}', GraphemeBreak::Lvt),
+    ('\u{D4CC}', '\u{D4CC}', GraphemeBreak::LV),
+    ('\u{D4CD}', '\u{D4E7}', GraphemeBreak::Lvt),
+    ('\u{D4E8}', '\u{D4E8}', GraphemeBreak::LV),
+    ('\u{D4E9}', '\u{D503}', GraphemeBreak::Lvt),
+    ('\u{D504}', '\u{D504}', GraphemeBreak::LV),
+    ('\u{D505}', '\u{D51F}', GraphemeBreak::Lvt),
+    ('\u{D520}', '\u{D520}', GraphemeBreak::LV),
+    ('\u{D521}', '\u{D53B}', GraphemeBreak::Lvt),
+    ('\u{D53C}', '\u{D53C}', GraphemeBreak::LV),
+    ('\u{D53D}', '\u{D557}', GraphemeBreak::Lvt),
+    ('\u{D558}', '\u{D558}', GraphemeBreak::LV),
+    ('\u{D559}', '\u{D573}', GraphemeBreak::Lvt),
+    ('\u{D574}', '\u{D574}', GraphemeBreak::LV),
+    ('\u{D575}', '\u{D58F}', GraphemeBreak::Lvt),
+    ('\u{D590}', '\u{D590}', GraphemeBreak::LV),
+    ('\u{D591}', '\u{D5AB}', GraphemeBreak::Lvt),
+    ('\u{D5AC}', '\u{D5AC}', GraphemeBreak::LV),
+    ('\u{D5AD}', '\u{D5C7}', GraphemeBreak::Lvt),
+    ('\u{D5C8}', '\u{D5C8}', GraphemeBreak::LV),
+    ('\u{D5C9}', '\u{D5E3}', GraphemeBreak::Lvt),
+    ('\u{D5E4}', '\u{D5E4}', GraphemeBreak::LV),
+    ('\u{D5E5}', '\u{D5FF}', GraphemeBreak::Lvt),
+    ('\u{D600}', '\u{D600}', GraphemeBreak::LV),
+    ('\u{D601}', '\u{D61B}', GraphemeBreak::Lvt),
+    ('\u{D61C}', '\u{D61C}', GraphemeBreak::LV),
+    ('\u{D61D}', '\u{D637}', GraphemeBreak::Lvt),
+    ('\u{D638}', '\u{D638}', GraphemeBreak::LV),
+    ('\u{D639}', '\u{D653}', GraphemeBreak::Lvt),
+    ('\u{D654}', '\u{D654}', GraphemeBreak::LV),
+    ('\u{D655}', '\u{D66F}', GraphemeBreak::Lvt),
+    ('\u{D670}', '\u{D670}', GraphemeBreak::LV),
+    ('\u{D671}', '\u{D68B}', GraphemeBreak::Lvt),
+    ('\u{D68C}', '\u{D68C}', GraphemeBreak::LV),
+    ('\u{D68D}', '\u{D6A7}', GraphemeBreak::Lvt),
+    ('\u{D6A8}', '\u{D6A8}', GraphemeBreak::LV),
+    ('\u{D6A9}', '\u{D6C3}', GraphemeBreak::Lvt),
+    ('\u{D6C4}', '\u{D6C4}', GraphemeBreak::LV),
+    ('\u{D6C5}', '\u{D6DF}', GraphemeBreak::Lvt),
+    ('\u{D6E0}', '\u{D6E0}', GraphemeBreak::LV),
+    ('\u{D6E1}', '\u{D6FB}', GraphemeBreak::Lvt),
+    ('\u{D6FC}', '\u{D6FC}', GraphemeBreak::LV),
+    ('\u{D6FD}', '\u{D717}', GraphemeBreak::Lvt),
+    ('\u{D718}', '\u{D718}', GraphemeBreak::LV),
+    ('\u{D719}', '\u{D733}', GraphemeBreak::Lvt),
+    ('\u{D734}', '\u{D734}', GraphemeBreak::LV),
+    ('\u{D735}', '\u{D74F}', GraphemeBreak::Lvt),
+    ('\u{D750}', '\u{D750}', GraphemeBreak::LV),
+    ('\u{D751}', '\u{D76B}', GraphemeBreak::Lvt),
+    ('\u{D76C}', '\u{D76C}', GraphemeBreak::LV),
+    ('\u{D76D}', '\u{D787}', GraphemeBreak::Lvt),
+    ('\u{D788}', '\u{D788}', GraphemeBreak::LV),
+    ('\u{D789}', '\u{D7A3}', GraphemeBreak::Lvt),
+    ('\u{D7B0}', '\u{D7C6}', GraphemeBreak::V),
+    ('\u{D7CB}', '\u{D7FB}', GraphemeBreak::T),
+    ('\u{FB1E}', '\u{FB1E}', GraphemeBreak::Extend),
+    ('\u{FE00}', '\u{FE0F}', GraphemeBreak::Extend),
+    ('\u{FE20}', '\u{FE2F}', GraphemeBreak::Extend),
+    ('\u{FEFF}', '\u{FEFF}', GraphemeBreak::Control),
+    ('\u{FF9E}', '\u{FF9F}', GraphemeBreak::Extend),
+    ('\u{FFF0}', '\u{FFFB}', GraphemeBreak::Control),
+    ('\u{101FD}', '\u{101FD}', GraphemeBreak::Extend),
+    ('\u{102E0}', '\u{102E0}', GraphemeBreak::Extend),
+    ('\u{10376}', '\u{1037A}', GraphemeBreak::Extend),
+    ('\u{10A01}', '\u{10A03}', GraphemeBreak::Extend),
+    ('\u{10A05}', '\u{10A06}', GraphemeBreak::Extend),
+    ('\u{10A0C}', '\u{10A0F}', GraphemeBreak::Extend),
+    ('\u{10A38}', '\u{10A3A}', GraphemeBreak::Extend),
+    ('\u{10A3F}', '\u{10A3F}', GraphemeBreak::Extend),
+    ('\u{10AE5}', '\u{10AE6}', GraphemeBreak::Extend),
+    ('\u{10D24}', '\u{10D27}', GraphemeBreak::Extend),
+    ('\u{10EAB}', '\u{10EAC}', GraphemeBreak::Extend),
+    ('\u{10F46}', '\u{10F50}', GraphemeBreak::Extend),
+    ('\u{10F82}', '\u{10F85}', GraphemeBreak::Extend),
+    ('\u{11000}', '\u{11000}', GraphemeBreak::SpacingMark),
+    ('\u{11001}', '\u{11001}', GraphemeBreak::Extend),
+    ('\u{11002}', '\u{11002}', GraphemeBreak::SpacingMark),
+    ('\u{11038}', '\u{11046}', GraphemeBreak::Extend),
+    ('\u{11070}', '\u{11070}', GraphemeBreak::Extend),
+    ('\u{11073}', '\u{11074}', GraphemeBreak::Extend),
+    ('\u{1107F}', '\u{11081}', GraphemeBreak::Extend),
+    ('\u{11082}', '\u{11082}', GraphemeBreak::SpacingMark),
+    ('\u{110B0}', '\u{110B2}', GraphemeBreak::SpacingMark),
+    ('\u{110B3}', '\u{110B6}', GraphemeBreak::Extend),
+    ('\u{110B7}', '\u{110B8}', GraphemeBreak::SpacingMark),
+    ('\u{110B9}', '\u{110BA}', GraphemeBreak::Extend),
+    ('\u{110BD}', '\u{110BD}', GraphemeBreak::Prepend),
+    ('\u{110C2}', '\u{110C2}', GraphemeBreak::Extend),
+    ('\u{110CD}', '\u{110CD}', GraphemeBreak::Prepend),
+    ('\u{11100}', '\u{11102}', GraphemeBreak::Extend),
+    ('\u{11127}', '\u{1112B}', GraphemeBreak::Extend),
+    ('\u{1112C}', '\u{1112C}', GraphemeBreak::SpacingMark),
+    ('\u{1112D}', '\u{11134}', GraphemeBreak::Extend),
+    ('\u{11145}', '\u{11146}', GraphemeBreak::SpacingMark),
+    ('\u{11173}', '\u{11173}', GraphemeBreak::Extend),
+    ('\u{11180}', '\u{11181}', GraphemeBreak::Extend),
+    ('\u{11182}', '\u{11182}', GraphemeBreak::SpacingMark),
+    ('\u{111B3}', '\u{111B5}', GraphemeBreak::SpacingMark),
+    ('\u{111B6}', '\u{111BE}', GraphemeBreak::Extend),
+    ('\u{111BF}', '\u{111C0}', GraphemeBreak::SpacingMark),
+    ('\u{111C2}', '\u{111C3}', GraphemeBreak::Prepend),
+    ('\u{111C9}', '\u{111CC}', GraphemeBreak::Extend),
+    ('\u{111CE}', '\u{111CE}', GraphemeBreak::SpacingMark),
+    ('\u{111CF}', '\u{111CF}', GraphemeBreak::Extend),
+    ('\u{1122C}', '\u{1122E}', GraphemeBreak::SpacingMark),
+    ('\u{1122F}', '\u{11231}', GraphemeBreak::Extend),
+    ('\u{11232}', '\u{11233}', GraphemeBreak::SpacingMark),
+    ('\u{11234}', '\u{11234}', GraphemeBreak::Extend),
+    ('\u{11235}', '\u{11235}', GraphemeBreak::SpacingMark),
+    ('\u{11236}', '\u{11237}', GraphemeBreak::Extend),
+    ('\u{1123E}', '\u{1123E}', GraphemeBreak::Extend),
+    ('\u{112DF}', '\u{112DF}', GraphemeBreak::Extend),
+    ('\u{112E0}', '\u{112E2}', GraphemeBreak::SpacingMark),
+    ('\u{112E3}', '\u{112EA}', GraphemeBreak::Extend),
+    ('\u{11300}', '\u{11301}', GraphemeBreak::Extend),
+    ('\u{11302}', '\u{11303}', GraphemeBreak::SpacingMark),
+    ('\u{1133B}', '\u{1133C}', GraphemeBreak::Extend),
+    ('\u{1133E}', '\u{1133E}', GraphemeBreak::Extend),
+    ('\u{1133F}', '\u{1133F}', GraphemeBreak::SpacingMark),
+    ('\u{11340}', '\u{11340}', GraphemeBreak::Extend),
+    ('\u{11341}', '\u{11344}', GraphemeBreak::SpacingMark),
+    ('\u{11347}', '\u{11348}', GraphemeBreak::SpacingMark),
+    ('\u{1134B}', '\u{1134D}', GraphemeBreak::SpacingMark),
+    ('\u{11357}', '\u{11357}', GraphemeBreak::Extend),
+    ('\u{11362}', '\u{11363}', GraphemeBreak::SpacingMark),
+    ('\u{11366}', '\u{1136C}', GraphemeBreak::Extend),
+    ('\u{11370}', '\u{11374}', GraphemeBreak::Extend),
+    ('\u{11435}', '\u{11437}', GraphemeBreak::SpacingMark),
+    ('\u{11438}', '\u{1143F}', GraphemeBreak::Extend),
+    ('\u{11440}', '\u{11441}', GraphemeBreak::SpacingMark),
+    ('\u{11442}', '\u{11444}', GraphemeBreak::Extend),
+    ('\u{11445}', '\u{11445}', GraphemeBreak::SpacingMark),
+    ('\u{11446}', '\u{11446}', GraphemeBreak::Extend),
+    ('\u{1145E}', '\u{1145E}', GraphemeBreak::Extend),
+    ('\u{114B0}', '\u{114B0}', GraphemeBreak::Extend),
+    ('\u{114B1}', '\u{114B2}', GraphemeBreak::SpacingMark),
+    ('\u{114B3}', '\u{114B8}', GraphemeBreak::Extend),
+    ('\u{114B9}', '\u{114B9}', GraphemeBreak::SpacingMark),
+    ('\u{114BA}', '\u{114BA}', GraphemeBreak::Extend),
+    ('\u{114BB}', '\u{114BC}', GraphemeBreak::SpacingMark),
+    ('\u{114BD}', '\u{114BD}', GraphemeBreak::Extend),
+    ('\u{114BE}', '\u{114BE}', GraphemeBreak::SpacingMark),
+    ('\u{114BF}', '\u{114C0}', GraphemeBreak::Extend),
+    ('\u{114C1}', '\u{114C1}', GraphemeBreak::SpacingMark),
+    ('\u{114C2}', '\u{114C3}', GraphemeBreak::Extend),
+    ('\u{115AF}', '\u{115AF}', GraphemeBreak::Extend),
+    ('\u{115B0}', '\u{115B1}', GraphemeBreak::SpacingMark),
+    ('\u{115B2}', '\u{115B5}', GraphemeBreak::Extend),
+    ('\u{115B8}', '\u{115BB}', GraphemeBreak::SpacingMark),
+    ('\u{115BC}', '\u{115BD}', GraphemeBreak::Extend),
+    ('\u{115BE}', '\u{115BE}', GraphemeBreak::SpacingMark),
+    ('\u{115BF}', '\u{115C0}', GraphemeBreak::Extend),
+    ('\u{115DC}', '\u{115DD}', GraphemeBreak::Extend),
+    ('\u{11630}', '\u{11632}', GraphemeBreak::SpacingMark),
+    ('\u{11633}', '\u{1163A}', GraphemeBreak::Extend),
+    ('\u{1163B}', '\u{1163C}', GraphemeBreak::SpacingMark),
+    ('\u{1163D}', '\u{1163D}', GraphemeBreak::Extend),
+    ('\u{1163E}', '\u{1163E}', GraphemeBreak::SpacingMark),
+    ('\u{1163F}', '\u{11640}', GraphemeBreak::Extend),
+    ('\u{116AB}', '\u{116AB}', GraphemeBreak::Extend),
+    ('\u{116AC}', '\u{116AC}', GraphemeBreak::SpacingMark),
+    ('\u{116AD}', '\u{116AD}', GraphemeBreak::Extend),
+    ('\u{116AE}', '\u{116AF}', GraphemeBreak::SpacingMark),
+    ('\u{116B0}', '\u{116B5}', GraphemeBreak::Extend),
+    ('\u{116B6}', '\u{116B6}', GraphemeBreak::SpacingMark),
+    ('\u{116B7}', '\u{116B7}', GraphemeBreak::Extend),
+    ('\u{1171D}', '\u{1171F}', GraphemeBreak::Extend),
+    ('\u{11722}', '\u{11725}', GraphemeBreak::Extend),
+    ('\u{11726}', '\u{11726}', GraphemeBreak::SpacingMark),
+    ('\u{11727}', '\u{1172B}', GraphemeBreak::Extend),
+    ('\u{1182C}', '\u{1182E}', GraphemeBreak::SpacingMark),
+    ('\u{1182F}', '\u{11837}', GraphemeBreak::Extend),
+    ('\u{11838}', '\u{11838}', GraphemeBreak::SpacingMark),
+    ('\u{11839}', '\u{1183A}', GraphemeBreak::Extend),
+    ('\u{11930}', '\u{11930}', GraphemeBreak::Extend),
+    ('\u{11931}', '\u{11935}', GraphemeBreak::SpacingMark),
+    ('\u{11937}', '\u{11938}', GraphemeBreak::SpacingMark),
+    ('\u{1193B}', '\u{1193C}', GraphemeBreak::Extend),
+    ('\u{1193D}', '\u{1193D}', GraphemeBreak::SpacingMark),
+    ('\u{1193E}', '\u{1193E}', GraphemeBreak::Extend),
+    ('\u{1193F}', '\u{1193F}', GraphemeBreak::Prepend),
+    ('\u{11940}', '\u{11940}', GraphemeBreak::SpacingMark),
+    ('\u{11941}', '\u{11941}', GraphemeBreak::Prepend),
+    ('\u{11942}', '\u{11942}', GraphemeBreak::SpacingMark),
+    ('\u{11943}', '\u{11943}', GraphemeBreak::Extend),
+    ('\u{119D1}', '\u{119D3}', GraphemeBreak::SpacingMark),
+    ('\u{119D4}', '\u{119D7}', GraphemeBreak::Extend),
+    ('\u{119DA}', '\u{119DB}', GraphemeBreak::Extend),
+    ('\u{119DC}', '\u{119DF}', GraphemeBreak::SpacingMark),
+    ('\u{119E0}', '\u{119E0}', GraphemeBreak::Extend),
+    ('\u{119E4}', '\u{119E4}', GraphemeBreak::SpacingMark),
+    ('\u{11A01}', '\u{11A0A}', GraphemeBreak::Extend),
+    ('\u{11A33}', '\u{11A38}', GraphemeBreak::Extend),
+    ('\u{11A39}', '\u{11A39}', GraphemeBreak::SpacingMark),
+    ('\u{11A3A}', '\u{11A3A}', GraphemeBreak::Prepend),
+    ('\u{11A3B}', '\u{11A3E}', GraphemeBreak::Extend),
+    ('\u{11A47}', '\u{11A47}', GraphemeBreak::Extend),
+    ('\u{11A51}', '\u{11A56}', GraphemeBreak::Extend),
+    ('\u{11A57}', '\u{11A58}', GraphemeBreak::SpacingMark),
+    ('\u{11A59}', '\u{11A5B}', GraphemeBreak::Extend),
+    ('\u{11A84}', '\u{11A89}', GraphemeBreak::Prepend),
+    ('\u{11A8A}', '\u{11A96}', GraphemeBreak::Extend),
+    ('\u{11A97}', '\u{11A97}', GraphemeBreak::SpacingMark),
+    ('\u{11A98}', '\u{11A99}', GraphemeBreak::Extend),
+    ('\u{11C2F}', '\u{11C2F}', GraphemeBreak::SpacingMark),
+    ('\u{11C30}', '\u{11C36}', GraphemeBreak::Extend),
+    ('\u{11C38}', '\u{11C3D}', GraphemeBreak::Extend),
+    ('\u{11C3E}', '\u{11C3E}', GraphemeBreak::SpacingMark),
+    ('\u{11C3F}', '\u{11C3F}', GraphemeBreak::Extend),
+    ('\u{11C92}', '\u{11CA7}', GraphemeBreak::Extend),
+    ('\u{11CA9}', '\u{11CA9}', GraphemeBreak::SpacingMark),
+    ('\u{11CAA}', '\u{11CB0}', GraphemeBreak::Extend),
+    ('\u{11CB1}', '\u{11CB1}', GraphemeBreak::SpacingMark),
+    ('\u{11CB2}', '\u{11CB3}', GraphemeBreak::Extend),
+    ('\u{11CB4}', '\u{11CB4}', GraphemeBreak::SpacingMark),
+    ('\u{11CB5}', '\u{11CB6}', GraphemeBreak::Extend),
+    ('\u{11D31}', '\u{11D36}', GraphemeBreak::Extend),
+    ('\u{11D3A}', '\u{11D3A}', GraphemeBreak::Extend),
+    ('\u{11D3C}', '\u{11D3D}', GraphemeBreak::Extend),
+    ('\u{11D3F}', '\u{11D45}', GraphemeBreak::Extend),
+    ('\u{11D46}', '\u{11D46}', GraphemeBreak::Prepend),
+    ('\u{11D47}', '\u{11D47}', GraphemeBreak::Extend),
+    ('\u{11D8A}', '\u{11D8E}', GraphemeBreak::SpacingMark),
+    ('\u{11D90}', '\u{11D91}', GraphemeBreak::Extend),
+    ('\u{11D93}', '\u{11D94}', GraphemeBreak::SpacingMark),
+    ('\u{11D95}', '\u{11D95}', GraphemeBreak::Extend),
+    ('\u{11D96}', '\u{11D96}', GraphemeBreak::SpacingMark),
+    ('\u{11D97}', '\u{11D97}', GraphemeBreak::Extend),
+    ('\u{11EF3}', '\u{11EF4}', GraphemeBreak::Extend),
+    ('\u{11EF5}', '\u{11EF6}', GraphemeBreak::SpacingMark),
+    ('\u{13430}', '\u{13438}', GraphemeBreak::Control),
+    ('\u{16AF0}', '\u{16AF4}', GraphemeBreak::Extend),
+    ('\u{16B30}', '\u{16B36}', GraphemeBreak::Extend),
+    ('\u{16F4F}', '\u{16F4F}', GraphemeBreak::Extend),
+    ('\u{16F51}', '\u{16F87}', GraphemeBreak::SpacingMark),
+    ('\u{16F8F}', '\u{16F92}', GraphemeBreak::Extend),
+    ('\u{16FE4}', '\u{16FE4}', GraphemeBreak::Extend),
+    ('\u{16FF0}', '\u{16FF1}', GraphemeBreak::SpacingMark),
+    ('\u{1BC9D}', '\u{1BC9E}', GraphemeBreak::Extend),
+    ('\u{1BCA0}', '\u{1BCA3}', GraphemeBreak::Control),
+    ('\u{1CF00}', '\u{1CF2D}', GraphemeBreak::Extend),
+    ('\u{1CF30}', '\u{1CF46}', GraphemeBreak::Extend),
+    ('\u{1D165}', '\u{1D165}', GraphemeBreak::Extend),
+    ('\u{1D166}', '\u{1D166}', GraphemeBreak::SpacingMark),
+    ('\u{1D167}', '\u{1D169}', GraphemeBreak::Extend),
+    ('\u{1D16D}', '\u{1D16D}', GraphemeBreak::SpacingMark),
+    ('\u{1D16E}', '\u{1D172}', GraphemeBreak::Extend),
+    ('\u{1D173}', '\u{1D17A}', GraphemeBreak::Control),
+    ('\u{1D17B}', '\u{1D182}', GraphemeBreak::Extend),
+    ('\u{1D185}', '\u{1D18B}', GraphemeBreak::Extend),
+    ('\u{1D1AA}', '\u{1D1AD}', GraphemeBreak::Extend),
+    ('\u{1D242}', '\u{1D244}', GraphemeBreak::Extend),
+    ('\u{1DA00}', '\u{1DA36}', GraphemeBreak::Extend),
+    ('\u{1DA3B}', '\u{1DA6C}', GraphemeBreak::Extend),
+    ('\u{1DA75}', '\u{1DA75}', GraphemeBreak::Extend),
+    ('\u{1DA84}', '\u{1DA84}', GraphemeBreak::Extend),
+    ('\u{1DA9B}', '\u{1DA9F}', GraphemeBreak::Extend),
+    ('\u{1DAA1}', '\u{1DAAF}', GraphemeBreak::Extend),
+    ('\u{1E000}', '\u{1E006}', GraphemeBreak::Extend),
+    ('\u{1E008}', '\u{1E018}', GraphemeBreak::Extend),
+    ('\u{1E01B}', '\u{1E021}', GraphemeBreak::Extend),
+    ('\u{1E023}', '\u{1E024}', GraphemeBreak::Extend),
+    ('\u{1E026}', '\u{1E02A}', GraphemeBreak::Extend),
+    ('\u{1E130}', '\u{1E136}', GraphemeBreak::Extend),
+    ('\u{1E2AE}', '\u{1E2AE}', GraphemeBreak::Extend),
+    ('\u{1E2EC}', '\u{1E2EF}', GraphemeBreak::Extend),
+    ('\u{1E8D0}', '\u{1E8D6}', GraphemeBreak::Extend),
+    ('\u{1E944}', '\u{1E94A}', GraphemeBreak::Extend),
+    ('\u{1F1E6}', '\u{1F1FF}', GraphemeBreak::RegionalIndicator),
+    ('\u{1F3FB}', '\u{1F3FF}', GraphemeBreak::Extend),
+    ('\u{E0000}', '\u{E001F}', GraphemeBreak::Control),
+    ('\u{E0020}', '\u{E007F}', GraphemeBreak::Extend),
+    ('\u{E0080}', '\u{E00FF}', GraphemeBreak::Control),
+    ('\u{E0100}', '\u{E01EF}', GraphemeBreak::Extend),
+    ('\u{E01F0}', '\u{E0FFF}', GraphemeBreak::Control),
+];
+
+pub(crate) const WORD_BREAK: &[(char, char, WordBreak)] = &[
+    ('\u{A}', '\u{A}', WordBreak::LF),
+    ('\u{B}', '\u{C}', WordBreak::Newline),
+    ('\u{D}', '\u{D}', WordBreak::CR),
+    ('\u{20}', '\u{20}', WordBreak::WSegSpace),
+    ('\u{22}', '\u{22}', WordBreak::DoubleQuote),
+    ('\u{27}', '\u{27}', WordBreak::SingleQuote),
+    ('\u{2C}', '\u{2C}', WordBreak::MidNum),
+    ('\u{2E}', '\u{2E}', WordBreak::MidNumLet),
+    ('\u{30}', '\u{39}', WordBreak::Numeric),
+    ('\u{3A}', '\u{3A}', WordBreak::MidLetter),
+    ('\u{3B}', '\u{3B}', WordBreak::MidNum),
+    ('\u{41}', '\u{5A}', WordBreak::ALetter),
+    ('\u{5F}', '\u{5F}', WordBreak::ExtendNumLet),
+    ('\u{61}', '\u{7A}', WordBreak::ALetter),
+    ('\u{85}', '\u{85}', WordBreak::Newline),
+    ('\u{AA}', '\u{AA}', WordBreak::ALetter),
+    ('\u{AD}', '\u{AD}', WordBreak::Format),
+    ('\u{B5}', '\u{B5}', WordBreak::ALetter),
+    ('\u{B7}', '\u{B7}', WordBreak::MidLetter),
+    ('\u{BA}', '\u{BA}', WordBreak::ALetter),
+    ('\u{C0}', '\u{D6}', WordBreak::ALetter),
+    ('\u{D8}', '\u{F6}', WordBreak::ALetter),
+    ('\u{F8}', '\u{2D7}', WordBreak::ALetter),
+    ('\u{2DE}', '\u{2FF}', WordBreak::ALetter),
+    ('\u{300}', '\u{36F}', WordBreak::Extend),
+    ('\u{370}', '\u{374}', WordBreak::ALetter),
+    ('\u{376}', '\u{377}', WordBreak::ALetter),
+    ('\u{37A}', '\u{37D}', WordBreak::ALetter),
+    ('\u{37E}', '\u{37E}', WordBreak::MidNum),
+    ('\u{37F}', '\u{37F}', WordBreak::ALetter),
+    ('\u{386}', '\u{386}', WordBreak::ALetter),
+    ('\u{387}', '\u{387}', WordBreak::MidLetter),
+    ('\u{388}', '\u{38A}', WordBreak::ALetter),
+    ('\u{38C}', '\u{38C}', WordBreak::ALetter),
+    ('\u{38E}', '\u{3A1}', WordBreak::ALetter),
+    ('\u{3A3}', '\u{3F5}', WordBreak::ALetter),
+    ('\u{3F7}', '\u{481}', WordBreak::ALetter),
+    ('\u{483}', '\u{489}', WordBreak::Extend),
+    ('\u{48A}', '\u{52F}', WordBreak::ALetter),
+    ('\u{531}', '\u{556}', WordBreak::ALetter),
+    ('\u{559}', '\u{55C}', WordBreak::ALetter),
+    ('\u{55E}', '\u{55E}', WordBreak::ALetter),
+    ('\u{55F}', '\u{55F}', WordBreak::MidLetter),
+    ('\u{560}', '\u{588}', WordBreak::ALetter),
+    ('\u{589}', '\u{589}', WordBreak::MidNum),
+    ('\u{58A}', '\u{58A}', WordBreak::ALetter),
+    ('\u{591}', '\u{5BD}', WordBreak::Extend),
+    ('\u{5BF}', '\u{5BF}', WordBreak::Extend),
+    ('\u{5C1}', '\u{5C2}', WordBreak::Extend),
+    ('\u{5C4}', '\u{5C5}', WordBreak::Extend),
+    ('\u{5C7}', '\u{5C7}', WordBreak::Extend),
+    ('\u{5D0}', '\u{5EA}', WordBreak::HebrewLetter),
+    ('\u{5EF}', '\u{5F2}', WordBreak::HebrewLetter),
+    ('\u{5F3}', '\u{5F3}', WordBreak::ALetter),
+    ('\u{5F4}', '\u{5F4}', WordBreak::MidLetter),
+    ('\u{600}', '\u{605}', WordBreak::Format),
+    ('\u{60C}', '\u{60D}', WordBreak::MidNum),
+    ('\u{610}', '\u{61A}', WordBreak::Extend),
+    ('\u{61C}', '\u{61C}', WordBreak::Format),
+    ('\u{620}', '\u{64A}', WordBreak::ALetter),
+    ('\u{64B}', '\u{65F}', WordBreak::Extend),
+    ('\u{660}', '\u{669}', WordBreak::Numeric),
+    ('\u{66B}', '\u{66B}', WordBreak::Numeric),
+    ('\u{66C}', '\u{66C}', WordBreak::MidNum),
+    ('\u{66E}', '\u{66F}', WordBreak::ALetter),
+    ('\u{670}', '\u{670}', WordBreak::Extend),
+    ('\u{671}', '\u{6D3}', WordBreak::ALetter),
+    ('\u{6D5}', '\u{6D5}', WordBreak::ALetter),
+    ('\u{6D6}', '\u{6DC}', WordBreak::Extend),
+    ('\u{6DD}', '\u{6DD}', WordBreak::Format),
+    ('\u{6DF}', '\u{6E4}', WordBreak::Extend),
+    ('\u{6E5}', '\u{6E6}', WordBreak::ALetter),
+    ('\u{6E7}', '\u{6E8}', WordBreak::Extend),
+    ('\u{6EA}', '\u{6ED}', WordBreak::Extend),
+    ('\u{6EE}', '\u{6EF}', WordBreak::ALetter),
+    ('\u{6F0}', '\u{6F9}', WordBreak::Numeric),
+    ('\u{6FA}', '\u{6FC}', WordBreak::ALetter),
+    ('\u{6FF}', '\u{6FF}', WordBreak::ALetter),
+    ('\u{70F}', '\u{70F}', WordBreak::Format),
+    ('\u{710}', '\u{710}', WordBreak::ALetter),
+    ('\u{711}', '\u{711}', WordBreak::Extend),
+    ('\u{712}', '\u{72F}', WordBreak::ALetter),
+    ('\u{730}', '\u{74A}', WordBreak::Extend),
+    ('\u{74D}', '\u{7A5}', WordBreak::ALetter),
+    ('\u{7A6}', '\u{7B0}', WordBreak::Extend),
+    ('\u{7B1}', '\u{7B1}', WordBreak::ALetter),
+    ('\u{7C0}', '\u{7C9}', WordBreak::Numeric),
+    ('\u{7CA}', '\u{7EA}', WordBreak::ALetter),
+    ('\u{7EB}', '\u{7F3}', WordBreak::Extend),
+    ('\u{7F4}', '\u{7F5}', WordBreak::ALetter),
+    ('\u{7F8}', '\u{7F8}', WordBreak::MidNum),
+    ('\u{7FA}', '\u{7FA}', WordBreak::ALetter),
+    ('\u{7FD}', '\u{7FD}', WordBreak::Extend),
+    ('\u{800}', '\u{815}', WordBreak::ALetter),
+    ('\u{816}', '\u{819}', WordBreak::Extend),
+    ('\u{81A}', '\u{81A}', WordBreak::ALetter),
+    ('\u{81B}', '\u{823}', WordBreak::Extend),
+    ('\u{824}', '\u{824}', WordBreak::ALetter),
+    ('\u{825}', '\u{827}', WordBreak::Extend),
+    ('\u{828}', '\u{828}', WordBreak::ALetter),
+    ('\u{829}', '\u{82D}', WordBreak::Extend),
+    ('\u{840}', '\u{858}', WordBreak::ALetter),
+    ('\u{859}', '\u{85B}', WordBreak::Extend),
+    ('\u{860}', '\u{86A}', WordBreak::ALetter),
+    ('\u{870}', '\u{887}', WordBreak::ALetter),
+    ('\u{889}', '\u{88E}', WordBreak::ALetter),
+    ('\u{890}', '\u{891}', WordBreak::Format),
+    ('\u{898}', '\u{89F}', WordBreak::Extend),
+    ('\u{8A0}', '\u{8C9}', WordBreak::ALetter),
+    ('\u{8CA}', '\u{8E1}', WordBreak::Extend),
+    ('\u{8E2}', '\u{8E2}', WordBreak::Format),
+    ('\u{8E3}', '\u{903}', WordBreak::Extend),
+    ('\u{904}', '\u{939}', WordBreak::ALetter),
+    ('\u{93A}', '\u{93C}', WordBreak::Extend),
+    ('\u{93D}', '\u{93D}', WordBreak::ALetter),
+    ('\u{93E}', '\u{94F}', WordBreak::Extend),
+    ('\u{950}', '\u{950}', WordBreak::ALetter),
+    ('\u{951}', '\u{957}', WordBreak::Extend),
+    ('\u{958}', '\u{961}', WordBreak::ALetter),
+    ('\u{962}', '\u{963}', WordBreak::Extend),
+    ('\u{966}', '\u{96F}', WordBreak::Numeric),
+    ('\u{971}', '\u{980}', WordBreak::ALetter),
+    ('\u{981}', '\u{983}', WordBreak::Extend),
+    ('\u{985}', '\u{98C}', WordBreak::ALetter),
+    ('\u{98F}', '\u{990}', WordBreak::ALetter),
+    ('\u{993}', '\u{9A8}', WordBreak::ALetter),
+    ('\u{9AA}', '\u{9B0}', WordBreak::ALetter),
+    ('\u{9B2}', '\u{9B2}', WordBreak::ALetter),
+    ('\u{9B6}', '\u{9B9}', WordBreak::ALetter),
+    ('\u{9BC}', '\u{9BC}', WordBreak::Extend),
+    ('\u{9BD}', '\u{9BD}', WordBreak::ALetter),
+    ('\u{9BE}', '\u{9C4}', WordBreak::Extend),
+    ('\u{9C7}', '\u{9C8}', WordBreak::Extend),
+    ('\u{9CB}', '\u{9CD}', WordBreak::Extend),
+    ('\u{9CE}', '\u{9CE}', WordBreak::ALetter),
+    ('\u{9D7}', '\u{9D7}', WordBreak::Extend),
+    ('\u{9DC}', '\u{9DD}', WordBreak::ALetter),
+    ('\u{9DF}', '\u{9E1}', WordBreak::ALetter),
+    ('\u{9E2}', '\u{9E3}', WordBreak::Extend),
+    ('\u{9E6}', '\u{9EF}', WordBreak::Numeric),
+    ('\u{9F0}', '\u{9F1}', WordBreak::ALetter),
+    ('\u{9FC}', '\u{9FC}', WordBreak::ALetter),
+    ('\u{9FE}', '\u{9FE}', WordBreak::Extend),
+    ('\u{A01}', '\u{A03}', WordBreak::Extend),
+    ('\u{A05}', '\u{A0A}', WordBreak::ALetter),
+    ('\u{A0F}', '\u{A10}', WordBreak::ALetter),
+    ('\u{A13}', '\u{A28}', WordBreak::ALetter),
+    ('\u{A2A}', '\u{A30}', WordBreak::ALetter),
+    ('\u{A32}', '\u{A33}', WordBreak::ALetter),
+    ('\u{A35}', '\u{A36}', WordBreak::ALetter),
+    ('\u{A38}', '\u{A39}', WordBreak::ALetter),
+    ('\u{A3C}', '\u{A3C}', WordBreak::Extend),
+    ('\u{A3E}', '\u{A42}', WordBreak::Extend),
+    ('\u{A47}', '\u{A48}', WordBreak::Extend),
+    ('\u{A4B}', '\u{A4D}', WordBreak::Extend),
+    ('\u{A51}', '\u{A51}', WordBreak::Extend),
+    ('\u{A59}', '\u{A5C}', WordBreak::ALetter),
+    ('\u{A5E}', '\u{A5E}', WordBreak::ALetter),
+    ('\u{A66}', '\u{A6F}', WordBreak::Numeric),
+    ('\u{A70}', '\u{A71}', WordBreak::Extend),
+    ('\u{A72}', '\u{A74}', WordBreak::ALetter),
+    ('\u{A75}', '\u{A75}', WordBreak::Extend),
+    ('\u{A81}', '\u{A83}', WordBreak::Extend),
+    ('\u{A85}', '\u{A8D}', WordBreak::ALetter),
+    ('\u{A8F}', '\u{A91}', WordBreak::ALetter),
+    ('\u{A93}', '\u{AA8}', WordBreak::ALetter),
+    ('\u{AAA}', '\u{AB0}', WordBreak::ALetter),
+    ('\u{AB2}', '\u{AB3}', WordBreak::ALetter),
+    ('\u{AB5}', '\u{AB9}', WordBreak::ALetter),
+    ('\u{ABC}', '\u{ABC}', WordBreak::Extend),
+    ('\u{ABD}', '\u{ABD}', WordBreak::ALetter),
+    ('\u{ABE}', '\u{AC5}', WordBreak::Extend),
+    ('\u{AC7}', '\u{AC9}', WordBreak::Extend),
+    ('\u{ACB}', '\u{ACD}', WordBreak::Extend),
+    ('\u{AD0}', '\u{AD0}', WordBreak::ALetter),
+    ('\u{AE0}', '\u{AE1}', WordBreak::ALetter),
+    ('\u{AE2}', '\u{AE3}', WordBreak::Extend),
+    ('\u{AE6}', '\u{AEF}', WordBreak::Numeric),
+    ('\u{AF9}', '\u{AF9}', WordBreak::ALetter),
+    ('\u{AFA}', '\u{AFF}', WordBreak::Extend),
+    ('\u{B01}', '\u{B03}', WordBreak::Extend),
+    ('\u{B05}', '\u{B0C}', WordBreak::ALetter),
+    ('\u{B0F}', '\u{B10}', WordBreak::ALetter),
+    ('\u{B13}', '\u{B28}', WordBreak::ALetter),
+    ('\u{B2A}', '\u{B30}', WordBreak::ALetter),
+    ('\u{B32}', '\u{B33}', WordBreak::ALetter),
+    ('\u{B35}', '\u{B39}', WordBreak::ALetter),
+    ('\u{B3C}', '\u{B3C}', WordBreak::Extend),
+    ('\u{B3D}', '\u{B3D}', WordBreak::ALetter),
+    ('\u{B3E}', '\u{B44}', WordBreak::Extend),
+    ('\u{B47}', '\u{B48}', WordBreak::Extend),
+    ('\u{B4B}', '\u{B4D}', WordBreak::Extend),
+    ('\u{B55}', '\u{B57}', WordBreak::Extend),
+    ('\u{B5C}', '\u{B5D}', WordBreak::ALetter),
+    ('\u{B5F}', '\u{B61}', WordBreak::ALetter),
+    ('\u{B62}', '\u{B63}', WordBreak::Extend),
+    ('\u{B66}', '\u{B6F}', WordBreak::Numeric),
+    ('\u{B71}', '\u{B71}', WordBreak::ALetter),
+    ('\u{B82}', '\u{B82}', WordBreak::Extend),
+    ('\u{B83}', '\u{B83}', WordBreak::ALetter),
+    ('\u{B85}', '\u{B8A}', WordBreak::ALetter),
+    ('\u{B8E}', '\u{B90}', WordBreak::ALetter),
+    ('\u{B92}', '\u{B95}', WordBreak::ALetter),
+    ('\u{B99}', '\u{B9A}', WordBreak::ALetter),
+    ('\u{B9C}', '\u{B9C}', WordBreak::ALetter),
+    ('\u{B9E}', '\u{B9F}', WordBreak::ALetter),
+    ('\u{BA3}', '\u{BA4}', WordBreak::ALetter),
+    ('\u{BA8}', '\u{BAA}', WordBreak::ALetter),
+    ('\u{BAE}', '\u{BB9}', WordBreak::ALetter),
+    ('\u{BBE}', '\u{BC2}', WordBreak::Extend),
+    ('\u{BC6}', '\u{BC8}', WordBreak::Extend),
+    ('\u{BCA}', '\u{BCD}', WordBreak::Extend),
+    ('\u{BD0}', '\u{BD0}', WordBreak::ALetter),
+    ('\u{BD7}', '\u{BD7}', WordBreak::Extend),
+    ('\u{BE6}', '\u{BEF}', WordBreak::Numeric),
+    ('\u{C00}', '\u{C04}', WordBreak::Extend),
+    ('\u{C05}', '\u{C0C}', WordBreak::ALetter),
+    ('\u{C0E}', '\u{C10}', WordBreak::ALetter),
+    ('\u{C12}', '\u{C28}', WordBreak::ALetter),
+    ('\u{C2A}', '\u{C39}', WordBreak::ALetter),
+    ('\u{C3C}', '\u{C3C}', WordBreak::Extend),
+    ('\u{C3D}', '\u{C3D}', WordBreak::ALetter),
+    ('\u{C3E}', '\u{C44}', WordBreak::Extend),
+    ('\u{C46}', '\u{C48}', WordBreak::Extend),
+    ('\u{C4A}', '\u{C4D}', WordBreak::Extend),
+    ('\u{C55}', '\u{C56}', WordBreak::Extend),
+    ('\u{C58}', '\u{C5A}', WordBreak::ALetter),
+    ('\u{C5D}', '\u{C5D}', WordBreak::ALetter),
+    ('\u{C60}', '\u{C61}', WordBreak::ALetter),
+    ('\u{C62}', '\u{C63}', WordBreak::Extend),
+    ('\u{C66}', '\u{C6F}', WordBreak::Numeric),
+    ('\u{C80}', '\u{C80}', WordBreak::ALetter),
+    ('\u{C81}', '\u{C83}', WordBreak::Extend),
+    ('\u{C85}', '\u{C8C}', WordBreak::ALetter),
+    ('\u{C8E}', '\u{C90}', WordBreak::ALetter),
+    ('\u{C92}', '\u{CA8}', WordBreak::ALetter),
+    ('\u{CAA}', '\u{CB3}', WordBreak::ALetter),
+    ('\u{CB5}', '\u{CB9}', WordBreak::ALetter),
+    ('\u{CBC}', '\u{CBC}', WordBreak::Extend),
+    ('\u{CBD}', '\u{CBD}', WordBreak::ALetter),
+    ('\u{CBE}', '\u{CC4}', WordBreak::Extend),
+    ('\u{CC6}', '\u{CC8}', WordBreak::Extend),
+    ('\u{CCA}', '\u{CCD}', WordBreak::Extend),
+    ('\u{CD5}', '\u{CD6}', WordBreak::Extend),
+    ('\u{CDD}', '\u{CDE}', WordBreak::ALetter),
+    ('\u{CE0}', '\u{CE1}', WordBreak::ALetter),
+    ('\u{CE2}', '\u{CE3}', WordBreak::Extend),
+    ('\u{CE6}', '\u{CEF}', WordBreak::Numeric),
+    ('\u{CF1}', '\u{CF2}', WordBreak::ALetter),
+    ('\u{D00}', '\u{D03}', WordBreak::Extend),
+    ('\u{D04}', '\u{D0C}', WordBreak::ALetter),
+    ('\u{D0E}', '\u{D10}', WordBreak::ALetter),
+    ('\u{D12}', '\u{D3A}', WordBreak::ALetter),
+    ('\u{D3B}', '\u{D3C}', WordBreak::Extend),
+    ('\u{D3D}', '\u{D3D}', WordBreak::ALetter),
+    ('\u{D3E}', '\u{D44}', WordBreak::Extend),
+    ('\u{D46}', '\u{D48}', WordBreak::Extend),
+    ('\u{D4A}', '\u{D4D}', WordBreak::Extend),
+    ('\u{D4E}', '\u{D4E}', WordBreak::ALetter),
+    ('\u{D54}', '\u{D56}', WordBreak::ALetter),
+    ('\u{D57}', '\u{D57}', WordBreak::Extend),
+    ('\u{D5F}', '\u{D61}', WordBreak::ALetter),
+    ('\u{D62}', '\u{D63}', WordBreak::Extend),
+    ('\u{D66}', '\u{D6F}', WordBreak::Numeric),
+    ('\u{D7A}', '\u{D7F}', WordBreak::ALetter),
+    ('\u{D81}', '\u{D83}', WordBreak::Extend),
+    ('\u{D85}', '\u{D96}', WordBreak::ALetter),
+    ('\u{D9A}', '\u{DB1}', WordBreak::ALetter),
+    ('\u{DB3}', '\u{DBB}', WordBreak::ALetter),
+    ('\u{DBD}', '\u{DBD}', WordBreak::ALetter),
+    ('\u{DC0}', '\u{DC6}', WordBreak::ALetter),
+    ('\u{DCA}', '\u{DCA}', WordBreak::Extend),
+    ('\u{DCF}', '\u{DD4}', WordBreak::Extend),
+    ('\u{DD6}', '\u{DD6}', WordBreak::Extend),
+    ('\u{DD8}', '\u{DDF}', WordBreak::Extend),
+    ('\u{DE6}', '\u{DEF}', WordBreak::Numeric),
+    ('\u{DF2}', '\u{DF3}', WordBreak::Extend),
+    ('\u{E31}', '\u{E31}', WordBreak::Extend),
+    ('\u{E34}', '\u{E3A}', WordBreak::Extend),
+    ('\u{E47}', '\u{E4E}', WordBreak::Extend),
+    ('\u{E50}', '\u{E59}', WordBreak::Numeric),
+    ('\u{EB1}', '\u{EB1}', WordBreak::Extend),
+    ('\u{EB4}', '\u{EBC}', WordBreak::Extend),
+    ('\u{EC8}', '\u{ECD}', WordBreak::Extend),
+    ('\u{ED0}', '\u{ED9}', WordBreak::Numeric),
+    ('\u{F00}', '\u{F00}', WordBreak::ALetter),
+    ('\u{F18}', '\u{F19}', WordBreak::Extend),
+    ('\u{F20}', '\u{F29}', WordBreak::Numeric),
+    ('\u{F35}', '\u{F35}', WordBreak::Extend),
+    ('\u{F37}', '\u{F37}', WordBreak::Extend),
+    ('\u{F39}', '\u{F39}', WordBreak::Extend),
+    ('\u{F3E}', '\u{F3F}', WordBreak::Extend),
+    ('\u{F40}', '\u{F47}', WordBreak::ALetter),
+    ('\u{F49}', '\u{F6C}', WordBreak::ALetter),
+    ('\u{F71}', '\u{F84}', WordBreak::Extend),
+    ('\u{F86}', '\u{F87}', WordBreak::Extend),
+    ('\u{F88}', '\u{F8C}', WordBreak::ALetter),
+    ('\u{F8D}', '\u{F97}', WordBreak::Extend),
+    ('\u{F99}', '\u{FBC}', WordBreak::Extend),
+    ('\u{FC6}', '\u{FC6}', WordBreak::Extend),
+    ('\u{102B}', '\u{103E}', WordBreak::Extend),
+    ('\u{1040}', '\u{1049}', WordBreak::Numeric),
+    ('\u{1056}', '\u{1059}', WordBreak::Extend),
+    ('\u{105E}', '\u{1060}', WordBreak::Extend),
+    ('\u{1062}', '\u{1064}', WordBreak::Extend),
+    ('\u{1067}', '\u{106D}', WordBreak::Extend),
+    ('\u{1071}', '\u{1074}', WordBreak::Extend),
+    ('\u{1082}', '\u{108D}', WordBreak::Extend),
+    ('\u{108F}', '\u{108F}', WordBreak::Extend),
+    ('\u{1090}', '\u{1099}', WordBreak::Numeric),
+    ('\u{109A}', '\u{109D}', WordBreak::Extend),
+    ('\u{10A0}', '\u{10C5}', WordBreak::ALetter),
+    ('\u{10C7}', '\u{10C7}', WordBreak::ALetter),
+    ('\u{10CD}', '\u{10CD}', WordBreak::ALetter),
+    ('\u{10D0}', '\u{10FA}', WordBreak::ALetter),
+    ('\u{10FC}', '\u{1248}', WordBreak::ALetter),
+    ('\u{124A}', '\u{124D}', WordBreak::ALetter),
+    ('\u{1250}', '\u{1256}', WordBreak::ALetter),
+    ('\u{1258}', '\u{1258}', WordBreak::ALetter),
+    ('\u{125A}', '\u{125D}', WordBreak::ALetter),
+    ('\u{1260}', '\u{1288}', WordBreak::ALetter),
+    ('\u{128A}', '\u{128D}', WordBreak::ALetter),
+    ('\u{1290}', '\u{12B0}', WordBreak::ALetter),
+    ('\u{12B2}', '\u{12B5}', WordBreak::ALetter),
+    ('\u{12B8}', '\u{12BE}', WordBreak::ALetter),
+    ('\u{12C0}', '\u{12C0}', WordBreak::ALetter),
+    ('\u{12C2}', '\u{12C5}', WordBreak::ALetter),
+    ('\u{12C8}', '\u{12D6}', WordBreak::ALetter),
+    ('\u{12D8}', '\u{1310}', WordBreak::ALetter),
+    ('\u{1312}', '\u{1315}', WordBreak::ALetter),
+    ('\u{1318}', '\u{135A}', WordBreak::ALetter),
+    ('\u{135D}', '\u{135F}', WordBreak::Extend),
+    ('\u{1380}', '\u{138F}', WordBreak::ALetter),
+    ('\u{13A0}', '\u{13F5}', WordBreak::ALetter),
+    ('\u{13F8}', '\u{13FD}', WordBreak::ALetter),
+    ('\u{1401}', '\u{166C}', WordBreak::ALetter),
+    ('\u{166F}', '\u{167F}', WordBreak::ALetter),
+    ('\u{1680}', '\u{1680}', WordBreak::WSegSpace),
+    ('\u{1681}', '\u{169A}', WordBreak::ALetter),
+    ('\u{16A0}', '\u{16EA}', WordBreak::ALetter),
+    ('\u{16EE}', '\u{16F8}', WordBreak::ALetter),
+    ('\u{1700}', '\u{1711}', WordBreak::ALetter),
+    ('\u{1712}', '\u{1715}', WordBreak::Extend),
+    ('\u{171F}', '\u{1731}', WordBreak::ALetter),
+    ('\u{1732}', '\u{1734}', WordBreak::Extend),
+    ('\u{1740}', '\u{1751}', WordBreak::ALetter),
+    ('\u{1752}', '\u{1753}', WordBreak::Extend),
+    ('\u{1760}', '\u{176C}', WordBreak::ALetter),
+    ('\u{176E}', '\u{1770}', WordBreak::ALetter),
+    ('\u{1772}', '\u{1773}', WordBreak::Extend),
+    ('\u{17B4}', '\u{17D3}', WordBreak::Extend),
+    ('\u{17DD}', '\u{17DD}', WordBreak::Extend),
+    ('\u{17E0}', '\u{17E9}', WordBreak::Numeric),
+    ('\u{180B}', '\u{180D}', WordBreak::Extend),
+    ('\u{180E}', '\u{180E}', WordBreak::Format),
+    ('\u{180F}', '\u{180F}', WordBreak::Extend),
+    ('\u{1810}', '\u{1819}', WordBreak::Numeric),
+    ('\u{1820}', '\u{1878}', WordBreak::ALetter),
+    ('\u{1880}', '\u{1884}', WordBreak::ALetter),
+    ('\u{1885}', '\u{1886}', WordBreak::Extend),
+    ('\u{1887}', '\u{18A8}', WordBreak::ALetter),
+    ('\u{18A9}', '\u{18A9}', WordBreak::Extend),
+    ('\u{18AA}', '\u{18AA}', WordBreak::ALetter),
+    ('\u{18B0}', '\u{18F5}', WordBreak::ALetter),
+    ('\u{1900}', '\u{191E}', WordBreak::ALetter),
+    ('\u{1920}', '\u{192B}', WordBreak::Extend),
+    ('\u{1930}', '\u{193B}', WordBreak::Extend),
+    ('\u{1946}', '\u{194F}', WordBreak::Numeric),
+    ('\u{19D0}', '\u{19D9}', WordBreak::Numeric),
+    ('\u{1A00}', '\u{1A16}', WordBreak::ALetter),
+    ('\u{1A17}', '\u{1A1B}', WordBreak::Extend),
+    ('\u{1A55}', '\u{1A5E}', WordBreak::Extend),
+    ('\u{1A60}', '\u{1A7C}', WordBreak::Extend),
+    ('\u{1A7F}', '\u{1A7F}', WordBreak::Extend),
+    ('\u{1A80}', '\u{1A89}', WordBreak::Numeric),
+    ('\u{1A90}', '\u{1A99}', WordBreak::Numeric),
+    ('\u{1AB0}', '\u{1ACE}', WordBreak::Extend),
+    ('\u{1B00}', '\u{1B04}', WordBreak::Extend),
+    ('\u{1B05}', '\u{1B33}', WordBreak::ALetter),
+    ('\u{1B34}', '\u{1B44}', WordBreak::Extend),
+    ('\u{1B45}', '\u{1B4C}', WordBreak::ALetter),
+    ('\u{1B50}', '\u{1B59}', WordBreak::Numeric),
+    ('\u{1B6B}', '\u{1B73}', WordBreak::Extend),
+    ('\u{1B80}', '\u{1B82}', WordBreak::Extend),
+    ('\u{1B83}', '\u{1BA0}', WordBreak::ALetter),
+    ('\u{1BA1}', '\u{1BAD}', WordBreak::Extend),
+    ('\u{1BAE}', '\u{1BAF}', WordBreak::ALetter),
+    ('\u{1BB0}', '\u{1BB9}', WordBreak::Numeric),
+    ('\u{1BBA}', '\u{1BE5}', WordBreak::ALetter),
+    ('\u{1BE6}', '\u{1BF3}', WordBreak::Extend),
+    ('\u{1C00}', '\u{1C23}', WordBreak::ALetter),
+    ('\u{1C24}', '\u{1C37}', WordBreak::Extend),
+    ('\u{1C40}', '\u{1C49}', WordBreak::Numeric),
+    ('\u{1C4D}', '\u{1C4F}', WordBreak::ALetter),
+    ('\u{1C50}', '\u{1C59}', WordBreak::Numeric),
+    ('\u{1C5A}', '\u{1C7D}', WordBreak::ALetter),
+    ('\u{1C80}', '\u{1C88}', WordBreak::ALetter),
+    ('\u{1C90}', '\u{1CBA}', WordBreak::ALetter),
+    ('\u{1CBD}', '\u{1CBF}', WordBreak::ALetter),
+    ('\u{1CD0}', '\u{1CD2}', WordBreak::Extend),
+    ('\u{1CD4}', '\u{1CE8}', WordBreak::Extend),
+    ('\u{1CE9}', '\u{1CEC}', WordBreak::ALetter),
+    ('\u{1CED}', '\u{1CED}', WordBreak::Extend),
+    ('\u{1CEE}', '\u{1CF3}', WordBreak::ALetter),
+    ('\u{1CF4}', '\u{1CF4}', WordBreak::Extend),
+    ('\u{1CF5}', '\u{1CF6}', WordBreak::ALetter),
+    ('\u{1CF7}', '\u{1CF9}', WordBreak::Extend),
+    ('\u{1CFA}', '\u{1CFA}', WordBreak::ALetter),
+    ('\u{1D00}', '\u{1DBF}', WordBreak::ALetter),
+    ('\u{1DC0}', '\u{1DFF}', WordBreak::Extend),
+    ('\u{1E00}', '\u{1F15}', WordBreak::ALetter),
+    ('\u{1F18}', '\u{1F1D}', WordBreak::ALetter),
+    ('\u{1F20}', '\u{1F45}', WordBreak::ALetter),
+    ('\u{1F48}', '\u{1F4D}', WordBreak::ALetter),
+    ('\u{1F50}', '\u{1F57}', WordBreak::ALetter),
+    ('\u{1F59}', '\u{1F59}', WordBreak::ALetter),
+    ('\u{1F5B}', '\u{1F5B}', WordBreak::ALetter),
+    ('\u{1F5D}', '\u{1F5D}', WordBreak::ALetter),
+    ('\u{1F5F}', '\u{1F7D}', WordBreak::ALetter),
+    ('\u{1F80}', '\u{1FB4}', WordBreak::ALetter),
+    ('\u{1FB6}', '\u{1FBC}', WordBreak::ALetter),
+    ('\u{1FBE}', '\u{1FBE}', WordBreak::ALetter),
+    ('\u{1FC2}', '\u{1FC4}', WordBreak::ALetter),
+    ('\u{1FC6}', '\u{1FCC}', WordBreak::ALetter),
+    ('\u{1FD0}', '\u{1FD3}', WordBreak::ALetter),
+    ('\u{1FD6}', '\u{1FDB}', WordBreak::ALetter),
+    ('\u{1FE0}', '\u{1FEC}', WordBreak::ALetter),
+    ('\u{1FF2}', '\u{1FF4}', WordBreak::ALetter),
+    ('\u{1FF6}', '\u{1FFC}', WordBreak::ALetter),
+    ('\u{2000}', '\u{2006}', WordBreak::WSegSpace),
+    ('\u{2008}', '\u{200A}', WordBreak::WSegSpace),
+    ('\u{200C}', '\u{200C}', WordBreak::Extend),
+    ('\u{200D}', '\u{200D}', WordBreak::Zwj),
+    ('\u{200E}', '\u{200F}', WordBreak::Format),
+    ('\u{2018}', '\u{2019}', WordBreak::MidNumLet),
+    ('\u{2024}', '\u{2024}', WordBreak::MidNumLet),
+    ('\u{2027}', '\u{2027}', WordBreak::MidLetter),
+    ('\u{2028}', '\u{2029}', WordBreak::Newline),
+    ('\u{202A}', '\u{202E}', WordBreak::Format),
+    ('\u{202F}', '\u{202F}', WordBreak::ExtendNumLet),
+    ('\u{203F}', '\u{2040}', WordBreak::ExtendNumLet),
+    ('\u{2044}', '\u{2044}', WordBreak::MidNum),
+    ('\u{2054}', '\u{2054}', WordBreak::ExtendNumLet),
+    ('\u{205F}', '\u{205F}', WordBreak::WSegSpace),
+    ('\u{2060}', '\u{2064}', WordBreak::Format),
+    ('\u{2066}', '\u{206F}', WordBreak::Format),
+    ('\u{2071}', '\u{2071}', WordBreak::ALetter),
+    ('\u{207F}', '\u{207F}', WordBreak::ALetter),
+    ('\u{2090}', '\u{209C}', WordBreak::ALetter),
+    ('\u{20D0}', '\u{20F0}', WordBreak::Extend),
+    ('\u{2102}', '\u{2102}', WordBreak::ALetter),
+    ('\u{2107}', '\u{2107}', WordBreak::ALetter),
+    ('\u{210A}', '\u{2113}', WordBreak::ALetter),
+    ('\u{2115}', '\u{2115}', WordBreak::ALetter),
+    ('\u{2119}', '\u{211D}', WordBreak::ALetter),
+    ('\u{2124}', '\u{2124}', WordBreak::ALetter),
+    ('\u{2126}', '\u{2126}', WordBreak::ALetter),
+    ('\u{2128}', '\u{2128}', WordBreak::ALetter),
+    ('\u{212A}', '\u{212D}', WordBreak::ALetter),
+    ('\u{212F}', '\u{2139}', WordBreak::ALetter),
+    ('\u{213C}', '\u{213F}', WordBreak::ALetter),
+    ('\u{2145}', '\u{2149}', WordBreak::ALetter),
+    ('\u{214E}', '\u{214E}', WordBreak::ALetter),
+    ('\u{2160}', '\u{2188}', WordBreak::ALetter),
+    ('\u{24B6}', '\u{24E9}', WordBreak::ALetter),
+    ('\u{2C00}', '\u{2CE4}', WordBreak::ALetter),
+    ('\u{2CEB}', '\u{2CEE}', WordBreak::ALetter),
+    ('\u{2CEF}', '\u{2CF1}', WordBreak::Extend),
+    ('\u{2CF2}', '\u{2CF3}', WordBreak::ALetter),
+    ('\u{2D00}', '\u{2D25}', WordBreak::ALetter),
+    ('\u{2D27}', '\u{2D27}', WordBreak::ALetter),
+    ('\u{2D2D}', '\u{2D2D}', WordBreak::ALetter),
+    ('\u{2D30}', '\u{2D67}', WordBreak::ALetter),
+    ('\u{2D6F}', '\u{2D6F}', WordBreak::ALetter),
+    ('\u{2D7F}', '\u{2D7F}', WordBreak::Extend),
+    ('\u{2D80}', '\u{2D96}', WordBreak::ALetter),
+    ('\u{2DA0}', '\u{2DA6}', WordBreak::ALetter),
+    ('\u{2DA8}', '\u{2DAE}', WordBreak::ALetter),
+    ('\u{2DB0}', '\u{2DB6}', WordBreak::ALetter),
+    ('\u{2DB8}', '\u{2DBE}', WordBreak::ALetter),
+    ('\u{2DC0}', '\u{2DC6}', WordBreak::ALetter),
+    ('\u{2DC8}', '\u{2DCE}', WordBreak::ALetter),
+    ('\u{2DD0}', '\u{2DD6}', WordBreak::ALetter),
+    ('\u{2DD8}', '\u{2DDE}', WordBreak::ALetter),
+    ('\u{2DE0}', '\u{2DFF}', WordBreak::Extend),
+    ('\u{2E2F}', '\u{2E2F}', WordBreak::ALetter),
+    ('\u{3000}', '\u{3000}', WordBreak::WSegSpace),
+    ('\u{3005}', '\u{3005}', WordBreak::ALetter),
+    ('\u{302A}', '\u{302F}', WordBreak::Extend),
+    ('\u{3031}', '\u{3035}', WordBreak::Katakana),
+    ('\u{303B}', '\u{303C}', WordBreak::ALetter),
+    ('\u{3099}', '\u{309A}', WordBreak::Extend),
+    ('\u{309B}', '\u{309C}', WordBreak::Katakana),
+    ('\u{30A0}', '\u{30FA}', WordBreak::Katakana),
+    ('\u{30FC}', '\u{30FF}', WordBreak::Katakana),
+    ('\u{3105}', '\u{312F}', WordBreak::ALetter),
+    ('\u{3131}', '\u{318E}', WordBreak::ALetter),
+    ('\u{31A0}', '\u{31BF}', WordBreak::ALetter),
+    ('\u{31F0}', '\u{31FF}', WordBreak::Katakana),
+    ('\u{32D0}', '\u{32FE}', WordBreak::Katakana),
+    ('\u{3300}', '\u{3357}', WordBreak::Katakana),
+    ('\u{A000}', '\u{A48C}', WordBreak::ALetter),
+    ('\u{A4D0}', '\u{A4FD}', WordBreak::ALetter),
+    ('\u{A500}', '\u{A60C}', WordBreak::ALetter),
+    ('\u{A610}', '\u{A61F}', WordBreak::ALetter),
+    ('\u{A620}', '\u{A629}', WordBreak::Numeric),
+    ('\u{A62A}', '\u{A62B}', WordBreak::ALetter),
+    ('\u{A640}', '\u{A66E}', WordBreak::ALetter),
+    ('\u{A66F}', '\u{A672}', WordBreak::Extend),
+    ('\u{A674}', '\u{A67D}', WordBreak::Extend),
+    ('\u{A67F}', '\u{A69D}', WordBreak::ALetter),
+    ('\u{A69E}', '\u{A69F}', WordBreak::Extend),
+    ('\u{A6A0}', '\u{A6EF}', WordBreak::ALetter),
+    ('\u{A6F0}', '\u{A6F1}', WordBreak::Extend),
+    ('\u{A708}', '\u{A7CA}', WordBreak::ALetter),
+    ('\u{A7D0}', '\u{A7D1}', WordBreak::ALetter),
+    ('\u{A7D3}', '\u{A7D3}', WordBreak::ALetter),
+    ('\u{A7D5}', '\u{A7D9}', WordBreak::ALetter),
+    ('\u{A7F2}', '\u{A801}', WordBreak::ALetter),
+    ('\u{A802}', '\u{A802}', WordBreak::Extend),
+    ('\u{A803}', '\u{A805}', WordBreak::ALetter),
+    ('\u{A806}', '\u{A806}', WordBreak::Extend),
+    ('\u{A807}', '\u{A80A}', WordBreak::ALetter),
+    ('\u{A80B}', '\u{A80B}', WordBreak::Extend),
+    ('\u{A80C}', '\u{A822}', WordBreak::ALetter),
+    ('\u{A823}', '\u{A827}', WordBreak::Extend),
+    ('\u{A82C}', '\u{A82C}', WordBreak::Extend),
+    ('\u{A840}', '\u{A873}', WordBreak::ALetter),
+    ('\u{A880}', '\u{A881}', WordBreak::Extend),
+    ('\u{A882}', '\u{A8B3}', WordBreak::ALetter),
+    ('\u{A8B4}', '\u{A8C5}', WordBreak::Extend),
+    ('\u{A8D0}', '\u{A8D9}', WordBreak::Numeric),
+    ('\u{A8E0}', '\u{A8F1}', WordBreak::Extend),
+    ('\u{A8F2}', '\u{A8F7}', WordBreak::ALetter),
+    ('\u{A8FB}', '\u{A8FB}', WordBreak::ALetter),
+    ('\u{A8FD}', '\u{A8FE}', WordBreak::ALetter),
+    ('\u{A8FF}', '\u{A8FF}', WordBreak::Extend),
+    ('\u{A900}', '\u{A909}', WordBreak::Numeric),
+    ('\u{A90A}', '\u{A925}', WordBreak::ALetter),
+    ('\u{A926}', '\u{A92D}', WordBreak::Extend),
+    ('\u{A930}', '\u{A946}', WordBreak::ALetter),
+    ('\u{A947}', '\u{A953}', WordBreak::Extend),
+    ('\u{A960}', '\u{A97C}', WordBreak::ALetter),
+    ('\u{A980}', '\u{A983}', WordBreak::Extend),
+    ('\u{A984}', '\u{A9B2}', WordBreak::ALetter),
+    ('\u{A9B3}', '\u{A9C0}', WordBreak::Extend),
+    ('\u{A9CF}', '\u{A9CF}', WordBreak::ALetter),
+    ('\u{A9D0}', '\u{A9D9}', WordBreak::Numeric),
+    ('\u{A9E5}', '\u{A9E5}', WordBreak::Extend),
+    ('\u{A9F0}', '\u{A9F9}', WordBreak::Numeric),
+    ('\u{AA00}', '\u{AA28}', WordBreak::ALetter),
+    ('\u{AA29}', '\u{AA36}', WordBreak::Extend),
+    ('\u{AA40}', '\u{AA42}', WordBreak::ALetter),
+    ('\u{AA43}', '\u{AA43}', WordBreak::Extend),
+    ('\u{AA44}', '\u{AA4B}', WordBreak::ALetter),
+    ('\u{AA4C}', '\u{AA4D}', WordBreak::Extend),
+    ('\u{AA50}', '\u{AA59}', WordBreak::Numeric),
+    ('\u{AA7B}', '\u{AA7D}', WordBreak::Extend),
+    ('\u{AAB0}', '\u{AAB0}', WordBreak::Extend),
+    ('\u{AAB2}', '\u{AAB4}', WordBreak::Extend),
+    ('\u{AAB7}', '\u{AAB8}', WordBreak::Extend),
+    ('\u{AABE}', '\u{AABF}', WordBreak::Extend),
+    ('\u{AAC1}', '\u{AAC1}', WordBreak::Extend),
+    ('\u{AAE0}', '\u{AAEA}', WordBreak::ALetter),
+    ('\u{AAEB}', '\u{AAEF}', WordBreak::Extend),
+    ('\u{AAF2}', '\u{AAF4}', WordBreak::ALetter),
+    ('\u{AAF5}', '\u{AAF6}', WordBreak::Extend),
+    ('\u{AB01}', '\u{AB06}', WordBreak::ALetter),
+    ('\u{AB09}', '\u{AB0E}', WordBreak::ALetter),
+    ('\u{AB11}', '\u{AB16}', WordBreak::ALetter),
+    ('\u{AB20}', '\u{AB26}', WordBreak::ALetter),
+    ('\u{AB28}', '\u{AB2E}', WordBreak::ALetter),
+    ('\u{AB30}', '\u{AB69}', WordBreak::ALetter),
+    ('\u{AB70}', '\u{ABE2}', WordBreak::ALetter),
+    ('\u{ABE3}', '\u{ABEA}', WordBreak::Extend),
+    ('\u{ABEC}', '\u{ABED}', WordBreak::Extend),
+    ('\u{ABF0}', '\u{ABF9}', WordBreak::Numeric),
+    ('\u{AC00}', '\u{D7A3}', WordBreak::ALetter),
+    ('\u{D7B0}', '\u{D7C6}', WordBreak::ALetter),
+    ('\u{D7CB}', '\u{D7FB}', WordBreak::ALetter),
+    ('\u{FB00}', '\u{FB06}', WordBreak::ALetter),
+    ('\u{FB13}', '\u{FB17}', WordBreak::ALetter),
+    ('\u{FB1D}', '\u{FB1D}', WordBreak::HebrewLetter),
+    ('\u{FB1E}', '\u{FB1E}', WordBreak::Extend),
+    ('\u{FB1F}', '\u{FB28}', WordBreak::HebrewLetter),
+    ('\u{FB2A}', '\u{FB36}', WordBreak::HebrewLetter),
+    ('\u{FB38}', '\u{FB3C}', WordBreak::HebrewLetter),
+    ('\u{FB3E}', '\u{FB3E}', WordBreak::HebrewLetter),
+    ('\u{FB40}', '\u{FB41}', WordBreak::HebrewLetter),
+    ('\u{FB43}', '\u{FB44}', WordBreak::HebrewLetter),
+    ('\u{FB46}', '\u{FB4F}', WordBreak::HebrewLetter),
+    ('\u{FB50}', '\u{FBB1}', WordBreak::ALetter),
+    ('\u{FBD3}', '\u{FD3D}', WordBreak::ALetter),
+    ('\u{FD50}', '\u{FD8F}', WordBreak::ALetter),
+    ('\u{FD92}', '\u{FDC7}', WordBreak::ALetter),
+    ('\u{FDF0}', '\u{FDFB}', WordBreak::ALetter),
+    ('\u{FE00}', '\u{FE0F}', WordBreak::Extend),
+    ('\u{FE10}', '\u{FE10}', WordBreak::MidNum),
+    ('\u{FE13}', '\u{FE13}', WordBreak::MidLetter),
+    ('\u{FE14}', '\u{FE14}', WordBreak::MidNum),
+    ('\u{FE20}', '\u{FE2F}', WordBreak::Extend),
+    ('\u{FE33}', '\u{FE34}', WordBreak::ExtendNumLet),
+    ('\u{FE4D}', '\u{FE4F}', WordBreak::ExtendNumLet),
+    ('\u{FE50}', '\u{FE50}', WordBreak::MidNum),
+    ('\u{FE52}', '\u{FE52}', WordBreak::MidNumLet),
+    ('\u{FE54}', '\u{FE54}', WordBreak::MidNum),
+    ('\u{FE55}', '\u{FE55}', WordBreak::MidLetter),
+    ('\u{FE70}', '\u{FE74}', WordBreak::ALetter),
+    ('\u{FE76}', '\u{FEFC}', WordBreak::ALetter),
+    ('\u{FEFF}', '\u{FEFF}', WordBreak::Format),
+    ('\u{FF07}', '\u{FF07}', WordBreak::MidNumLet),
+    ('\u{FF0C}', '\u{FF0C}', WordBreak::MidNum),
+    ('\u{FF0E}', '\u{FF0E}', WordBreak::MidNumLet),
+    ('\u{FF10}', '\u{FF19}', WordBreak::Numeric),
+    ('\u{FF1A}', '\u{FF1A}', WordBreak::MidLetter),
+    ('\u{FF1B}', '\u{FF1B}', WordBreak::MidNum),
+    ('\u{FF21}', '\u{FF3A}', WordBreak::ALetter),
+    ('\u{FF3F}', '\u{FF3F}', WordBreak::ExtendNumLet),
+    ('\u{FF41}', '\u{FF5A}', WordBreak::ALetter),
+    ('\u{FF66}', '\u{FF9D}', WordBreak::Katakana),
+    ('\u{FF9E}', '\u{FF9F}', WordBreak::Extend),
+    ('\u{FFA0}', '\u{FFBE}', WordBreak::ALetter),
+    ('\u{FFC2}', '\u{FFC7}', WordBreak::ALetter),
+    ('\u{FFCA}', '\u{FFCF}', WordBreak::ALetter),
+    ('\u{FFD2}', '\u{FFD7}', WordBreak::ALetter),
+    ('\u{FFDA}', '\u{FFDC}', WordBreak::ALetter),
+    ('\u{FFF9}', '\u{FFFB}', WordBreak::Format),
+    ('\u{10000}', '\u{1000B}', WordBreak::ALetter),
+    ('\u{1000D}', '\u{10026}', WordBreak::ALetter),
+    ('\u{10028}', '\u{1003A}', WordBreak::ALetter),
+    ('\u{1003C}', '\u{1003D}', WordBreak::ALetter),
+    ('\u{1003F}', '\u{1004D}', WordBreak::ALetter),
+    ('\u{10050}', '\u{1005D}', WordBreak::ALetter),
+    ('\u{10080}', '\u{100FA}', WordBreak::ALetter),
+    ('\u{10140}', '\u{10174}', WordBreak::ALetter),
+    ('\u{101FD}', '\u{101FD}', WordBreak::Extend),
+    ('\u{10280}', '\u{1029C}', WordBreak::ALetter),
+    ('\u{102A0}', '\u{102D0}', WordBreak::ALetter),
+    ('\u{102E0}', '\u{102E0}', WordBreak::Extend),
+    ('\u{10300}', '\u{1031F}', WordBreak::ALetter),
+    ('\u{1032D}', '\u{1034A}', WordBreak::ALetter),
+    ('\u{10350}', '\u{10375}', WordBreak::ALetter),
+    ('\u{10376}', '\u{1037A}', WordBreak::Extend),
+    ('\u{10380}', '\u{1039D}', WordBreak::ALetter),
+    ('\u{103A0}', '\u{103C3}', WordBreak::ALetter),
+    ('\u{103C8}', '\u{103CF}', WordBreak::ALetter),
+    ('\u{103D1}', '\u{103D5}', WordBreak::ALetter),
+    ('\u{10400}', '\u{1049D}', WordBreak::ALetter),
+    ('\u{104A0}', '\u{104A9}', WordBreak::Numeric),
+    ('\u{104B0}', '\u{104D3}', WordBreak::ALetter),
+    ('\u{104D8}', '\u{104FB}', WordBreak::ALetter),
+    ('\u{10500}', '\u{10527}', WordBreak::ALetter),
+    ('\u{10530}', '\u{10563}', WordBreak::ALetter),
+    ('\u{10570}', '\u{1057A}', WordBreak::ALetter),
+    ('\u{1057C}', '\u{1058A}', WordBreak::ALetter),
+    ('\u{1058C}', '\u{10592}', WordBreak::ALetter),
+    ('\u{10594}', '\u{10595}', WordBreak::ALetter),
+    ('\u{10597}', '\u{105A1}', WordBreak::ALetter),
+    ('\u{105A3}', '\u{105B1}', WordBreak::ALetter),
+    ('\u{105B3}', '\u{105B9}', WordBreak::ALetter),
+    ('\u{105BB}', '\u{105BC}', WordBreak::ALetter),
+    ('\u{10600}', '\u{10736}', WordBreak::ALetter),
+    ('\u{10740}', '\u{10755}', WordBreak::ALetter),
+    ('\u{10760}', '\u{10767}', WordBreak::ALetter),
+    ('\u{10780}', '\u{10785}', WordBreak::ALetter),
+    ('\u{10787}', '\u{107B0}', WordBreak::ALetter),
+    ('\u{107B2}', '\u{107BA}', WordBreak::ALetter),
+    ('\u{10800}', '\u{10805}', WordBreak::ALetter),
+    ('\u{10808}', '\u{10808}', WordBreak::ALetter),
+    ('\u{1080A}', '\u{10835}', WordBreak::ALetter),
+    ('\u{10837}', '\u{10838}', WordBreak::ALetter),
+    ('\u{1083C}', '\u{1083C}', WordBreak::ALetter),
+    ('\u{1083F}', '\u{10855}', WordBreak::ALetter),
+    ('\u{10860}', '\u{10876}', WordBreak::ALetter),
+    ('\u{10880}', '\u{1089E}', WordBreak::ALetter),
+    ('\u{108E0}', '\u{108F2}', WordBreak::ALetter),
+    ('\u{108F4}', '\u{108F5}', WordBreak::ALetter),
+    ('\u{10900}', '\u{10915}', WordBreak::ALetter),
+    ('\u{10920}', '\u{10939}', WordBreak::ALetter),
+    ('\u{10980}', '\u{109B7}', WordBreak::ALetter),
+    ('\u{109BE}', '\u{109BF}', WordBreak::ALetter),
+    ('\u{10A00}', '\u{10A00}', WordBreak::ALetter),
+    ('\u{10A01}', '\u{10A03}', WordBreak::Extend),
+    ('\u{10A05}', '\u{10A06}', WordBreak::Extend),
+    ('\u{10A0C}', '\u{10A0F}', WordBreak::Extend),
+    ('\u{10A10}', '\u{10A13}', WordBreak::ALetter),
+    ('\u{10A15}', '\u{10A17}', WordBreak::ALetter),
+    ('\u{10A19}', '\u{10A35}', WordBreak::ALetter),
+    ('\u{10A38}', '\u{10A3A}', WordBreak::Extend),
+    ('\u{10A3F}', '\u{10A3F}', WordBreak::Extend),
+    ('\u{10A60}', '\u{10A7C}', WordBreak::ALetter),
+    ('\u{10A80}', '\u{10A9C}', WordBreak::ALetter),
+    ('\u{10AC0}', '\u{10AC7}', WordBreak::ALetter),
+    ('\u{10AC9}', '\u{10AE4}', WordBreak::ALetter),
+    ('\u{10AE5}', '\u{10AE6}', WordBreak::Extend),
+    ('\u{10B00}', '\u{10B35}', WordBreak::ALetter),
+    ('\u{10B40}', '\u{10B55}', WordBreak::ALetter),
+    ('\u{10B60}', '\u{10B72}', WordBreak::ALetter),
+    ('\u{10B80}', '\u{10B91}', WordBreak::ALetter),
+    ('\u{10C00}', '\u{10C48}', WordBreak::ALetter),
+    ('\u{10C80}', '\u{10CB2}', WordBreak::ALetter),
+    ('\u{10CC0}', '\u{10CF2}', WordBreak::ALetter),
+    ('\u{10D00}', '\u{10D23}', WordBreak::ALetter),
+    ('\u{10D24}', '\u{10D27}', WordBreak::Extend),
+    ('\u{10D30}', '\u{10D39}', WordBreak::Numeric),
+    ('\u{10E80}', '\u{10EA9}', WordBreak::ALetter),
+    ('\u{10EAB}', '\u{10EAC}', WordBreak::Extend),
+    ('\u{10EB0}', '\u{10EB1}', WordBreak::ALetter),
+    ('\u{10F00}', '\u{10F1C}', WordBreak::ALetter),
+    ('\u{10F27}', '\u{10F27}', WordBreak::ALetter),
+    ('\u{10F30}', '\u{10F45}', WordBreak::ALetter),
+    ('\u{10F46}', '\u{10F50}', WordBreak::Extend),
+    ('\u{10F70}', '\u{10F81}', WordBreak::ALetter),
+    ('\u{10F82}', '\u{10F85}', WordBreak::Extend),
+    ('\u{10FB0}', '\u{10FC4}', WordBreak::ALetter),
+    ('\u{10FE0}', '\u{10FF6}', WordBreak::ALetter),
+    ('\u{11000}', '\u{11002}', WordBreak::Extend),
+    ('\u{11003}', '\u{11037}', WordBreak::ALetter),
+    ('\u{11038}', '\u{11046}', WordBreak::Extend),
+    ('\u{11066}', '\u{1106F}', WordBreak::Numeric),
+    ('\u{11070}', '\u{11070}', WordBreak::Extend),
+    ('\u{11071}', '\u{11072}', WordBreak::ALetter),
+    ('\u{11073}', '\u{11074}', WordBreak::Extend),
+    ('\u{11075}', '\u{11075}', WordBreak::ALetter),
+    ('\u{1107F}', '\u{11082}', WordBreak::Extend),
+    ('\u{11083}', '\u{110AF}', WordBreak::ALetter),
+    ('\u{110B0}', '\u{110BA}', WordBreak::Extend),
+    ('\u{110BD}', '\u{110BD}', WordBreak::Format),
+    ('\u{110C2}', '\u{110C2}', WordBreak::Extend),
+    ('\u{110CD}', '\u{110CD}', WordBreak::Format),
+    ('\u{110D0}', '\u{110E8}', WordBreak::ALetter),
+    ('\u{110F0}', '\u{110F9}', WordBreak::Numeric),
+    ('\u{11100}', '\u{11102}', WordBreak::Extend),
+    ('\u{11103}', '\u{11126}', WordBreak::ALetter),
+    ('\u{11127}', '\u{11134}', WordBreak::Extend),
+    ('\u{11136}', '\u{1113F}', WordBreak::Numeric),
+    ('\u{11144}', '\u{11144}', WordBreak::ALetter),
+    ('\u{11145}', '\u{11146}', WordBreak::Extend),
+    ('\u{11147}', '\u{11147}', WordBreak::ALetter),
+    ('\u{11150}', '\u{11172}', WordBreak::ALetter),
+    ('\u{11173}', '\u{11173}', WordBreak::Extend),
+    ('\u{11176}', '\u{11176}', WordBreak::ALetter),
+    ('\u{11180}', '\u{11182}', WordBreak::Extend),
+    ('\u{11183}', '\u{111B2}', WordBreak::ALetter),
+    ('\u{111B3}', '\u{111C0}', WordBreak::Extend),
+    ('\u{111C1}', '\u{111C4}', WordBreak::ALetter),
+    ('\u{111C9}', '\u{111CC}', WordBreak::Extend),
+    ('\u{111CE}', '\u{111CF}', WordBreak::Extend),
+    ('\u{111D0}', '\u{111D9}', WordBreak::Numeric),
+    ('\u{111DA}', '\u{111DA}', WordBreak::ALetter),
+    ('\u{111DC}', '\u{111DC}', WordBreak::ALetter),
+    ('\u{11200}', '\u{11211}', WordBreak::ALetter),
+    ('\u{11213}', '\u{1122B}', WordBreak::ALetter),
+    ('\u{1122C}', '\u{11237}', WordBreak::Extend),
+    ('\u{1123E}', '\u{1123E}', WordBreak::Extend),
+    ('\u{11280}', '\u{11286}', WordBreak::ALetter),
+    ('\u{11288}', '\u{11288}', WordBreak::ALetter),
+    ('\u{1128A}', '\u{1128D}', WordBreak::ALetter),
+    ('\u{1128F}', '\u{1129D}', WordBreak::ALetter),
+    ('\u{1129F}', '\u{112A8}', WordBreak::ALetter),
+    ('\u{112B0}', '\u{112DE}', WordBreak::ALetter),
+    ('\u{112DF}', '\u{112EA}', WordBreak::Extend),
+    ('\u{112F0}', '\u{112F9}', WordBreak::Numeric),
+    ('\u{11300}', '\u{11303}', WordBreak::Extend),
+    ('\u{11305}', '\u{1130C}', WordBreak::ALetter),
+    ('\u{1130F}', '\u{11310}', WordBreak::ALetter),
+    ('\u{11313}', '\u{11328}', WordBreak::ALetter),
+    ('\u{1132A}', '\u{11330}', WordBreak::ALetter),
+    ('\u{11332}', '\u{11333}', WordBreak::ALetter),
+    ('\u{11335}', '\u{11339}', WordBreak::ALetter),
+    ('\u{1133B}', '\u{1133C}', WordBreak::Extend),
+    ('\u{1133D}', '\u{1133D}', WordBreak::ALetter),
+    ('\u{1133E}', '\u{11344}', WordBreak::Extend),
+    ('\u{11347}', '\u{11348}', WordBreak::Extend),
+    ('\u{1134B}', '\u{1134D}', WordBreak::Extend),
+    ('\u{11350}', '\u{11350}', WordBreak::ALetter),
+    ('\u{11357}', '\u{11357}', WordBreak::Extend),
+    ('\u{1135D}', '\u{11361}', WordBreak::ALetter),
+    ('\u{11362}', '\u{11363}', WordBreak::Extend),
+    ('\u{11366}', '\u{1136C}', WordBreak::Extend),
+    ('\u{11370}', '\u{11374}', WordBreak::Extend),
+    ('\u{11400}', '\u{11434}', WordBreak::ALetter),
+    ('\u{11435}', '\u{11446}', WordBreak::Extend),
+    ('\u{11447}', '\u{1144A}', WordBreak::ALetter),
+    ('\u{11450}', '\u{11459}', WordBreak::Numeric),
+    ('\u{1145E}', '\u{1145E}', WordBreak::Extend),
+    ('\u{1145F}', '\u{11461}', WordBreak::ALetter),
+    ('\u{11480}', '\u{114AF}', WordBreak::ALetter),
+    ('\u{114B0}', '\u{114C3}', WordBreak::Extend),
+    ('\u{114C4}', '\u{114C5}', WordBreak::ALetter),
+    ('\u{114C7}', '\u{114C7}', WordBreak::ALetter),
+    ('\u{114D0}', '\u{114D9}', WordBreak::Numeric),
+    ('\u{11580}', '\u{115AE}', WordBreak::ALetter),
+    ('\u{115AF}', '\u{115B5}', WordBreak::Extend),
+    ('\u{115B8}', '\u{115C0}', WordBreak::Extend),
+    ('\u{115D8}', '\u{115DB}', WordBreak::ALetter),
+    ('\u{115DC}', '\u{115DD}', WordBreak::Extend),
+    ('\u{11600}', '\u{1162F}', WordBreak::ALetter),
+    ('\u{11630}', '\u{11640}', WordBreak::Extend),
+    ('\u{11644}', '\u{11644}', WordBreak::ALetter),
+    ('\u{11650}', '\u{11659}', WordBreak::Numeric),
+    ('\u{11680}', '\u{116AA}', WordBreak::ALetter),
+    ('\u{116AB}', '\u{116B7}', WordBreak::Extend),
+    ('\u{116B8}', '\u{116B8}', WordBreak::ALetter),
+    ('\u{116C0}', '\u{116C9}', WordBreak::Numeric),
+    ('\u{1171D}', '\u{1172B}', WordBreak::Extend),
+    ('\u{11730}', '\u{11739}', WordBreak::Numeric),
+    ('\u{11800}', '\u{1182B}', WordBreak::ALetter),
+    ('\u{1182C}', '\u{1183A}', WordBreak::Extend),
+    ('\u{118A0}', '\u{118DF}', WordBreak::ALetter),
+    ('\u{118E0}', '\u{118E9}', WordBreak::Numeric),
+    ('\u{118FF}', '\u{11906}', WordBreak::ALetter),
+    ('\u{11909}', '\u{11909}', WordBreak::ALetter),
+    ('\u{1190C}', '\u{11913}', WordBreak::ALetter),
+    ('\u{11915}', '\u{11916}', WordBreak::ALetter),
+    ('\u{11918}', '\u{1192F}', WordBreak::ALetter),
+    ('\u{11930}', '\u{11935}', WordBreak::Extend),
+    ('\u{11937}', '\u{11938}', WordBreak::Extend),
+    ('\u{1193B}', '\u{1193E}', WordBreak::Extend),
+    ('\u{1193F}', '\u{1193F}', WordBreak::ALetter),
+    ('\u{11940}', '\u{11940}', WordBreak::Extend),
+    ('\u{11941}', '\u{11941}', WordBreak::ALetter),
+    ('\u{11942}', '\u{11943}', WordBreak::Extend),
+    ('\u{11950}', '\u{11959}', WordBreak::Numeric),
+    ('\u{119A0}', '\u{119A7}', WordBreak::ALetter),
+    ('\u{119AA}', '\u{119D0}', WordBreak::ALetter),
+    ('\u{119D1}', '\u{119D7}', WordBreak::Extend),
+    ('\u{119DA}', '\u{119E0}', WordBreak::Extend),
+    ('\u{119E1}', '\u{119E1}', WordBreak::ALetter),
+    ('\u{119E3}', '\u{119E3}', WordBreak::ALetter),
+    ('\u{119E4}', '\u{119E4}', WordBreak::Extend),
+    ('\u{11A00}', '\u{11A00}', WordBreak::ALetter),
+    ('\u{11A01}', '\u{11A0A}', WordBreak::Extend),
+    ('\u{11A0B}', '\u{11A32}', WordBreak::ALetter),
+    ('\u{11A33}', '\u{11A39}', WordBreak::Extend),
+    ('\u{11A3A}', '\u{11A3A}', WordBreak::ALetter),
+    ('\u{11A3B}', '\u{11A3E}', WordBreak::Extend),
+    ('\u{11A47}', '\u{11A47}', WordBreak::Extend),
+    ('\u{11A50}', '\u{11A50}', WordBreak::ALetter),
+    ('\u{11A51}', '\u{11A5B}', WordBreak::Extend),
+    ('\u{11A5C}', '\u{11A89}', WordBreak::ALetter),
+    ('\u{11A8A}', '\u{11A99}', WordBreak::Extend),
+    ('\u{11A9D}', '\u{11A9D}', WordBreak::ALetter),
+    ('\u{11AB0}', '\u{11AF8}', WordBreak::ALetter),
+    ('\u{11C00}', '\u{11C08}', WordBreak::ALetter),
+    ('\u{11C0A}', '\u{11C2E}', WordBreak::ALetter),
+    ('\u{11C2F}', '\u{11C36}', WordBreak::Extend),
+    ('\u{11C38}', '\u{11C3F}', WordBreak::Extend),
+    ('\u{11C40}', '\u{11C40}', WordBreak::ALetter),
+    ('\u{11C50}', '\u{11C59}', WordBreak::Numeric),
+    ('\u{11C72}', '\u{11C8F}', WordBreak::ALetter),
+    ('\u{11C92}', '\u{11CA7}', WordBreak::Extend),
+    ('\u{11CA9}', '\u{11CB6}', WordBreak::Extend),
+    ('\u{11D00}', '\u{11D06}', WordBreak::ALetter),
+    ('\u{11D08}', '\u{11D09}', WordBreak::ALetter),
+    ('\u{11D0B}', '\u{11D30}', WordBreak::ALetter),
+    ('\u{11D31}', '\u{11D36}', WordBreak::Extend),
+    ('\u{11D3A}', '\u{11D3A}', WordBreak::Extend),
+    ('\u{11D3C}', '\u{11D3D}', WordBreak::Extend),
+    ('\u{11D3F}', '\u{11D45}', WordBreak::Extend),
+    ('\u{11D46}', '\u{11D46}', WordBreak::ALetter),
+    ('\u{11D47}', '\u{11D47}', WordBreak::Extend),
+    ('\u{11D50}', '\u{11D59}', WordBreak::Numeric),
+    ('\u{11D60}', '\u{11D65}', WordBreak::ALetter),
+    ('\u{11D67}', '\u{11D68}', WordBreak::ALetter),
+    ('\u{11D6A}', '\u{11D89}', WordBreak::ALetter),
+    ('\u{11D8A}', '\u{11D8E}', WordBreak::Extend),
+    ('\u{11D90}', '\u{11D91}', WordBreak::Extend),
+    ('\u{11D93}', '\u{11D97}', WordBreak::Extend),
+    ('\u{11D98}', '\u{11D98}', WordBreak::ALetter),
+    ('\u{11DA0}', '\u{11DA9}', WordBreak::Numeric),
+    ('\u{11EE0}', '\u{11EF2}', WordBreak::ALetter),
+    ('\u{11EF3}', '\u{11EF6}', WordBreak::Extend),
+    ('\u{11FB0}', '\u{11FB0}', WordBreak::ALetter),
+    ('\u{12000}', '\u{12399}', WordBreak::ALetter),
+    ('\u{12400}', '\u{1246E}', WordBreak::ALetter),
+    ('\u{12480}', '\u{12543}', WordBreak::ALetter),
+    ('\u{12F90}', '\u{12FF0}', WordBreak::ALetter),
+    ('\u{13000}', '\u{1342E}', WordBreak::ALetter),
+    ('\u{13430}', '\u{13438}', WordBreak::Format),
+    ('\u{14400}', '\u{14646}', WordBreak::ALetter),
+    ('\u{16800}', '\u{16A38}', WordBreak::ALetter),
+    ('\u{16A40}', '\u{16A5E}', WordBreak::ALetter),
+    ('\u{16A60}', '\u{16A69}', WordBreak::Numeric),
+    ('\u{16A70}', '\u{16ABE}', WordBreak::ALetter),
+    ('\u{16AC0}', '\u{16AC9}', WordBreak::Numeric),
+    ('\u{16AD0}', '\u{16AED}', WordBreak::ALetter),
+    ('\u{16AF0}', '\u{16AF4}', WordBreak::Extend),
+    ('\u{16B00}', '\u{16B2F}', WordBreak::ALetter),
+    ('\u{16B30}', '\u{16B36}', WordBreak::Extend),
+    ('\u{16B40}', '\u{16B43}', WordBreak::ALetter),
+    ('\u{16B50}', '\u{16B59}', WordBreak::Numeric),
+    ('\u{16B63}', '\u{16B77}', WordBreak::ALetter),
+    ('\u{16B7D}', '\u{16B8F}', WordBreak::ALetter),
+    ('\u{16E40}', '\u{16E7F}', WordBreak::ALetter),
+    ('\u{16F00}', '\u{16F4A}', WordBreak::ALetter),
+    ('\u{16F4F}', '\u{16F4F}', WordBreak::Extend),
+    ('\u{16F50}', '\u{16F50}', WordBreak::ALetter),
+    ('\u{16F51}', '\u{16F87}', WordBreak::Extend),
+    ('\u{16F8F}', '\u{16F92}', WordBreak::Extend),
+    ('\u{16F93}', '\u{16F9F}', WordBreak::ALetter),
+    ('\u{16FE0}', '\u{16FE1}', WordBreak::ALetter),
+    ('\u{16FE3}', '\u{16FE3}', WordBreak::ALetter),
+    ('\u{16FE4}', '\u{16FE4}', WordBreak::Extend),
+    ('\u{16FF0}', '\u{16FF1}', WordBreak::Extend),
+    ('\u{1AFF0}', '\u{1AFF3}', WordBreak::Katakana),
+    ('\u{1AFF5}', '\u{1AFFB}', WordBreak::Katakana),
+    ('\u{1AFFD}', '\u{1AFFE}', WordBreak::Katakana),
+    ('\u{1B000}', '\u{1B000}', WordBreak::Katakana),
+    ('\u{1B120}', '\u{1B122}', WordBreak::Katakana),
+    ('\u{1B164}', '\u{1B167}', WordBreak::Katakana),
+    ('\u{1BC00}', '\u{1BC6A}', WordBreak::ALetter),
+    ('\u{1BC70}', '\u{1BC7C}', WordBreak::ALetter),
+    ('\u{1BC80}', '\u{1BC88}', WordBreak::ALetter),
+    ('\u{1BC90}', '\u{1BC99}', WordBreak::ALetter),
+    ('\u{1BC9D}', '\u{1BC9E}', WordBreak::Extend),
+    ('\u{1BCA0}', '\u{1BCA3}', WordBreak::Format),
+    ('\u{1CF00}', '\u{1CF2D}', WordBreak::Extend),
+    ('\u{1CF30}', '\u{1CF46}', WordBreak::Extend),
+    ('\u{1D165}', '\u{1D169}', WordBreak::Extend),
+    ('\u{1D16D}', '\u{1D172}', WordBreak::Extend),
+    ('\u{1D173}', '\u{1D17A}', WordBreak::Format),
+    ('\u{1D17B}', '\u{1D182}', WordBreak::Extend),
+    ('\u{1D185}', '\u{1D18B}', WordBreak::Extend),
+    ('\u{1D1AA}', '\u{1D1AD}', WordBreak::Extend),
+    ('\u{1D242}', '\u{1D244}', WordBreak::Extend),
+    ('\u{1D400}', '\u{1D454}', WordBreak::ALetter),
+    ('\u{1D456}', '\u{1D49C}', WordBreak::ALetter),
+    ('\u{1D49E}', '\u{1D49F}', WordBreak::ALetter),
+    ('\u{1D4A2}', '\u{1D4A2}', WordBreak::ALetter),
+    ('\u{1D4A5}', '\u{1D4A6}', WordBreak::ALetter),
+    ('\u{1D4A9}', '\u{1D4AC}', WordBreak::ALetter),
+    ('\u{1D4AE}', '\u{1D4B9}', WordBreak::ALetter),
+    ('\u{1D4BB}', '\u{1D4BB}', WordBreak::ALetter),
+    ('\u{1D4BD}', '\u{1D4C3}', WordBreak::ALetter),
+    ('\u{1D4C5}', '\u{1D505}', WordBreak::ALetter),
+    ('\u{1D507}', '\u{1D50A}', WordBreak::ALetter),
+    ('\u{1D50D}', '\u{1D514}', WordBreak::ALetter),
+    ('\u{1D516}', '\u{1D51C}', WordBreak::ALetter),
+    ('\u{1D51E}', '\u{1D539}', WordBreak::ALetter),
+    ('\u{1D53B}', '\u{1D53E}', WordBreak::ALetter),
+    ('\u{1D540}', '\u{1D544}', WordBreak::ALetter),
+    ('\u{1D546}', '\u{1D546}', WordBreak::ALetter),
+    ('\u{1D54A}', '\u{1D550}', WordBreak::ALetter),
+    ('\u{1D552}', '\u{1D6A5}', WordBreak::ALetter),
+    ('\u{1D6A8}', '\u{1D6C0}', WordBreak::ALetter),
+    ('\u{1D6C2}', '\u{1D6DA}', WordBreak::ALetter),
+    ('\u{1D6DC}', '\u{1D6FA}', WordBreak::ALetter),
+    ('\u{1D6FC}', '\u{1D714}', WordBreak::ALetter),
+    ('\u{1D716}', '\u{1D734}', WordBreak::ALetter),
+    ('\u{1D736}', '\u{1D74E}', WordBreak::ALetter),
+    ('\u{1D750}', '\u{1D76E}', WordBreak::ALetter),
+    ('\u{1D770}', '\u{1D788}', WordBreak::ALetter),
+    ('\u{1D78A}', '\u{1D7A8}', WordBreak::ALetter),
+    ('\u{1D7AA}', '\u{1D7C2}', WordBreak::ALetter),
+    ('\u{1D7C4}', '\u{1D7CB}', WordBreak::ALetter),
+    ('\u{1D7CE}', '\u{1D7FF}', WordBreak::Numeric),
+    ('\u{1DA00}', '\u{1DA36}', WordBreak::Extend),
+    ('\u{1DA3B}', '\u{1DA6C}', WordBreak::Extend),
+    ('\u{1DA75}', '\u{1DA75}', WordBreak::Extend),
+    ('\u{1DA84}', '\u{1DA84}', WordBreak::Extend),
+    ('\u{1DA9B}', '\u{1DA9F}', WordBreak::Extend),
+    ('\u{1DAA1}', '\u{1DAAF}', WordBreak::Extend),
+    ('\u{1DF00}', '\u{1DF1E}', WordBreak::ALetter),
+    ('\u{1E000}', '\u{1E006}', WordBreak::Extend),
+    ('\u{1E008}', '\u{1E018}', WordBreak::Extend),
+    ('\u{1E01B}', '\u{1E021}', WordBreak::Extend),
+    ('\u{1E023}', '\u{1E024}', WordBreak::Extend),
+    ('\u{1E026}', '\u{1E02A}', WordBreak::Extend),
+    ('\u{1E100}', '\u{1E12C}', WordBreak::ALetter),
+    ('\u{1E130}', '\u{1E136}', WordBreak::Extend),
+    ('\u{1E137}', '\u{1E13D}', WordBreak::ALetter),
+    ('\u{1E140}', '\u{1E149}', WordBreak::Numeric),
+    ('\u{1E14E}', '\u{1E14E}', WordBreak::ALetter),
+    ('\u{1E290}', '\u{1E2AD}', WordBreak::ALetter),
+    ('\u{1E2AE}', '\u{1E2AE}', WordBreak::Extend),
+    ('\u{1E2C0}', '\u{1E2EB}', WordBreak::ALetter),
+    ('\u{1E2EC}', '\u{1E2EF}', WordBreak::Extend),
+    ('\u{1E2F0}', '\u{1E2F9}', WordBreak::Numeric),
+    ('\u{1E7E0}', '\u{1E7E6}', WordBreak::ALetter),
+    ('\u{1E7E8}', '\u{1E7EB}', WordBreak::ALetter),
+    ('\u{1E7ED}', '\u{1E7EE}', WordBreak::ALetter),
+    ('\u{1E7F0}', '\u{1E7FE}', WordBreak::ALetter),
+    ('\u{1E800}', '\u{1E8C4}', WordBreak::ALetter),
+    ('\u{1E8D0}', '\u{1E8D6}', WordBreak::Extend),
+    ('\u{1E900}', '\u{1E943}', WordBreak::ALetter),
+    ('\u{1E944}', '\u{1E94A}', WordBreak::Extend),
+    ('\u{1E94B}', '\u{1E94B}', WordBreak::ALetter),
+    ('\u{1E950}', '\u{1E959}', WordBreak::Numeric),
+    ('\u{1EE00}', '\u{1EE03}', WordBreak::ALetter),
+    ('\u{1EE05}', '\u{1EE1F}', WordBreak::ALetter),
+    ('\u{1EE21}', '\u{1EE22}', WordBreak::ALetter),
+    ('\u{1EE24}', '\u{1EE24}', WordBreak::ALetter),
+    ('\u{1EE27}', '\u{1EE27}', WordBreak::ALetter),
+    ('\u{1EE29}', '\u{1EE32}', WordBreak::ALetter),
+    ('\u{1EE34}', '\u{1EE37}', WordBreak::ALetter),
+    ('\u{1EE39}', '\u{1EE39}', WordBreak::ALetter),
+    ('\u{1EE3B}', '\u{1EE3B}', WordBreak::ALetter),
+    ('\u{1EE42}', '\u{1EE42}', WordBreak::ALetter),
+    ('\u{1EE47}', '\u{1EE47}', WordBreak::ALetter),
+    ('\u{1EE49}', '\u{1EE49}', WordBreak::ALetter),
+    ('\u{1EE4B}', '\u{1EE4B}', WordBreak::ALetter),
+    ('\u{1EE4D}', '\u{1EE4F}', WordBreak::ALetter),
+    ('\u{1EE51}', '\u{1EE52}', WordBreak::ALetter),
+    ('\u{1EE54}', '\u{1EE54}', WordBreak::ALetter),
+    ('\u{1EE57}', '\u{1EE57}', WordBreak::ALetter),
+    ('\u{1EE59}', '\u{1EE59}', WordBreak::ALetter),
+    ('\u{1EE5B}', '\u{1EE5B}', WordBreak::ALetter),
+    ('\u{1EE5D}', '\u{1EE5D}', WordBreak::ALetter),
+    ('\u{1EE5F}', '\u{1EE5F}', WordBreak::ALetter),
+    ('\u{1EE61}', '\u{1EE62}', WordBreak::ALetter),
+    ('\u{1EE64}', '\u{1EE64}', WordBreak::ALetter),
+    ('\u{1EE67}', '\u{1EE6A}', WordBreak::ALetter),
+    ('\u{1EE6C}', '\u{1EE72}', WordBreak::ALetter),
+    ('\u{1EE74}', '\u{1EE77}', WordBreak::ALetter),
+    ('\u{1EE79}', '\u{1EE7C}', WordBreak::ALetter),
+    ('\u{1EE7E}', '\u{1EE7E}', WordBreak::ALetter),
+    ('\u{1EE80}', '\u{1EE89}', WordBreak::ALetter),
+    ('\u{1EE8B}', '\u{1EE9B}', WordBreak::ALetter),
+    ('\u{1EEA1}', '\u{1EEA3}', WordBreak::ALetter),
+    ('\u{1EEA5}', '\u{1EEA9}', WordBreak::ALetter),
+    ('\u{1EEAB}', '\u{1EEBB}', WordBreak::ALetter),
+    ('\u{1F130}', '\u{1F149}', WordBreak::ALetter),
+    ('\u{1F150}', '\u{1F169}', WordBreak::ALetter),
+    ('\u{1F170}', '\u{1F189}', WordBreak::ALetter),
+    ('\u{1F1E6}', '\u{1F1FF}', WordBreak::RegionalIndicator),
+    ('\u{1F3FB}', '\u{1F3FF}', WordBreak::Extend),
+    ('\u{1FBF0}', '\u{1FBF9}', WordBreak::Numeric),
+    ('\u{E0001}', '\u{E0001}', WordBreak::Format),
+    ('\u{E0020}', '\u{E007F}', WordBreak::Extend),
+    ('\u{E0100}', '\u{E01EF}', WordBreak::Extend),
+];
+
+pub(crate) const EXTENDED_PICTOGRAPHIC: &[(char, char)] = &[
+    ('\u{A9}', '\u{A9}'),
+    ('\u{AE}', '\u{AE}'),
+    ('\u{203C}', '\u{203C}'),
+    ('\u{2049}', '\u{2049}'),
+    ('\u{2122}', '\u{2122}'),
+    ('\u{2139}', '\u{2139}'),
+    ('\u{2194}', '\u{2199}'),
+    ('\u{21A9}', '\u{21AA}'),
+    ('\u{231A}', '\u{231B}'),
+    ('\u{2328}', '\u{2328}'),
+    ('\u{2388}', '\u{2388}'),
+    ('\u{23CF}', '\u{23CF}'),
+    ('\u{23E9}', '\u{23F3}'),
+    ('\u{23F8}', '\u{23FA}'),
+    ('\u{24C2}', '\u{24C2}'),
+    ('\u{25AA}', '\u{25AB}'),
+    ('\u{25B6}', '\u{25B6}'),
+    ('\u{25C0}', '\u{25C0}'),
+    ('\u{25FB}', '\u{25FE}'),
+    ('\u{2600}', '\u{2605}'),
+    ('\u{2607}', '\u{2612}'),
+    ('\u{2614}', '\u{2685}'),
+    ('\u{2690}', '\u{2705}'),
+    ('\u{2708}', '\u{2712}'),
+    ('\u{2714}', '\u{2714}'),
+    ('\u{2716}', '\u{2716}'),
+    ('\u{271D}', '\u{271D}'),
+    ('\u{2721}', '\u{2721}'),
+    ('\u{2728}', '\u{2728}'),
+    ('\u{2733}', '\u{2734}'),
+    ('\u{2744}', '\u{2744}'),
+    ('\u{2747}', '\u{2747}'),
+    ('\u{274C}', '\u{274C}'),
+    ('\u{274E}', '\u{274E}'),
+    ('\u{2753}', '\u{2755}'),
+    ('\u{2757}', '\u{2757}'),
+    ('\u{2763}', '\u{2767}'),
+    ('\u{2795}', '\u{2797}'),
+    ('\u{27A1}', '\u{27A1}'),
+    ('\u{27B0}', '\u{27B0}'),
+    ('\u{27BF}', '\u{27BF}'),
+    ('\u{2934}', '\u{2935}'),
+    ('\u{2B05}', '\u{2B07}'),
+    ('\u{2B1B}', '\u{2B1C}'),
+    ('\u{2B50}', '\u{2B50}'),
+    ('\u{2B55}', '\u{2B55}'),
+    ('\u{3030}', '\u{3030}'),
+    ('\u{303D}', '\u{303D}'),
+    ('\u{3297}', '\u{3297}'),
+    ('\u{3299}', '\u{3299}'),
+    ('\u{1F000}', '\u{1F0FF}'),
+    ('\u{1F10D}', '\u{1F10F}'),
+    ('\u{1F12F}', '\u{1F12F}'),
+    ('\u{1F16C}', '\u{1F171}'),
+    ('\u{1F17E}', '\u{1F17F}'),
+    ('\u{1F18E}', '\u{1F18E}'),
+    ('\u{1F191}', '\u{1F19A}'),
+    ('\u{1F1AD}', '\u{1F1E5}'),
+    ('\u{1F201}', '\u{1F20F}'),
+    ('\u{1F21A}', '\u{1F21A}'),
+    ('\u{1F22F}', '\u{1F22F}'),
+    ('\u{1F232}', '\u{1F23A}'),
+    ('\u{1F23C}', '\u{1F23F}'),
+    ('\u{1F249}', '\u{1F3FA}'),
+    ('\u{1F400}', '\u{1F53D}'),
+    ('\u{1F546}', '\u{1F64F}'),
+    ('\u{1F680}', '\u{1F6FF}'),
+    ('\u{1F774}', '\u{1F77F}'),
+    ('\u{1F7D5}', '\u{1F7FF}'),
+    ('\u{1F80C}', '\u{1F80F}'),
+    ('\u{1F848}', '\u{1F84F}'),
+    ('\u{1F85A}', '\u{1F85F}'),
+    ('\u{1F888}', '\u{1F88F}'),
+    ('\u{1F8AE}', '\u{1F8FF}'),
+    ('\u{1F90C}', '\u{1F93A}'),
+    ('\u{1F93C}', '\u{1F945}'),
+    ('\u{1F947}', '\u{1FAFF}'),
+    ('\u{1FC00}', '\u{1FFFD}'),
+];
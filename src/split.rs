@@ -0,0 +1,206 @@
+use super::regex::*;
+
+impl Regex {
+    // Pieces of the string between matches, empty matches split between characters
+    pub fn split<'r, 'h>(&'r self, string: &'h str) -> Split<'r, 'h> {
+        return Split::new(self, string);
+    }
+
+    // At most limit pieces, the last one being the rest of the string
+    pub fn splitn<'r, 'h>(&'r self, string: &'h str, limit: usize) -> SplitN<'r, 'h> {
+        return SplitN {
+            splits: Split::new(self, string),
+            limit,
+        };
+    }
+
+    // Same as splitn but going from the end, so the last piece is the start of the string
+    pub fn rsplitn<'r, 'h>(&'r self, string: &'h str, limit: usize) -> RSplitN<'r, 'h> {
+        return RSplitN {
+            finder: self.rfind_iter(string),
+            haystack: string,
+            last: Some(string.len()),
+            limit,
+        };
+    }
+
+    // Every piece keeps the match that ended it, there's no empty piece at the end if the string ends in a match
+    pub fn split_inclusive<'r, 'h>(&'r self, string: &'h str) -> SplitInclusive<'r, 'h> {
+        return SplitInclusive {
+            finder: self.find_iter(string),
+            haystack: string,
+            last: Some(0),
+        };
+    }
+
+    // Matches and the text between them in order, for tokenising without losing the separators
+    pub fn segments<'r, 'h>(&'r self, string: &'h str) -> Segments<'r, 'h> {
+        return Segments {
+            finder: self.find_iter(string),
+            haystack: string,
+            last: 0,
+            pending: None,
+        };
+    }
+}
+
+#[derive(Debug)]
+pub struct Split<'r, 'h> {
+    finder: Matches<'r, 'h>,
+    haystack: &'h str,
+    // Start of the next piece, None once the last piece has been returned
+    last: Option<usize>,
+}
+
+impl<'r, 'h> Split<'r, 'h> {
+    fn new(regex: &'r Regex, haystack: &'h str) -> Self {
+        return Self {
+            finder: regex.find_iter(haystack),
+            haystack,
+            last: Some(0),
+        };
+    }
+}
+
+impl<'r, 'h> Iterator for Split<'r, 'h> {
+    type Item = &'h str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let last = self.last?;
+        match self.finder.next() {
+            Some(m) => {
+                self.last = Some(m.end());
+                return Some(&self.haystack[last..m.start()]);
+            }
+            None => {
+                self.last = None;
+                return Some(&self.haystack[last..]);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SplitN<'r, 'h> {
+    splits: Split<'r, 'h>,
+    limit: usize,
+}
+
+impl<'r, 'h> Iterator for SplitN<'r, 'h> {
+    type Item = &'h str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.limit == 0 {
+            return None;
+        }
+        self.limit -= 1;
+        if self.limit > 0 {
+            return self.splits.next();
+        }
+        let last = self.splits.last.take()?;
+        return Some(&self.splits.haystack[last..]);
+    }
+}
+
+#[derive(Debug)]
+pub struct RSplitN<'r, 'h> {
+    finder: RMatches<'r, 'h>,
+    haystack: &'h str,
+    // End of the next piece
+    last: Option<usize>,
+    limit: usize,
+}
+
+impl<'r, 'h> Iterator for RSplitN<'r, 'h> {
+    type Item = &'h str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.limit == 0 {
+            return None;
+        }
+        self.limit -= 1;
+        let last = self.last?;
+        if self.limit > 0 {
+            if let Some(m) = self.finder.next() {
+                self.last = Some(m.start());
+                return Some(&self.haystack[m.end()..last]);
+            }
+        }
+        self.last = None;
+        return Some(&self.haystack[..last]);
+    }
+}
+
+#[derive(Debug)]
+pub struct SplitInclusive<'r, 'h> {
+    finder: Matches<'r, 'h>,
+    haystack: &'h str,
+    last: Option<usize>,
+}
+
+impl<'r, 'h> Iterator for SplitInclusive<'r, 'h> {
+    type Item = &'h str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let last = self.last?;
+        match self.finder.next() {
+            Some(m) => {
+                self.last = Some(m.end());
+                return Some(&self.haystack[last..m.end()]);
+            }
+            None => {
+                self.last = None;
+                if last == self.haystack.len() {
+                    return None;
+                }
+                return Some(&self.haystack[last..]);
+            }
+        }
+    }
+}
+
+// Empty text between two matches isn't returned, empty matches are
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Segment<'h> {
+    Matched(Match<'h>),
+    Unmatched(&'h str),
+}
+
+#[derive(Debug)]
+pub struct Segments<'r, 'h> {
+    finder: Matches<'r, 'h>,
+    haystack: &'h str,
+    // End of the last match
+    last: usize,
+    // Match found after some text that was returned first
+    pending: Option<Match<'h>>,
+}
+
+impl<'r, 'h> Iterator for Segments<'r, 'h> {
+    type Item = Segment<'h>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(m) = self.pending.take() {
+            return Some(Segment::Matched(m));
+        }
+        match self.finder.next() {
+            Some(m) => {
+                let last = self.last;
+                self.last = m.end();
+                if m.start() == last {
+                    return Some(Segment::Matched(m));
+                }
+                self.pending = Some(m);
+                return Some(Segment::Unmatched(&self.haystack[last..m.start()]));
+            }
+            None => {
+                let last = self.last;
+                self.last = self.haystack.len();
+                if last == self.haystack.len() {
+                    return None;
+                }
+                return Some(Segment::Unmatched(&self.haystack[last..]));
+            }
+        }
+    }
+}
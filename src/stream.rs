@@ -0,0 +1,261 @@
+use crate::bytes::{Input, Regex};
+use crate::regex;
+use crate::compiled_node::{AnchorNode, CNode, SpecialNode};
+use crate::config::RegexConfig;
+use crate::error::Error;
+use crate::haystack::{next_index, Haystack, RawBytes, Units};
+use crate::parallel_nfa;
+use crate::regex::MatchingEngine;
+use std::io::{self, Read};
+use std::ops::Range;
+
+// Searches bytes that come in a chunk at a time, like a network capture or a log that doesn't fit in memory
+// Gives the same matches bytes::Regex::find_iter would over everything fed in, with offsets from the start of the stream
+// For patterns the parallel engine runs the search carries on from where the last chunk left it, so apart from a match
+// waiting on what comes next only the last character or so is kept
+#[derive(Debug)]
+pub struct StreamMatcher {
+    regex: Regex,
+    searcher: Searcher,
+    // Bytes the search still needs, along with one character before them so anchors can see what came before
+    buffer: Vec<u8>,
+    // Where the buffer starts in the stream
+    offset: usize,
+}
+
+impl StreamMatcher {
+    pub fn new(regex: &str) -> Result<Self, Error> {
+        return Self::with_config(regex, RegexConfig::default());
+    }
+
+    // Patterns that would have to keep the whole stream around are turned down instead
+    pub fn with_config(regex: &str, config: RegexConfig) -> Result<Self, Error> {
+        let regex = Regex::with_config(regex, config)?;
+        let searcher = Searcher::new(&regex.regex).map_err(|reason| Error::Unstreamable { reason: reason.to_string() })?;
+        return Ok(Self {
+            regex,
+            searcher,
+            buffer: Vec::new(),
+            offset: 0,
+        });
+    }
+
+    // Matches nothing later in the stream can change, one that could still get longer waits for the next chunk
+    pub fn feed(&mut self, chunk: &[u8]) -> Vec<Range<usize>> {
+        self.buffer.extend_from_slice(chunk);
+        return self.search(false);
+    }
+
+    // Whatever matches are left at the end of the stream, after which the matcher is ready for a new one
+    pub fn finish(&mut self) -> Vec<Range<usize>> {
+        let matches = self.search(true);
+        self.buffer.clear();
+        self.offset = 0;
+        self.searcher.reset();
+        return matches;
+    }
+
+    // Every match in what the reader gives up to its end, read a chunk at a time
+    pub fn search_reader<R: Read>(&mut self, mut reader: R) -> io::Result<Vec<Range<usize>>> {
+        let mut chunk = vec![0; 64 * 1024];
+        let mut matches = Vec::new();
+        loop {
+            let read = match reader.read(&mut chunk) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            };
+            matches.extend(self.feed(&chunk[..read]));
+        }
+        matches.extend(self.finish());
+        return Ok(matches);
+    }
+
+    fn search(&mut self, eof: bool) -> Vec<Range<usize>> {
+        let regex = &self.regex.regex;
+        // Anchors at the end of the span need to see the character after it, so the last one waits for the next chunk
+        // along with a UTF-8 sequence the chunk cut in two
+        let end = if eof {
+            self.buffer.len()
+        } else {
+            let complete = match regex.units {
+                Units::Bytes => self.buffer.len(),
+                Units::Utf8 => self.buffer.len() - incomplete_utf8_len(&self.buffer),
+            };
+            previous_char(regex.units, &self.buffer, complete).map_or(0, |(_, len)| complete - len)
+        };
+        let matches = self.searcher.search(regex, &self.buffer, self.offset, end, eof);
+        if !eof {
+            // Drop everything the search is done with apart from the character before it
+            let resume = self.searcher.resume_point() - self.offset;
+            let keep = previous_char(regex.units, &self.buffer, resume).map_or(resume, |(_, len)| resume - len);
+            self.buffer.drain(..keep);
+            self.offset += keep;
+        }
+        return matches.into_iter().map(|(start, end)| start..end).collect();
+    }
+}
+
+// What StreamMatcher and replace_all_to_writer share, finding matches in a buffer that's a window onto the stream
+// The parallel engine keeps its threads between windows, the backtracker searches again from where it got to and
+// can only be used when matches have a length limit, so that it knows which matches nothing past the window can change
+#[derive(Debug)]
+pub(crate) struct Searcher {
+    // None when the backtracker has to run the pattern
+    nfa: Option<parallel_nfa::Resumable>,
+    // Most bytes a match can take up, for patterns only the backtracker can run
+    max_len: usize,
+    // Where the backtracker's next search starts in the stream
+    position: usize,
+    last_match_end: Option<usize>,
+}
+
+impl Searcher {
+    // Patterns that would have to keep the whole stream around are turned down with the reason why
+    pub(crate) fn new(regex: &regex::Regex) -> Result<Self, &'static str> {
+        for node in regex.node_vec.iter() {
+            let reason = match &node.node {
+                CNode::Special(SpecialNode::BackRef(_)) => "backreferences can refer back to text any distance away",
+                CNode::Special(SpecialNode::StartLookBack(_)) | CNode::Special(SpecialNode::StartVariableLookback(..)) => "lookbehind isn't supported",
+                CNode::Anchor(AnchorNode::UnicodeWordBoundary) | CNode::Anchor(AnchorNode::GraphemeBoundary) => "\\b{w} and \\b{g} can look back any distance",
+                _ => continue,
+            };
+            return Err(reason);
+        }
+        let nfa = match *regex.engine.lock().unwrap() {
+            MatchingEngine::ParallelNFA {} => Some(parallel_nfa::Resumable::new(&regex.node_vec)),
+            _ => None,
+        };
+        let max_len = match regex.max_match_len() {
+            Some(max_len) => max_len,
+            None if nfa.is_some() => 0,
+            // Their matches do have a length limit, but it isn't worked out from the counters
+            None if regex.node_vec.iter().any(|node| matches!(node.node, CNode::Special(SpecialNode::RepeatLoop(..)))) => {
+                return Err("repetitions too big to copy out are counted on the backtracker, which can't tell how long their matches can be")
+            }
+            None => return Err("lookarounds, atomic groups and \\X need the backtracker, which can only run over a stream when matches have a length limit"),
+        };
+        return Ok(Self {
+            nfa,
+            max_len,
+            position: 0,
+            last_match_end: None,
+        });
+    }
+
+    // Ready for a new stream
+    pub(crate) fn reset(&mut self) {
+        if let Some(nfa) = &mut self.nfa {
+            nfa.restart(0);
+        }
+        self.position = 0;
+        self.last_match_end = None;
+    }
+
+    // Where the search carries on from, the buffer has to have the text from here on and the character before it
+    pub(crate) fn resume_point(&self) -> usize {
+        return match &self.nfa {
+            Some(nfa) => nfa.resume_point(),
+            None => self.position,
+        };
+    }
+
+    // Earliest position in the stream a match that hasn't been given yet can start at
+    pub(crate) fn earliest_start(&self) -> usize {
+        return match &self.nfa {
+            Some(nfa) => nfa.earliest_start(),
+            None => self.position,
+        };
+    }
+
+    // Matches in the buffer up to end that nothing after it can change, or all that are left at the end of the stream
+    // The buffer starts at offset in the stream, and positions in and out are in the stream
+    pub(crate) fn search(&mut self, regex: &regex::Regex, buffer: &[u8], offset: usize, end: usize, eof: bool) -> Vec<(usize, usize)> {
+        let mut matches = Vec::new();
+        loop {
+            let found = match &mut self.nfa {
+                Some(nfa) => {
+                    let input = Input::new(buffer).span(0..end);
+                    match regex.units {
+                        Units::Bytes => nfa.run(&regex.node_vec, regex.root_node_idx, &regex.optimized_root_node, &input.with_haystack(&RawBytes(buffer)), offset, eof),
+                        Units::Utf8 => nfa.run(&regex.node_vec, regex.root_node_idx, &regex.optimized_root_node, &input, offset, eof),
+                    }
+                }
+                None => self.backtrack(regex, buffer, offset, end, eof),
+            };
+            let (start, match_end) = match found {
+                Some(found) => found,
+                None => break,
+            };
+            let next = if start == match_end {
+                match regex.units {
+                    Units::Bytes => match_end + 1,
+                    Units::Utf8 => offset + next_index(buffer, match_end - offset),
+                }
+            } else {
+                match_end
+            };
+            let skip = start == match_end && self.last_match_end == Some(match_end);
+            if !skip {
+                self.last_match_end = Some(match_end);
+                matches.push((start, match_end));
+            }
+            match &mut self.nfa {
+                Some(nfa) => nfa.restart(next),
+                None => self.position = next,
+            }
+            if next > offset + end {
+                break;
+            }
+        }
+        if !eof && self.nfa.is_none() {
+            // Matches can't start before the first one that hasn't been given yet could
+            let mut safe = end.saturating_sub(self.max_len);
+            while safe > 0 && regex.units == Units::Utf8 && matches!(buffer.get(safe), Some(b) if b & 0xC0 == 0x80) {
+                safe -= 1;
+            }
+            self.position = self.position.max(offset + safe);
+        }
+        return matches;
+    }
+
+    // The next match from where the backtracker got to that starts early enough that no match starting later than it
+    // could run past the end and take priority over it
+    fn backtrack(&self, regex: &regex::Regex, buffer: &[u8], offset: usize, end: usize, eof: bool) -> Option<(usize, usize)> {
+        let input = Input::new(buffer).span(self.position - offset..end);
+        let (start, match_end) = match regex.units {
+            Units::Bytes => regex.search_span(&input.with_haystack(&RawBytes(buffer)))?,
+            Units::Utf8 => regex.search_span(&input)?,
+        };
+        if !eof && start + self.max_len >= end {
+            return None;
+        }
+        return Some((offset + start, offset + match_end));
+    }
+}
+
+// Character before the position the regex's units would see
+fn previous_char(units: Units, haystack: &[u8], at: usize) -> Option<(char, usize)> {
+    return match units {
+        Units::Bytes => RawBytes(haystack).prev_char(at),
+        Units::Utf8 => haystack.prev_char(at),
+    };
+}
+
+// Number of bytes at the end that start a UTF-8 sequence without finishing it
+fn incomplete_utf8_len(bytes: &[u8]) -> usize {
+    for len in 1..=bytes.len().min(3) {
+        let b = bytes[bytes.len() - len];
+        if b & 0xC0 != 0x80 {
+            let needed = match b {
+                0xC2..=0xDF => 2,
+                0xE0..=0xEF => 3,
+                0xF0..=0xF4 => 4,
+                _ => 1,
+            };
+            return if needed > len { len } else { 0 };
+        }
+    }
+    return 0;
+}
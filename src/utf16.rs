@@ -0,0 +1,130 @@
+use crate::config::RegexConfig;
+use crate::error::Error;
+use crate::regex;
+
+// What to search and how in UTF-16 text, positions count u16s and a span can start and end anywhere
+pub type Input<'h> = regex::Input<'h, [u16]>;
+
+// Same patterns as regex::Regex searching UTF-16 without converting it first, offsets are in u16 units
+// A surrogate pair is one character, a surrogate on its own is a character that only . and negated classes like [^a] take
+#[derive(Clone, Debug)]
+pub struct Regex {
+    regex: regex::Regex,
+}
+
+impl Regex {
+    // Panics if the pattern goes over any of the default limits, use try_new for patterns that aren't trusted
+    pub fn new(regex: &str) -> Self {
+        return Self::try_new(regex).unwrap();
+    }
+
+    pub fn try_new(regex: &str) -> Result<Self, Error> {
+        return Self::with_config(regex, RegexConfig::default());
+    }
+
+    // There's no such thing as a single byte in UTF-16 so (?-u) isn't allowed
+    pub fn with_config(regex: &str, config: RegexConfig) -> Result<Self, Error> {
+        return Ok(Self {
+            regex: regex::Regex::with_config(regex, config)?,
+        });
+    }
+
+    pub fn as_str(&self) -> &str {
+        return &self.regex.expr;
+    }
+
+    pub fn is_match(&self, haystack: &[u16]) -> bool {
+        return self.regex.search_span(&Input::new(haystack).earliest(true)).is_some();
+    }
+
+    pub fn find<'h>(&self, haystack: &'h [u16]) -> Option<Match<'h>> {
+        return self.search(&Input::new(haystack));
+    }
+
+    // Searching starts at the offset but anchors still see what comes before it
+    pub fn find_at<'h>(&self, haystack: &'h [u16], start: usize) -> Option<Match<'h>> {
+        if start > haystack.len() {
+            return None;
+        }
+        return self.search(&Input::new(haystack).span(start..haystack.len()));
+    }
+
+    pub fn find_iter<'r, 'h>(&'r self, haystack: &'h [u16]) -> Matches<'r, 'h> {
+        return Matches::new(&self.regex, Input::new(haystack));
+    }
+
+    pub fn captures<'h>(&self, haystack: &'h [u16]) -> Option<Captures<'h>> {
+        return self.search_captures(&Input::new(haystack));
+    }
+
+    pub fn captures_iter<'r, 'h>(&'r self, haystack: &'h [u16]) -> CaptureMatches<'r, 'h> {
+        return CaptureMatches::new(Matches::new(&self.regex, Input::new(haystack)));
+    }
+
+    pub fn search<'h>(&self, input: &Input<'h>) -> Option<Match<'h>> {
+        let (start, end) = self.regex.search_span(input)?;
+        return Some(Match::new(input.haystack(), start, end));
+    }
+
+    pub fn search_captures<'h>(&self, input: &Input<'h>) -> Option<Captures<'h>> {
+        let slots = self.regex.search_slots(input)?;
+        return Some(Captures::new(input.haystack(), slots, self.regex.group_names.clone()));
+    }
+}
+
+// A match in the haystack, positions are u16 offsets
+pub type Match<'h> = regex::Match<'h, [u16]>;
+
+impl<'h> Match<'h> {
+    pub fn as_slice(&self) -> &'h [u16] {
+        return self.slice();
+    }
+
+    // Unpaired surrogates become U+FFFD
+    pub fn to_string_lossy(&self) -> String {
+        return String::from_utf16_lossy(self.as_slice());
+    }
+}
+
+// Where each capture group matched, group 0 being the whole match
+pub type Captures<'h> = regex::Captures<'h, [u16]>;
+
+// Successive non-overlapping matches, the same rules as regex::Matches
+// After an empty match the search moves on by one character, which is two units for a surrogate pair
+pub type Matches<'r, 'h> = regex::Matches<'r, 'h, [u16]>;
+
+// Capture groups of each match find_iter would return
+pub type CaptureMatches<'r, 'h> = regex::CaptureMatches<'r, 'h, [u16]>;
+
+// First character in src and how many units it takes up, which is 1 or 2
+// None if src is empty or starts with a surrogate that isn't part of a pair
+#[inline(always)]
+pub(crate) fn decode_utf16(src: &[u16]) -> Option<(char, usize)> {
+    let u0 = *src.first()?;
+    match u0 {
+        0xD800..=0xDBFF => {
+            let u1 = *src.get(1)?;
+            if !(0xDC00..=0xDFFF).contains(&u1) {
+                return None;
+            }
+            let cp = 0x10000 + (((u0 as u32) - 0xD800) << 10) + ((u1 as u32) - 0xDC00);
+            char::from_u32(cp).map(|c| (c, 2))
+        }
+        0xDC00..=0xDFFF => None,
+        _ => char::from_u32(u0 as u32).map(|c| (c, 1)),
+    }
+}
+
+// Same as decode_utf16 for the last character in src
+#[inline(always)]
+pub(crate) fn decode_last_utf16(src: &[u16]) -> Option<(char, usize)> {
+    let last = *src.last()?;
+    match last {
+        0xDC00..=0xDFFF if src.len() >= 2 => match decode_utf16(&src[src.len() - 2..]) {
+            Some((c, 2)) => Some((c, 2)),
+            _ => None,
+        },
+        0xD800..=0xDFFF => None,
+        _ => decode_utf16(&src[src.len() - 1..]),
+    }
+}
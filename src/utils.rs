@@ -1,4 +1,4 @@
-use super::{compiled_node::OptionBool, constants::*, nfa::*, parse::ParseToken};
+use super::{compiled_node::OptionBool, config::RegexConfig, constants::*, error::Error, haystack::INVALID, nfa::*, parse::ParseToken};
 
 pub fn remove_duplicates_without_sort<T: PartialEq + Eq + std::hash::Hash + Copy>(vec: &mut Vec<T>, set: &mut fxhash::FxHashSet<T>) {
     // Linear time complexity and reuses allocations in the set
@@ -19,6 +19,7 @@ pub fn remove_duplicates_without_sort<T: PartialEq + Eq + std::hash::Hash + Copy
 pub trait RangeUtils {
     fn invert(&mut self);
     fn minimize(&mut self);
+    fn complement(&mut self);
 }
 
 impl RangeUtils for Vec<(char, char)> {
@@ -52,6 +53,74 @@ impl RangeUtils for Vec<(char, char)> {
         new_ranges.push((left, right));
         *self = new_ranges;
     }
+
+    // Every character outside of the ranges, unlike invert which only complements them one at a time
+    fn complement(&mut self) {
+        self.minimize();
+        let mut new = vec![];
+        let mut next = 0u32;
+        for (start, end) in self.iter() {
+            if (*start as u32) > next {
+                new.push((char_at_or_after(next), char_at_or_before(*start as u32 - 1)));
+            }
+            next = *end as u32 + 1;
+        }
+        if next <= char::MAX as u32 {
+            new.push((char_at_or_after(next), char::MAX));
+        }
+        // A gap made up of only surrogates has nothing in it
+        new.retain(|(start, end)| start <= end);
+        *self = new;
+    }
+}
+
+// Steps over the surrogates, which aren't characters
+fn char_at_or_after(value: u32) -> char {
+    return char::from_u32(value).unwrap_or('\u{E000}');
+}
+
+fn char_at_or_before(value: u32) -> char {
+    return char::from_u32(value).unwrap_or('\u{D7FF}');
+}
+
+/// Escapes every character the parser treats as special so the result matches `string` literally.
+/// Whitespace and `#` are escaped as well so the output is still literal inside `(?x)`.
+pub fn escape(string: &str) -> String {
+    let mut out = String::with_capacity(string.len());
+    for c in string.chars() {
+        if is_meta_character(c) || c.is_whitespace() {
+            out.push(BACKSLASH);
+        }
+        out.push(c);
+    }
+    return out;
+}
+
+pub(crate) fn is_meta_character(c: char) -> bool {
+    return matches!(c, '\\' | '.' | '+' | '*' | '?' | '(' | ')' | '|' | '[' | ']' | '{' | '}' | '^' | '$' | '#' | '-');
+}
+
+// First byte scan followed by a comparison of the rest, used by the root node for literal regexes
+pub(crate) fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    let (first, rest) = match needle.split_first() {
+        Some(t) => t,
+        None => return Some(0),
+    };
+    let last_start = haystack.len().checked_sub(needle.len())?;
+    let mut i = 0;
+    while i <= last_start {
+        match haystack[i..=last_start].iter().position(|b| b == first) {
+            Some(offset) => {
+                i += offset;
+                if &haystack[i + 1..i + needle.len()] == rest {
+                    return Some(i);
+                }
+                i += 1;
+            }
+            None => return None,
+        }
+    }
+    return None;
 }
 
 pub(crate) fn str_to_char_vec(string: &str) -> Vec<char> {
@@ -116,10 +185,33 @@ pub(crate) fn parse_range_character(c: char) -> Node {
     };
 }
 
-pub(crate) fn parse_square_brackets(chars: &mut Vec<char>, node_vec: &mut Vec<Node>, callstack: &mut Vec<ParseToken>) {
+// \xHH or \x{H...} starting at index, gives the value and the index of the last character it took up
+pub(crate) fn parse_hex_escape(string: &[char], index: usize) -> Result<(u32, usize), Error> {
+    let invalid = |reason: &str| Error::InvalidPattern { reason: reason.to_string() };
+    let (digits, last) = if string.get(index) == Some(&'{') {
+        let end = string[index..].iter().position(|c| *c == '}').ok_or_else(|| invalid("unclosed hex escape"))? + index;
+        (&string[index + 1..end], end)
+    } else {
+        if string.len() < index + 2 {
+            return Err(invalid("\\x needs two hex digits"));
+        }
+        (&string[index..index + 2], index + 1)
+    };
+    // from_str_radix would also take a sign in front of the digits
+    if digits.is_empty() || !digits.iter().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid("invalid hex escape"));
+    }
+    let value = u32::from_str_radix(&digits.iter().collect::<String>(), 16).map_err(|_| invalid("hex escape is too large"))?;
+    if value == INVALID as u32 {
+        return Err(invalid("\\x{10FFFF} stands for invalid input and can't be matched"));
+    }
+    return Ok((value, last));
+}
+
+pub(crate) fn parse_square_brackets(chars: &mut Vec<char>, case_insensitive: bool, node_vec: &mut Vec<Node>, callstack: &mut Vec<ParseToken>) -> Result<(), Error> {
     // println!("Square Expression: {:?}", chars);
     if chars.len() == 0 {
-        return;
+        return Ok(());
     }
     // let before = Node::new_transition();
     // let before_index = node_vec.len();
@@ -137,18 +229,28 @@ pub(crate) fn parse_square_brackets(chars: &mut Vec<char>, node_vec: &mut Vec<No
     }
 
     let mut ranges = Vec::<(char, char)>::new();
-    let mut no_match_ranges = Vec::<(char, char)>::new();
     let mut match_characters = Vec::<char>::new();
-    let mut no_match_characters = Vec::<char>::new();
+    // \W, \D, \S, \H and \V each match everything outside of their own set
+    let mut negated_sets = Vec::<Vec<(char, char)>>::new();
 
     let mut looking_back = false;
 
     let mut tokens = vec![];
 
-    for character in chars {
+    let mut j = 0;
+    while j < chars.len() {
+        let character = &chars[j];
+        j += 1;
         if looking_back {
             match character {
                 '\\' => tokens.push(('\\', false)),
+                // \xHH and \x{H...} are plain characters so they can be the ends of a range, (?-u) makes them bytes
+                'x' => {
+                    let (value, last) = parse_hex_escape(chars, j)?;
+                    let c = char::from_u32(value).ok_or_else(|| Error::InvalidPattern { reason: format!("\\x{{{:X}}} isn't a Unicode scalar value", value) })?;
+                    tokens.push((c, false));
+                    j = last + 1;
+                }
                 _ => tokens.push((*character, true)),
             }
             looking_back = false;
@@ -167,26 +269,34 @@ pub(crate) fn parse_square_brackets(chars: &mut Vec<char>, node_vec: &mut Vec<No
         if escaped {
             match character {
                 'w' => {
-                    ranges.push(('a', 'z'));
-                    ranges.push(('A', 'Z'));
-                    match_characters.push('_');
+                    ranges.extend(w());
                 }
                 'W' => {
-                    no_match_ranges.push(('a', 'z'));
-                    no_match_ranges.push(('A', 'Z'));
-                    no_match_characters.push('_');
+                    negated_sets.push(w());
                 }
                 's' => {
                     match_characters.extend(WHITESPACE);
                 }
                 'S' => {
-                    no_match_characters.extend(WHITESPACE);
+                    negated_sets.push(WHITESPACE.iter().map(|c| (*c, *c)).collect());
+                }
+                'h' => {
+                    match_characters.extend(HORIZONTAL_SPACE);
+                }
+                'H' => {
+                    negated_sets.push(HORIZONTAL_SPACE.iter().map(|c| (*c, *c)).collect());
+                }
+                'v' => {
+                    match_characters.extend(VERTICAL_SPACE);
+                }
+                'V' => {
+                    negated_sets.push(VERTICAL_SPACE.iter().map(|c| (*c, *c)).collect());
                 }
                 'd' => {
-                    ranges.push(('0', '9'));
+                    ranges.extend(d());
                 }
                 'D' => {
-                    no_match_ranges.push(('0', '9'));
+                    negated_sets.push(d());
                 }
                 _ => match_characters.push(character),
             }
@@ -194,6 +304,9 @@ pub(crate) fn parse_square_brackets(chars: &mut Vec<char>, node_vec: &mut Vec<No
             if tokens.get(i + 1) == Some(&('-', false)) && tokens.get(i + 2).map(|v| v.1 == false).is_true() {
                 // bounds checking already done in the if statement
                 let end = tokens[i + 2].0;
+                if end < character {
+                    return Err(Error::InvalidPattern { reason: format!("character class range {}-{} is out of order", character, end) });
+                }
                 ranges.push((character, end));
                 i += 2;
             } else {
@@ -203,17 +316,25 @@ pub(crate) fn parse_square_brackets(chars: &mut Vec<char>, node_vec: &mut Vec<No
         i += 1;
     }
 
-    // Deal with no match characters
-
-    for character in no_match_characters {
-        no_match_ranges.push((character, character));
+    // Other cases are added before negating so [^a-z] with the i flag doesn't match A either
+    if case_insensitive {
+        let mut others = vec![];
+        for (start, end) in ranges.iter() {
+            for c in *start..=std::cmp::min(*end, LAST_CASED) {
+                others.extend(other_cases(c).into_iter().map(|other| (other, other)));
+            }
+        }
+        ranges.append(&mut others);
+        let mut others = match_characters.iter().flat_map(|c| other_cases(*c)).collect::<Vec<char>>();
+        match_characters.append(&mut others);
+        match_characters.sort_unstable();
+        match_characters.dedup();
     }
 
-    // Deal with no match ranges
-
-    no_match_ranges.invert();
-    ranges.append(&mut no_match_ranges);
-    std::mem::drop(no_match_ranges);
+    for mut set in negated_sets {
+        set.complement();
+        ranges.append(&mut set);
+    }
 
     if exclusive {
         if ranges.is_empty() && match_characters.is_empty() {
@@ -225,7 +346,7 @@ pub(crate) fn parse_square_brackets(chars: &mut Vec<char>, node_vec: &mut Vec<No
                 node_vec,
                 callstack,
             );
-            return;
+            return Ok(());
         }
 
         if ranges.is_empty() {
@@ -237,10 +358,9 @@ pub(crate) fn parse_square_brackets(chars: &mut Vec<char>, node_vec: &mut Vec<No
                 node_vec,
                 callstack,
             );
-            return;
+            return Ok(());
         }
 
-        ranges.invert();
         ranges.minimize();
 
         if match_characters.is_empty() {
@@ -252,7 +372,7 @@ pub(crate) fn parse_square_brackets(chars: &mut Vec<char>, node_vec: &mut Vec<No
                 node_vec,
                 callstack,
             );
-            return;
+            return Ok(());
         }
 
         // Determine the number of branches for a miss in exclusive node
@@ -289,7 +409,7 @@ pub(crate) fn parse_square_brackets(chars: &mut Vec<char>, node_vec: &mut Vec<No
             );
         }
 
-        return;
+        return Ok(());
     } else {
         ranges.minimize();
 
@@ -302,7 +422,7 @@ pub(crate) fn parse_square_brackets(chars: &mut Vec<char>, node_vec: &mut Vec<No
                 node_vec,
                 callstack,
             );
-            return;
+            return Ok(());
         }
 
         // 3 approaches: Only ranges, only characters or two nodes with a branch.
@@ -335,7 +455,7 @@ pub(crate) fn parse_square_brackets(chars: &mut Vec<char>, node_vec: &mut Vec<No
                 node_vec,
                 callstack,
             );
-            return;
+            return Ok(());
         } else if c_cost < d_cost {
             for (start, end) in ranges {
                 (start..=end).for_each(|v| match_characters.push(v));
@@ -348,7 +468,7 @@ pub(crate) fn parse_square_brackets(chars: &mut Vec<char>, node_vec: &mut Vec<No
                 node_vec,
                 callstack,
             );
-            return;
+            return Ok(());
         } else {
             let len = node_vec.len();
             node_vec.get_mut(callstack.pop().unwrap().idx()).unwrap().push_child(len);
@@ -379,284 +499,179 @@ pub(crate) fn parse_square_brackets(chars: &mut Vec<char>, node_vec: &mut Vec<No
             });
         }
     }
+    return Ok(());
 }
 
-pub(crate) fn parse_curly_brackets(contents: &Vec<char>, node_vec: &mut Vec<Node>, callstack: &mut Vec<ParseToken>, lazy: bool, possessive: bool) {
-    use ParseToken::*;
-    let pos_of_comma = contents.iter().position(|c| *c == ',');
-    if let Some(p) = pos_of_comma {
-        if p == contents.len() - 1 {
-            let mut s1 = String::new();
-            for i in 0..p {
-                s1.push(contents[i]);
-            }
-            let to_repeat = s1.parse::<usize>().unwrap() - 1;
-            match callstack.pop().unwrap() {
-                S(last_index) => {
-                    let len = node_vec.len();
-                    node_vec.get_mut(last_index).unwrap().get_children_mut().unwrap().push(len);
-                    for i in 0..to_repeat {
-                        let mut clone = node_vec.get(last_index).unwrap().clone();
-                        let children = clone.get_children_mut().unwrap();
-                        children.clear();
-                        if i != to_repeat - 1 {
-                            children.push(node_vec.len() + 1);
-                        } else {
-                            children.lazy_dependent_insert(node_vec.len(), lazy);
-                        }
-                        node_vec.push(clone);
-                    }
-                    callstack.push(S(node_vec.len() - 1));
-
-                    if possessive {
-                        let mut last = node_vec.pop().unwrap();
-                        let children = last.get_children_mut().unwrap();
-                        children.clear();
-                        children.push(node_vec.len() + 1);
-                        children.push(node_vec.len() + 2);
-                        node_vec.push(Node::StartAtomic {children: vec![node_vec.len() + 1]});
-                        node_vec.push(last);
-                        node_vec.push(Node::EndAtomic {children: vec![]});
-                        callstack.pop();
-                        callstack.push(S(node_vec.len() - 1));
-                    }
-                }
-                M {
-                    mut before,
-                    mut after,
-                    first,
-                    mut last,
-                    ..
-                } => {
-                    for _ in 0..to_repeat {
-                        let mut new_nodes = vec![];
-                        let new_before_index = node_vec.len();
-                        for i in first..node_vec.len() {
-                            new_nodes.push(node_vec.get(i).unwrap().clone());
-                        }
-                        node_vec.get_mut(last).unwrap().get_children_mut().unwrap().push(new_before_index);
-                        for i in 0..new_nodes.len() {
-                            let len = new_nodes.len();
-                            let node = new_nodes.get_mut(i).unwrap();
-                            match node.get_children_mut() {
-                                Some(c) => {
-                                    for child in c {
-                                        *child += len;
-                                    }
-                                }
-                                None => continue,
-                            }
-                        }
-                        last += new_nodes.len();
-                        after += new_nodes.len();
-                        before += new_nodes.len();
-                        node_vec.extend(new_nodes);
-                    }
-                    node_vec.get_mut(after).unwrap().lazy_dependent_insert(before, lazy);
-                    callstack.push(M {
-                        first,
-                        before,
-                        after,
-                        last,
-                        to_link: last,
-                    });
-                }
-            }
-        } else {
-            let mut s1 = String::new();
-            let mut s2 = String::new();
-            for i in 0..p {
-                s1.push(contents[i])
-            }
-            for i in p + 1..contents.len() {
-                s2.push(contents[i]);
-            }
-            let int1 = s1.parse::<usize>().unwrap();
-            let int2 = s2.parse::<usize>().unwrap() - int1;
-            match callstack.pop().unwrap() {
-                S(last_index) => {
-                    let len = node_vec.len();
-                    let final_index = int2 + int1 - 1 + len;
-                    node_vec.get_mut(last_index).unwrap().get_children_mut().unwrap().push(len);
-                    for i in 1..int1 {
-                        let mut clone = node_vec.get(last_index).unwrap().clone();
-                        let children = clone.get_children_mut().unwrap();
-                        children.clear();
-                        if i != int1 - 1 {
-                            children.push(node_vec.len() + 1);
-                        } else {
-                            children.push(final_index);
-                        }
-                        node_vec.push(clone);
-                    }
-                    callstack.push(S(node_vec.len() - 1));
+// Links the end of a group back to its start, through nodes that keep an iteration that consumed nothing from going round again
+// Gives the node the group is now entered through, which is where another loop around it has to go in front of
+pub(crate) fn add_group_loop(node_vec: &mut Vec<Node>, first: usize, before: usize, after: usize, lazy: bool, counters: &mut usize) -> usize {
+    let counter = *counters;
+    *counters += 1;
+    let start_iteration = node_vec.len();
+    node_vec.push(Node::StartIteration { children: vec![before], counter });
+    node_vec.push(Node::NextIteration { children: vec![start_iteration], counter });
+    for child in node_vec[first].get_children_mut().unwrap().iter_mut() {
+        if *child == before {
+            *child = start_iteration;
+        }
+    }
+    // The exit is already a child of after so looping back goes in front of it unless lazy
+    node_vec[after].lazy_dependent_insert(start_iteration + 1, !lazy);
+    return start_iteration;
+}
 
-                    if possessive {
-                        add_node(Node::StartAtomic {children: vec![]}, node_vec, callstack);
-                    }
+pub(crate) fn parse_curly_brackets(
+    contents: &[char],
+    node_vec: &mut Vec<Node>,
+    callstack: &mut Vec<ParseToken>,
+    lazy: bool,
+    possessive: bool,
+    counters: &mut usize,
+    config: &RegexConfig,
+) -> Result<(), Error> {
+    use ParseToken::*;
+    let contents = char_vec_to_string(contents);
+    // A bound too big to even parse is certainly over the limit
+    let parse_bound = |bound: &str| {
+        if bound.is_empty() || !bound.chars().all(|c| c.is_ascii_digit()) {
+            return Err(Error::InvalidPattern { reason: format!("invalid repetition {{{}}}", contents) });
+        }
+        return bound.parse::<usize>().map_err(|_| Error::RepetitionLimitExceeded { limit: config.repetition_limit });
+    };
+    let (min, max) = match contents.split_once(',') {
+        Some((min, max)) => (
+            if min.is_empty() { 0 } else { parse_bound(min)? },
+            if max.is_empty() { None } else { Some(parse_bound(max)?) },
+        ),
+        None => {
+            let n = parse_bound(&contents)?;
+            (n, Some(n))
+        }
+    };
+    if max.map(|max| max < min).is_true() {
+        return Err(Error::InvalidPattern { reason: format!("invalid repetition range {{{}}}", contents) });
+    }
+    if std::cmp::max(min, max.unwrap_or(0)) > config.repetition_limit {
+        return Err(Error::RepetitionLimitExceeded { limit: config.repetition_limit });
+    }
 
-                    // --------------------Add Optional Nodes----------------------
-
-                    let last_index = callstack.pop().unwrap().idx();
-                    let len = node_vec.len();
-                    node_vec.get_mut(last_index).unwrap().get_children_mut().unwrap().insert(0, len);
-                    for _ in 0..int2 {
-                        let mut clone = node_vec.get(last_index).unwrap().clone();
-                        let children = clone.get_children_mut().unwrap();
-                        children.clear();
-                        children.push(node_vec.len() + 1);
-                        children.lazy_dependent_insert(final_index, lazy);
-                        node_vec.push(clone);
-                    }
-                    callstack.push(S(node_vec.len()));
-                    if possessive {
-                        node_vec.push(Node::EndAtomic {children: vec![]});
-                    } else {
-                        node_vec.push(Node::new_transition());
-                    }
-                }
-                M {
-                    mut last,
-                    mut first,
-                    mut after,
-                    mut before,
-                    ..
-                } => {
-                    let initial_first = first;
-                    for _ in 0..int1.saturating_sub(1) {
-                        let mut new_nodes = vec![];
-                        let new_before_index = node_vec.len();
-                        for i in first..node_vec.len() {
-                            new_nodes.push(node_vec.get(i).unwrap().clone());
-                        }
-                        node_vec.get_mut(last).unwrap().get_children_mut().unwrap().push(new_before_index);
-                        for i in 0..new_nodes.len() {
-                            let len = new_nodes.len();
-                            let node = new_nodes.get_mut(i).unwrap();
-                            match node.get_children_mut() {
-                                Some(c) => {
-                                    for child in c {
-                                        *child += len;
-                                    }
-                                }
-                                None => continue,
-                            }
-                        }
-                        last += new_nodes.len();
-                        after += new_nodes.len();
-                        before += new_nodes.len();
-                        first += new_nodes.len();
-                        node_vec.extend(new_nodes);
-                    }
-                    let final_idx = last + int2 * (node_vec.len() - first);
-                    for _ in 0..int2 {
-                        let mut new_nodes = vec![];
-                        let new_before_index = node_vec.len();
-                        for i in first..node_vec.len() {
-                            new_nodes.push(node_vec.get(i).unwrap().clone());
-                        }
-                        node_vec.get_mut(last).unwrap().get_children_mut().unwrap().push(new_before_index);
-                        for i in 0..new_nodes.len() {
-                            let len = new_nodes.len();
-                            let node = new_nodes.get_mut(i).unwrap();
-                            match node.get_children_mut() {
-                                Some(c) => {
-                                    for child in c {
-                                        *child += len;
-                                    }
-                                }
-                                None => continue,
-                            }
-                        }
-                        last += new_nodes.len();
-                        after += new_nodes.len();
-                        before += new_nodes.len();
-                        first += new_nodes.len();
-                        node_vec.extend(new_nodes);
-                        node_vec.get_mut(first).unwrap().lazy_dependent_insert(final_idx, lazy);
+    // Turn whatever is being repeated into a segment from `start` to the end of the vector, entered through `entry` and left through `exit`
+    // The node in front of it stays put because whatever came before is already linked to it
+    let len = node_vec.len();
+    let (wrapper, start, exit) = match callstack.pop().unwrap() {
+        S(index) => {
+            let mut node = std::mem::replace(&mut node_vec[index], Node::Transition { children: vec![len] });
+            let children = node.get_children_mut().unwrap();
+            children.clear();
+            children.push(len + 2);
+            node_vec.push(Node::Transition { children: vec![len + 1] });
+            node_vec.push(node);
+            node_vec.push(Node::new_transition());
+            (index, len, len + 2)
+        }
+        M { first, last, .. } => {
+            let entry = std::mem::replace(&mut node_vec[first], Node::Transition { children: vec![len] });
+            node_vec.push(entry);
+            (first, first + 1, last)
+        }
+    };
+    let entry = len;
+    let size = node_vec.len() - start;
+
+    let copies = max.unwrap_or(std::cmp::max(min, 1));
+    let end;
+    let unrolled = copies.saturating_mul(size);
+    // Copying the segment out keeps it usable by the parallel engine, counters only run on the backtracker which can take
+    // exponential time, so they're only used when the copies wouldn't fit in the size limit
+    if node_vec.len().saturating_add(unrolled) <= config.size_limit {
+        for i in 1..copies {
+            for j in start..start + size {
+                let mut node = node_vec[j].clone();
+                if let Some(children) = node.get_children_mut() {
+                    for child in children.iter_mut() {
+                        *child += i * size;
                     }
-                    callstack.push(M {
-                        first: initial_first,
-                        before,
-                        after,
-                        last,
-                        to_link: last,
-                    });
                 }
+                node_vec.push(node);
             }
         }
-    } else {
-        let mut s1 = String::from("");
-        for character in contents {
-            s1.push(*character);
-        }
-        let to_repeat = s1.parse::<usize>().unwrap();
-        match callstack.pop().unwrap() {
-            S(last_index) => {
-                let len = node_vec.len();
-                node_vec.get_mut(last_index).unwrap().get_children_mut().unwrap().push(len);
-                for i in 1..to_repeat {
-                    let mut clone = node_vec.get(last_index).unwrap().clone();
-                    let children = clone.get_children_mut().unwrap();
-                    children.clear();
-                    if i != to_repeat - 1 {
-                        children.push(node_vec.len() + 1);
-                    }
-                    node_vec.push(clone);
-                }
-                callstack.push(S(node_vec.len() - 1));
+        // An open ended range loops on the last copy, entered through a StartIteration node that goes in after the copies
+        let looped = max.is_none() && copies > 0;
+        let last_entry = entry + copies.saturating_sub(1) * size;
+        let start_iteration = node_vec.len();
+        end = if looped { start_iteration + 2 } else { start_iteration };
+        for i in 0..copies {
+            let next = if i + 1 == copies {
+                end
+            } else if looped && i + 2 == copies {
+                start_iteration
+            } else {
+                entry + (i + 1) * size
+            };
+            node_vec[exit + i * size].push_child(next);
+            if i >= min {
+                node_vec[entry + i * size].lazy_dependent_insert(end, lazy);
             }
-            M {
-                mut before,
-                mut after,
-                first,
-                mut last,
-                ..
-            } => {
-                for _ in 0..to_repeat {
-                    let mut new_nodes = vec![];
-                    let new_before_index = node_vec.len();
-                    for i in first..node_vec.len() {
-                        new_nodes.push(node_vec.get(i).unwrap().clone());
-                    }
-                    node_vec.get_mut(last).unwrap().get_children_mut().unwrap().push(new_before_index);
-                    for i in 0..new_nodes.len() {
-                        let len = new_nodes.len();
-                        let node = new_nodes.get_mut(i).unwrap();
-                        match node.get_children_mut() {
-                            Some(c) => {
-                                for child in c {
-                                    *child += len;
-                                }
-                            }
-                            None => continue,
-                        }
-                    }
-                    last += new_nodes.len();
-                    after += new_nodes.len();
-                    before += new_nodes.len();
-                    node_vec.extend(new_nodes);
-                }
-                callstack.push(M {
-                    first,
-                    before,
-                    after,
-                    last,
-                    to_link: last,
-                });
+        }
+        if copies == 0 {
+            node_vec[wrapper] = Node::Transition { children: vec![end] };
+        } else if looped {
+            if copies == 1 {
+                node_vec[wrapper] = Node::Transition { children: vec![start_iteration] };
             }
+            let counter = *counters;
+            *counters += 1;
+            node_vec.push(Node::StartIteration { children: vec![last_entry], counter });
+            node_vec.push(Node::NextIteration { children: vec![start_iteration], counter });
+            node_vec[exit + (copies - 1) * size].lazy_dependent_insert(start_iteration + 1, !lazy);
+        }
+    } else {
+        let counter = *counters;
+        *counters += 1;
+        let repeat_loop = node_vec.len();
+        let repeat_exit = repeat_loop + 1;
+        end = repeat_loop + 2;
+        let mut start_children = vec![entry];
+        if min == 0 {
+            start_children.lazy_dependent_insert(repeat_exit, lazy);
         }
+        node_vec[wrapper] = Node::StartRepeat { children: start_children, counter };
+        node_vec[exit] = Node::EndRepeat {
+            children: if lazy { vec![repeat_exit, repeat_loop] } else { vec![repeat_loop, repeat_exit] },
+            counter,
+            min,
+        };
+        node_vec.push(Node::RepeatLoop {
+            children: vec![entry],
+            counter,
+            max: max.unwrap_or(usize::MAX),
+        });
+        node_vec.push(Node::RepeatExit { children: vec![end], counter, min });
     }
+
     if possessive {
-        if let M { first, last, .. } = callstack.last().unwrap() {
-            node_vec[*first] = Node::StartAtomic {
-                children: node_vec[*first].get_children_mut().unwrap().clone(),
-            };
-            node_vec[*last] = Node::EndAtomic {
-                children: node_vec[*last].get_children_mut().unwrap().clone(),
-            };
-        }
+        let inner = std::mem::replace(&mut node_vec[wrapper], Node::StartAtomic { children: vec![end + 1] });
+        node_vec.push(Node::EndAtomic { children: vec![] });
+        node_vec.push(inner);
+    } else {
+        node_vec.push(Node::new_transition());
     }
+    // A loop around the whole repetition needs to go in between what comes before it and where it starts,
+    // and in between where it ends and the way out, like it would around a group
+    let before = node_vec.len();
+    let last = before + 1;
+    let start_node = std::mem::replace(&mut node_vec[wrapper], Node::Transition { children: vec![before] });
+    node_vec.push(start_node);
+    node_vec.push(Node::new_transition());
+    node_vec[end].push_child(last);
+    callstack.push(M {
+        first: wrapper,
+        before,
+        after: end,
+        last,
+        to_link: last,
+    });
+    return Ok(());
 }
 
 fn get_enclosing_brackets_to_repeat(string: &[char], mut index: usize) -> Vec<char> {
@@ -730,3 +745,30 @@ pub(crate) fn add_node(node: Node, node_vec: &mut Vec<Node>, callstack: &mut Vec
 pub(crate) fn add_character(c: char, node_vec: &mut Vec<Node>, callstack: &mut Vec<ParseToken>) {
     add_node(Node::new_from_char(c), node_vec, callstack)
 }
+
+// No character after this one has another case
+const LAST_CASED: char = '\u{1E943}';
+
+// The other cases of a character that are single characters
+fn other_cases(c: char) -> Vec<char> {
+    let mut characters = vec![];
+    for other in [c.to_lowercase().collect::<Vec<char>>(), c.to_uppercase().collect::<Vec<char>>()].iter() {
+        if other.len() == 1 && other[0] != c && !characters.contains(&other[0]) {
+            characters.push(other[0]);
+        }
+    }
+    return characters;
+}
+
+// When ignoring case a character also matches its other cases, as long as they're single characters
+pub(crate) fn add_character_with_case(c: char, case_insensitive: bool, node_vec: &mut Vec<Node>, callstack: &mut Vec<ParseToken>) {
+    if !case_insensitive {
+        return add_character(c, node_vec, callstack);
+    }
+    let mut characters = vec![c];
+    characters.extend(other_cases(c));
+    if characters.len() == 1 {
+        return add_character(c, node_vec, callstack);
+    }
+    add_node(Node::new_from_chars(characters, false), node_vec, callstack);
+}